@@ -2,6 +2,7 @@
 // Copyright (c) 2019, clams@mail.com
 
 use crate::lex::position::Position;
+use crate::ast::cst::{CstElem,CstKind};
 use std::collections::HashMap;
 use std::fmt;
 
@@ -11,12 +12,14 @@ pub enum AstNodeKind {
     Root, // first node of a tree
     Module, Ports, Port, Params, Param,
     Class, Extends, Implements, Function, Task,
-    Constraint, Covergroup, SvaProperty,
+    Constraint, Covergroup, SvaProperty, SvaSequence, SvaExpr,
+    Coverpoint, CoverCross, CoverBins, CoverOption,
     Interface, Modport, Clocking,
     Package,
-    Program,
-    Udp, Primitive,
-    Config,
+    Program, Checker, Let,
+    Udp, UdpTable, UdpEntry, Primitive,
+    Specify, SpecPath, TimingCheck,
+    Config, ConfigRule,
     //
     Header,
     Body,
@@ -42,6 +45,7 @@ pub enum AstNodeKind {
     Type, Typedef, Scope,
     Struct, Union, StructInit, Concat, Replication,
     Enum, EnumIdent,
+    Error, // Placeholder for a part of the code which failed to parse
 }
 
 #[allow(dead_code)]
@@ -49,8 +53,13 @@ pub enum AstNodeKind {
 pub struct AstNode {
     pub kind  : AstNodeKind,
     pub pos   : Position,
+    /// End of the node in the source (last character)
+    pub end   : Position,
     pub child : Vec<AstNode>,
-    pub attr  : HashMap<String, String>
+    pub attr  : HashMap<String, String>,
+    /// Comments/whitespace before and after the node (only in lossless mode)
+    pub leading  : Vec<CstElem>,
+    pub trailing : Vec<CstElem>,
 }
 
 impl AstNode {
@@ -58,17 +67,39 @@ impl AstNode {
         AstNode {
             kind : k,
             pos: pos,
+            end: pos,
             child : Vec::new(),
-            attr : HashMap::new()
+            attr : HashMap::new(),
+            leading : Vec::new(),
+            trailing : Vec::new(),
+        }
+    }
+
+    // Extend the end position of every node to cover all its children
+    pub fn update_span(&mut self) {
+        for c in &mut self.child {
+            c.update_span();
+            if c.end > self.end {
+                self.end = c.end;
+            }
         }
     }
 
     pub fn to_string_lvl(&self, lvl:usize) -> String {
         let mut s = format!("{:width$}{} :","",self.kind,width=lvl*2);
-        for (k,v) in &self.attr {
+        // Sort attributes for a stable output
+        let mut attr : Vec<_> = self.attr.iter().collect();
+        attr.sort();
+        for (k,v) in attr {
             s.push_str(format!(" {}={},",k,v).as_ref());
         }
         s.pop();
+        // Display comments attached to the node
+        for (n,v) in [("leading",&self.leading),("trailing",&self.trailing)].iter() {
+            for e in v.iter().filter(|e| e.kind==CstKind::Comment) {
+                s.push_str(&format!("\n{:width$}  # {} {}","",n,e.text.trim_end(),width=lvl*2));
+            }
+        }
         for c in &self.child {
             s.push('\n');
             s.push_str(&c.to_string_lvl(lvl+1));
@@ -84,6 +115,10 @@ impl AstNode {
     pub fn is_signed(&self) -> bool {
         self.attr.get("signing").map_or(false,|x| x=="signed")
     }
+
+    pub fn is_unsigned(&self) -> bool {
+        self.attr.get("signing").map(|x| x.as_ref()) == Some("unsigned")
+    }
 }
 
 
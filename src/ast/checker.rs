@@ -0,0 +1,54 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+use crate::ast::module_body::{parse_module_body,ModuleCntxt};
+use crate::ast::sva::parse_sva_ports;
+
+/// Parse a checker declaration: same structure as a module (header + body)
+/// with formal arguments following the property/sequence syntax
+/// Suppose the checker keyword was peeked
+pub fn parse_checker(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Checker, t.pos);
+    let nt = expect_t!(ts,"checker declaration",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),nt.value.into());
+    let mut node_h = AstNode::new(AstNodeKind::Header, ts.get_pos());
+    let nt = expect_t!(ts,"checker declaration",TokenKind::ParenLeft,TokenKind::SemiColon);
+    if nt.kind == TokenKind::ParenLeft {
+        node_h.child = parse_sva_ports(ts)?.child;
+        expect_t!(ts,"checker declaration",TokenKind::SemiColon);
+    }
+    n.child.push(node_h);
+    let mut node_b = AstNode::new(AstNodeKind::Body, t.pos);
+    let res = parse_module_body(ts,&mut node_b, ModuleCntxt::Checker)
+        .and_then(|_| check_label(ts, &n.attr["name"]));
+    n.end = ts.last_end;
+    node_b.end = ts.last_end;
+    // Keep the checker even if incomplete
+    n.child.push(node_b);
+    node.child.push(n);
+    res
+}
+
+/// Parse a let declaration: let name [(args)] = expression;
+/// Suppose the let keyword was peeked
+pub fn parse_let(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Let, t.pos);
+    let nt = expect_t!(ts,"let declaration",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),nt.value.into());
+    let nt = expect_t!(ts,"let declaration",TokenKind::ParenLeft,TokenKind::OpEq);
+    if nt.kind == TokenKind::ParenLeft {
+        n.child.push(parse_sva_ports(ts)?);
+        expect_t!(ts,"let declaration",TokenKind::OpEq);
+    }
+    n.child.push(parse_expr(ts,ExprCntxt::Stmt,false)?);
+    expect_t!(ts,"let declaration",TokenKind::SemiColon);
+    node.child.push(n);
+    Ok(())
+}
@@ -4,11 +4,13 @@
 #[allow(unused_imports)]
 use crate::reporter::{REPORTER, MsgID};
 use crate::error::{SvError};
-use crate::lex::token::{TokenKind};
+use crate::lex::token::{Token,TokenKind};
 use crate::lex::token_stream::TokenStream;
 use crate::ast::astnode::*;
 use crate::ast::common::*;
 use crate::ast::module_body::parse_sensitivity;
+use crate::ast::covergroup::parse_covergroup;
+use crate::ast::constraint::parse_constraint;
 // use crate::ast::module_body::*;
 
 /// This function should be called after a keyword interface
@@ -25,11 +27,11 @@ pub fn parse_class(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     // Optionnal virtual/interface keyword
     match t.kind {
         TokenKind::KwVirtual => {
-            node.attr.insert("qualifier".to_owned(),t.value);
+            node.attr.insert("qualifier".to_owned(),t.value.into());
             t = ts.next_t(false)?;
         }
         TokenKind::KwIntf => {
-            node.attr.insert("qualifier".to_owned(),t.value);
+            node.attr.insert("qualifier".to_owned(),t.value.into());
             is_class_intf = true;
             t = ts.next_t(false)?;
         }
@@ -42,13 +44,13 @@ pub fn parse_class(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     // Optional lifetime indicator
     t = ts.next_t(false)?;
     if t.kind==TokenKind::KwStatic || t.kind==TokenKind::KwAutomatic {
-        node.attr.insert("lifetime".to_owned(),t.value);
+        node.attr.insert("lifetime".to_owned(),t.value.into());
         t = ts.next_t(false)?;
     }
     // Mandatory class name
     match t.kind {
         TokenKind::Ident => {
-            node.attr.insert("name".to_owned(),t.value);
+            node.attr.insert("name".to_owned(),t.value.into());
             t = ts.next_t(false)?;
         },
         _ => return Err(SvError::syntax(t, "class declaration, expecting identifier or lifetime (static/automatic)"))
@@ -104,64 +106,54 @@ pub fn parse_class(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     loop {
         t = ts.next_t(true)?;
         // if node.attr["name"]=="uvm_reg_field" {rpt_t!(MsgID::DbgStatus, &t , "[class] Top level token");}
-        match t.kind {
-            // Class members : known types, identifier, rand qualifier
-            TokenKind::TypeIntAtom   |
-            TokenKind::TypeIntVector |
-            TokenKind::TypeReal      |
-            TokenKind::TypeString    |
-            TokenKind::TypeCHandle   |
-            TokenKind::TypeEvent     |
-            TokenKind::KwRand        |
-            TokenKind::KwConst       |
-            TokenKind::Ident       => parse_class_members(ts, &mut node)?,
-            // Class parameters
-            TokenKind::KwParam | TokenKind::KwLParam => {
-                ts.rewind(1); // put back the token so that it can be read by the parse param function
-                // potential list of param (the parse function extract only one at a time)
-                loop {
-                    let node_param = parse_param_decl(ts,true)?;
-                    node.child.push(node_param);
-                    loop_args_break_cont!(ts,"param declaration",SemiColon);
-                }
-            }
-            // Typedef
-            TokenKind::KwTypedef => parse_typedef(ts,&mut node)?,
-            // A class can be defined inside a class
-            TokenKind::KwClass  => node.child.push(parse_class(ts)?),
-            TokenKind::KwImport => parse_import(ts,&mut node)?,
-            // Local/protected/static : can be members or method -> continue parsing
-            TokenKind::KwLocal     |
-            TokenKind::KwProtected => {
-                loop {
-                    t = ts.next_t(true)?;
-                    match t.kind {
-                        TokenKind::KwFunction => { parse_func(ts, &mut node, false, false)?; break; },
-                        TokenKind::KwTask     => { parse_task(ts, &mut node)?;               break; },
-                        TokenKind::TypeIntAtom   |
-                        TokenKind::TypeIntVector |
-                        TokenKind::TypeReal      |
-                        TokenKind::TypeString    |
-                        TokenKind::TypeCHandle   |
-                        TokenKind::TypeEvent     |
-                        TokenKind::KwRand        |
-                        TokenKind::KwVar         |
-                        TokenKind::KwConst       |
-                        TokenKind::Macro         |
-                        TokenKind::Ident         => {parse_class_members(ts, &mut node)?; break; },
-                        TokenKind::KwVirtual   |
-                        TokenKind::KwStatic     => {}
-                        _ => return Err(SvError::syntax(t, "extern function/task declaration")),
-                    }
-                }
+        match parse_class_item(ts,&mut node,t) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(&mut node,e)?,
+        }
+    }
+    node.end = ts.last_end;
+    // println!("[class] {}", node);
+    Ok(node)
+}
+
+// Parse one item of a class body: return true when the end of the class is reached
+fn parse_class_item(ts : &mut TokenStream, node : &mut AstNode, mut t: Token) -> Result<bool, SvError> {
+    match t.kind {
+        // Class members : known types, identifier, rand qualifier
+        TokenKind::TypeIntAtom   |
+        TokenKind::TypeIntVector |
+        TokenKind::TypeReal      |
+        TokenKind::TypeString    |
+        TokenKind::TypeCHandle   |
+        TokenKind::TypeEvent     |
+        TokenKind::KwRand        |
+        TokenKind::KwConst       |
+        TokenKind::Ident       => parse_class_members(ts, node)?,
+        // Class parameters
+        TokenKind::KwParam | TokenKind::KwLParam => {
+            ts.rewind(1); // put back the token so that it can be read by the parse param function
+            // potential list of param (the parse function extract only one at a time)
+            loop {
+                let node_param = parse_param_decl(ts,true)?;
+                node.child.push(node_param);
+                loop_args_break_cont!(ts,"param declaration",SemiColon);
             }
-            TokenKind::KwStatic    => {
+        }
+        // Typedef
+        TokenKind::KwTypedef => parse_typedef(ts,node)?,
+        // A class can be defined inside a class
+        TokenKind::KwClass  => node.child.push(parse_class(ts)?),
+        TokenKind::KwImport => parse_import(ts,node)?,
+        // Local/protected/static : can be members or method -> continue parsing
+        TokenKind::KwLocal     |
+        TokenKind::KwProtected => {
+            loop {
                 t = ts.next_t(true)?;
-                if t.kind == TokenKind::KwLocal || t.kind == TokenKind::KwProtected {
-                    t = ts.next_t(true)?;
-                }
-                // println!("[class] local/protected/static followed by {}", t);
                 match t.kind {
+                    TokenKind::KwFunction => { parse_func(ts, node, false, false)?; break; },
+                    TokenKind::KwTask     => { parse_task(ts, node, false)?;               break; },
                     TokenKind::TypeIntAtom   |
                     TokenKind::TypeIntVector |
                     TokenKind::TypeReal      |
@@ -171,78 +163,111 @@ pub fn parse_class(ts : &mut TokenStream) -> Result<AstNode, SvError> {
                     TokenKind::KwRand        |
                     TokenKind::KwVar         |
                     TokenKind::KwConst       |
-                    TokenKind::Ident         => parse_class_members(ts, &mut node)?,
-                    TokenKind::KwFunction    => parse_func(ts, &mut node, false, false)?,
-                    TokenKind::KwTask        => parse_task(ts, &mut node)?,
-                    TokenKind::KwConstraint  => parse_constraint(ts,&mut node)?,
-                    _ => return Err(SvError::syntax(t, "virtual task/function/interface")),
+                    TokenKind::Macro         |
+                    TokenKind::Ident         => {parse_class_members(ts, node)?; break; },
+                    TokenKind::KwVirtual   |
+                    TokenKind::KwStatic     => {}
+                    _ => return Err(SvError::syntax(t, "extern function/task declaration")),
                 }
             }
-            TokenKind::KwExtern => {
-                loop {
-                    t = ts.next_t(true)?;
-                    match t.kind {
-                        TokenKind::KwFunction => { parse_func(ts, &mut node, false, false)?; break;},
-                        TokenKind::KwTask     => { parse_task(ts, &mut node)?; break;},
-                        TokenKind::KwLocal     |
-                        TokenKind::KwProtected |
-                        TokenKind::KwVirtual   |
-                        TokenKind::KwStatic     => {}
-                        _ => return Err(SvError::syntax(t, "extern function/task declaration")),
-                    }
+        }
+        TokenKind::KwStatic    => {
+            t = ts.next_t(true)?;
+            if t.kind == TokenKind::KwLocal || t.kind == TokenKind::KwProtected {
+                t = ts.next_t(true)?;
+            }
+            // println!("[class] local/protected/static followed by {}", t);
+            match t.kind {
+                TokenKind::TypeIntAtom   |
+                TokenKind::TypeIntVector |
+                TokenKind::TypeReal      |
+                TokenKind::TypeString    |
+                TokenKind::TypeCHandle   |
+                TokenKind::TypeEvent     |
+                TokenKind::KwRand        |
+                TokenKind::KwVar         |
+                TokenKind::KwConst       |
+                TokenKind::Ident         => parse_class_members(ts, node)?,
+                TokenKind::KwFunction    => parse_func(ts, node, false, false)?,
+                TokenKind::KwTask        => parse_task(ts, node, false)?,
+                TokenKind::KwConstraint  => {
+                    ts.rewind(1);
+                    ts.flush_rd();
+                    parse_constraint(ts,node)?;
                 }
+                _ => return Err(SvError::syntax(t, "virtual task/function/interface")),
             }
-            // Function/task
-            TokenKind::KwFunction => parse_func(ts, &mut node, false, false)?,
-            TokenKind::KwTask     => parse_task(ts, &mut node)?,
-            TokenKind::KwVirtual => {
+        }
+        TokenKind::KwExtern => {
+            loop {
                 t = ts.next_t(true)?;
-                if t.kind == TokenKind::KwProtected {
-                    t = ts.next_t(true)?;
-                }
                 match t.kind {
-                    TokenKind::Ident | TokenKind::KwIntf | TokenKind::Macro => parse_vintf(ts, &mut node)?,
-                    TokenKind::KwFunction => parse_func(ts, &mut node, false, false)?,
-                    TokenKind::KwTask     => parse_task(ts, &mut node)?,
-                    _ => return Err(SvError::syntax(t, "virtual task/function/interface")),
+                    TokenKind::KwFunction => { parse_func(ts, node, false, false)?; break;},
+                    TokenKind::KwTask     => { parse_task(ts, node, false)?; break;},
+                    TokenKind::KwConstraint => {
+                        // Qualifiers are not kept for a constraint prototype
+                        ts.rewind(1);
+                        ts.flush_rd();
+                        parse_constraint(ts,node)?;
+                        break;
+                    },
+                    TokenKind::KwLocal     |
+                    TokenKind::KwProtected |
+                    TokenKind::KwVirtual   |
+                    TokenKind::KwStatic     => {}
+                    _ => return Err(SvError::syntax(t, "extern function/task/constraint declaration")),
                 }
-
             }
-            TokenKind::KwPure => {
-                t = ts.next_t(true)?;
-                if t.kind != TokenKind::KwVirtual {
-                    return Err(SvError::syntax(t, "virtual task/function/interface"))
-                }
+        }
+        // Function/task
+        TokenKind::KwFunction => parse_func(ts, node, false, false)?,
+        TokenKind::KwTask     => parse_task(ts, node, false)?,
+        TokenKind::KwVirtual => {
+            t = ts.next_t(true)?;
+            if t.kind == TokenKind::KwProtected {
                 t = ts.next_t(true)?;
-                if t.kind == TokenKind::KwProtected {
-                    t = ts.next_t(true)?;
-                }
-                match t.kind {
-                    TokenKind::KwFunction => parse_func(ts, &mut node, false, false)?,
-                    TokenKind::KwTask     => parse_task(ts, &mut node)?,
-                    _ => return Err(SvError::syntax(t, "pure virtual task/function")),
-                }
             }
-            TokenKind::Macro => {
-                parse_macro(ts,&mut node)?;
-                // Check for trailing ; after a macro
+            match t.kind {
+                TokenKind::Ident | TokenKind::KwIntf | TokenKind::Macro => parse_vintf(ts, node)?,
+                TokenKind::KwFunction => parse_func(ts, node, false, false)?,
+                TokenKind::KwTask     => parse_task(ts, node, false)?,
+                _ => return Err(SvError::syntax(t, "virtual task/function/interface")),
+            }
+
+        }
+        TokenKind::KwPure => {
+            t = ts.next_t(true)?;
+            if t.kind != TokenKind::KwVirtual {
+                return Err(SvError::syntax(t, "virtual task/function/interface"))
+            }
+            t = ts.next_t(true)?;
+            if t.kind == TokenKind::KwProtected {
                 t = ts.next_t(true)?;
-                if t.kind == TokenKind::SemiColon {ts.flush(1);} else {ts.rewind(1);}
-            },
-            TokenKind::CompDir => parse_macro(ts,&mut node)?,
-            TokenKind::KwConstraint => parse_constraint(ts,&mut node)?,
-            TokenKind::KwCovergroup => parse_covergroup(ts,&mut node)?,
-            TokenKind::SemiColon => {ts.flush(1);}, // TODO: generate a warning
-            TokenKind::KwEndClass => {
-                ts.flush(1);
-                check_label(ts,&node.attr["name"])?;
-                break;
-            },
-            _ => return Err(SvError::syntax(t, "class body")),
+            }
+            match t.kind {
+                TokenKind::KwFunction => parse_func(ts, node, false, false)?,
+                TokenKind::KwTask     => parse_task(ts, node, false)?,
+                _ => return Err(SvError::syntax(t, "pure virtual task/function")),
+            }
         }
+        TokenKind::Macro => {
+            parse_macro(ts,node)?;
+            // Check for trailing ; after a macro
+            t = ts.next_t(true)?;
+            if t.kind == TokenKind::SemiColon {ts.flush(1);} else {ts.rewind(1);}
+        },
+        TokenKind::CompDir => parse_macro(ts,node)?,
+        TokenKind::KwConstraint => parse_constraint(ts,node)?,
+        TokenKind::KwCovergroup => parse_covergroup(ts,node)?,
+        TokenKind::SemiColon => {ts.flush(1);}, // TODO: generate a warning
+        TokenKind::KwEndClass => {
+            ts.flush(1);
+            check_label(ts,&node.attr["name"])?;
+            return Ok(true);
+        },
+        _ => return Err(SvError::syntax(t, "class body")),
     }
-    // println!("[class] {}", node);
-    Ok(node)
+    Ok(false)
 }
 
 
@@ -250,7 +275,7 @@ pub fn parse_class(ts : &mut TokenStream) -> Result<AstNode, SvError> {
 pub fn parse_class_type(ts : &mut TokenStream, node : &mut AstNode, is_intf : bool) -> Result<(), SvError> {
     parse_opt_scope(ts,node)?;
     let t = expect_t!(ts,"data type",TokenKind::Ident);
-    node.attr.insert("type".to_owned(),t.value);
+    node.attr.insert("type".to_owned(),t.value.into());
     node.pos = t.pos;
     // Check for param
     parse_opt_params!(ts,node);
@@ -290,28 +315,28 @@ pub fn parse_class_members(ts : &mut TokenStream, node : &mut AstNode) -> Result
             }
             TokenKind::KwAutomatic |
             TokenKind::KwStatic if allow_lifetime => {
-                node_m.attr.insert("lifetime".to_owned(), t.value);
+                node_m.attr.insert("lifetime".to_owned(), t.value.into());
                 allow_lifetime = false;
             }
             TokenKind::KwLocal |
             TokenKind::KwProtected if allow_access => {
-                node_m.attr.insert("access".to_owned(), t.value);
+                node_m.attr.insert("access".to_owned(), t.value.into());
                 allow_access = false;
             }
             TokenKind::KwRand if allow_rand => {
-                node_m.attr.insert("rand".to_owned(), t.value);
+                node_m.attr.insert("rand".to_owned(), t.value.into());
                 allow_rand = false;
             }
             TokenKind::KwReg |
             TokenKind::KwVar if allow_var => {
-                node_m.attr.insert("net".to_owned(), t.value);
+                node_m.attr.insert("net".to_owned(), t.value.into());
                 allow_var = false;
             }
             TokenKind::KwInput  |
             TokenKind::KwOutput |
             TokenKind::KwInout  |
             TokenKind::KwRef    if allow_dir => {
-                node_m.attr.insert("dir".to_owned(), t.value);
+                node_m.attr.insert("dir".to_owned(), t.value.into());
                 allow_dir = false
             }
             _ => break
@@ -323,6 +348,7 @@ pub fn parse_class_members(ts : &mut TokenStream, node : &mut AstNode) -> Result
     // Parse data name
     let mut n = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
     parse_var_decl_name(ts, &mut n,ExprCntxt::StmtList,false,false)?;
+    n.end = ts.last_end;
     // println!("[parse_class_members] {}", node_m);
     // if node_m.attr.contains_key("net") {println!("{:?}", node);}
     node_m.child.push(n);
@@ -338,6 +364,7 @@ pub fn parse_class_members(ts : &mut TokenStream, node : &mut AstNode) -> Result
         }
         n = AstNode::new(AstNodeKind::Identifier, t.pos);
         parse_var_decl_name(ts, &mut n,ExprCntxt::StmtList,false,false)?;
+        n.end = ts.last_end;
         // println!("[class members] {}", node_m);
         // ts.display_status("class_members");
         node_m.child.push(n);
@@ -373,15 +400,15 @@ pub fn parse_func(ts : &mut TokenStream, node : &mut AstNode, is_oob : bool, is_
                 allow_virtual = false;
             }
             TokenKind::KwVirtual if allow_virtual => {
-                node_f.attr.insert(t.value,"".to_owned());
+                node_f.attr.insert(t.value.into(),"".to_owned());
                 allow_virtual = false;
             }
             TokenKind::KwLocal | TokenKind::KwProtected if allow_protloc => {
-                node_f.attr.insert("access".to_owned(),t.value);
+                node_f.attr.insert("access".to_owned(),t.value.into());
                 allow_protloc = false;
             }
             TokenKind::KwStatic if allow_static => {
-                node_f.attr.insert(t.value, "".to_owned());
+                node_f.attr.insert(t.value.into(), "".to_owned());
                 allow_static = false;
             }
             TokenKind::KwFunction => break,
@@ -390,7 +417,7 @@ pub fn parse_func(ts : &mut TokenStream, node : &mut AstNode, is_oob : bool, is_
     }
     t = ts.next_t(true)?;
     if t.kind==TokenKind::KwAutomatic || t.kind==TokenKind::KwStatic {
-        node_f.attr.insert("lifetime".to_owned(),t.value);
+        node_f.attr.insert("lifetime".to_owned(),t.value.into());
         ts.flush_rd();
         t = ts.next_t(true)?;
     }
@@ -436,7 +463,7 @@ pub fn parse_func(ts : &mut TokenStream, node : &mut AstNode, is_oob : bool, is_
     t = ts.next_t(false)?;
     match t.kind {
         TokenKind::KwNew |
-        TokenKind::Ident => {node_f.attr.insert("name".to_owned(),t.value);}
+        TokenKind::Ident => {node_f.attr.insert("name".to_owned(),t.value.into());}
         _ => return Err(SvError::syntax(t, "function header. Expecting function name"))
     }
     // Optional function port definition
@@ -475,7 +502,11 @@ pub fn parse_func(ts : &mut TokenStream, node : &mut AstNode, is_oob : bool, is_
         // if node_f.attr["name"]=="create" {println!("[parse_func] Next statement start : {} : {} ({})", t.kind, t.value, t.pos);}
         match t.kind {
             TokenKind::KwEndFunction => break,
-            _ => allow_decl = parse_class_stmt(ts,&mut node_f,false, allow_decl, !has_args, false)?,
+            // Syntax error in a statement: skip to the next one and continue
+            _ => match parse_class_stmt(ts,&mut node_f,false, allow_decl, !has_args, false) {
+                Ok(b) => allow_decl = b,
+                Err(e) => ts.resync(&mut node_f,e)?,
+            },
         }
 
     }
@@ -483,20 +514,21 @@ pub fn parse_func(ts : &mut TokenStream, node : &mut AstNode, is_oob : bool, is_
     ts.flush_rd();
     // Check for optional end label
     check_label(ts,&node_f.attr["name"])?;
+    node_f.end = ts.last_end;
     // ts.display_status("parse_func: done");
     node.child.push(node_f);
     Ok(())
 }
 
 /// Parse a data type
-pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvError> {
+pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode, is_decl: bool) -> Result<(), SvError> {
     ts.rewind(0);
     let mut node_task = AstNode::new(AstNodeKind::Task, ts.get_pos());
     let mut t;
     let mut allow_virtual = true;
     let mut allow_protloc = true;
     let mut allow_static = true;
-    let mut no_body = false;
+    let mut no_body = is_decl;
     // optional pure
     loop {
         t = ts.next_t(false)?;
@@ -515,15 +547,15 @@ pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvEr
                 allow_virtual = false;
             }
             TokenKind::KwVirtual if allow_virtual => {
-                node_task.attr.insert(t.value,"".to_owned());
+                node_task.attr.insert(t.value.into(),"".to_owned());
                 allow_virtual = false;
             }
             TokenKind::KwLocal | TokenKind::KwProtected if allow_protloc => {
-                node_task.attr.insert("access".to_owned(),t.value);
+                node_task.attr.insert("access".to_owned(),t.value.into());
                 allow_protloc = false;
             }
             TokenKind::KwStatic if allow_static => {
-                node_task.attr.insert(t.value, "".to_owned());
+                node_task.attr.insert(t.value.into(), "".to_owned());
                 allow_static = false;
             }
             TokenKind::KwTask => break,
@@ -532,14 +564,14 @@ pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvEr
     }
     t = ts.next_t(true)?;
     if t.kind==TokenKind::KwAutomatic || t.kind==TokenKind::KwStatic {
-        node_task.attr.insert("lifetime".to_owned(),t.value);
+        node_task.attr.insert("lifetime".to_owned(),t.value.into());
         ts.flush(1); // consume lifetime
     }
 
     // Expect task name
     parse_opt_scope(ts,&mut node_task)?;
     t = expect_t!(ts,"task name",TokenKind::Ident);
-    node_task.attr.insert("name".to_owned(),t.value);
+    node_task.attr.insert("name".to_owned(),t.value.into());
 
     // Optional function port definition
     t = ts.next_t(false)?;
@@ -580,12 +612,17 @@ pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvEr
                 ts.flush(1); // consume the return
                 expect_t!(ts,"return statement",TokenKind::SemiColon);
             },
-            _ => allow_decl = parse_class_stmt(ts,&mut node_task,false,allow_decl,!has_args, false)?,
+            // Syntax error in a statement: skip to the next one and continue
+            _ => match parse_class_stmt(ts,&mut node_task,false,allow_decl,!has_args, false) {
+                Ok(b) => allow_decl = b,
+                Err(e) => ts.resync(&mut node_task,e)?,
+            },
         }
     }
     ts.flush_rd();
     // Check for optional end label
     check_label(ts,&node_task.attr["name"])?;
+    node_task.end = ts.last_end;
 
     // println!("[parse_task] {}", node_task);
     node.child.push(node_task);
@@ -596,7 +633,11 @@ pub fn parse_task(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvEr
 // TODO: check if this can be removed (likely)
 pub fn parse_class_stmt_or_block(ts : &mut TokenStream, node: &mut AstNode, allow_assign: bool) -> Result<(), SvError> {
     let is_block = parse_has_begin(ts,node)?;
-    parse_class_stmt(ts,node, is_block, is_block, false, allow_assign)?;
+    if is_block {
+        parse_class_block(ts,node, allow_assign)?;
+    } else {
+        parse_class_stmt(ts,node, false, false, false, allow_assign)?;
+    }
     if is_block && node.attr["block"]!="" {
         check_label(ts, &node.attr["block"])?;
     }
@@ -604,6 +645,18 @@ pub fn parse_class_stmt_or_block(ts : &mut TokenStream, node: &mut AstNode, allo
 }
 
 
+/// Parse the statements of a begin/end block, skipping statements with a syntax error
+pub fn parse_class_block(ts : &mut TokenStream, node: &mut AstNode, allow_assign: bool) -> Result<(), SvError> {
+    let mut allow_decl = true;
+    loop {
+        match parse_class_stmt(ts,node, true, allow_decl, false, allow_assign) {
+            Ok(_) => return Ok(()),
+            Err(e) => ts.resync(node,e)?,
+        }
+        allow_decl = false;
+    }
+}
+
 /// Parse any statement in a process
 pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: bool, mut allow_decl: bool, allow_port: bool, allow_assign: bool) -> Result<bool, SvError> {
     ts.rewind(0);
@@ -618,10 +671,11 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
                 let mut n = AstNode::new(AstNodeKind::Block, t.pos);
                 ts.flush(1);
                 parse_label(ts,&mut n,"block".to_owned())?;
-                parse_class_stmt(ts,&mut n, true, true,false, allow_assign)?;
+                parse_class_block(ts,&mut n, allow_assign)?;
                 if n.attr["block"]!="" {
                     check_label(ts, &n.attr["block"])?;
                 }
+                n.end = ts.last_end;
                 node.child.push(n);
             }
             TokenKind::KwIf   => parse_class_if_else(ts,node, allow_assign)?,
@@ -632,14 +686,14 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             TokenKind::KwForever => {
                 ts.flush(1);
                 let mut n = AstNode::new(AstNodeKind::Loop, t.pos);
-                n.attr.insert("kind".to_owned(), t.value);
+                n.attr.insert("kind".to_owned(), t.value.into());
                 parse_class_stmt_or_block(ts, &mut n, allow_assign)?;
                 node.child.push(n);
             }
             TokenKind::KwForeach => {
                 ts.flush(1);
                 let mut n = AstNode::new(AstNodeKind::Loop, t.pos);
-                n.attr.insert("kind".to_owned(), t.value);
+                n.attr.insert("kind".to_owned(), t.value.into());
                 t = ts.next_t(false)?;
                 if t.kind!=TokenKind::ParenLeft {
                     return Err(SvError::syntax(t,"foreach. Expecting ("));
@@ -661,7 +715,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             TokenKind::KwRepeat | TokenKind::KwWhile  => {
                 ts.flush(1);
                 let mut n = AstNode::new(AstNodeKind::Loop, t.pos);
-                n.attr.insert("kind".to_owned(), t.value);
+                n.attr.insert("kind".to_owned(), t.value.into());
                 t = ts.next_t(false)?;
                 if t.kind != TokenKind::ParenLeft {
                     return Err(SvError::syntax(t, &format!("{} loop, expecting (", n.attr["kind"].clone() )));
@@ -675,7 +729,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             TokenKind::KwDo   => {
                 ts.flush(1);
                 let mut n = AstNode::new(AstNodeKind::Loop, t.pos);
-                n.attr.insert("kind".to_owned(), t.value);
+                n.attr.insert("kind".to_owned(), t.value.into());
                 let mut ns = AstNode::new(AstNodeKind::Statement, ts.get_pos());
                 parse_class_stmt_or_block(ts, &mut ns, allow_assign)?;
                 t = ts.next_t(false)?;
@@ -712,10 +766,11 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
                     t = ts.next_t(true)?;
                     if t.kind == TokenKind::KwJoin {
                         ts.flush(1);
-                        n.attr.insert("join".to_owned(), t.value);
+                        n.attr.insert("join".to_owned(), t.value.into());
                         if has_label {
                             check_label(ts, &n.attr["label"])?;
                         }
+                        n.end = ts.last_end;
                         break;
                     } else {
                         ts.rewind(1);
@@ -731,7 +786,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             }
             TokenKind::KwDisable => {
                 let mut n = AstNode::new(AstNodeKind::Statement, t.pos);
-                n.attr.insert("kind".to_owned(), t.value);
+                n.attr.insert("kind".to_owned(), t.value.into());
                 ts.flush(1);
                 t = ts.next_t(true)?;
                 match t.kind {
@@ -739,7 +794,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
                     TokenKind::Ident => n.child.push(parse_ident_hier(ts)?),
                     _ => return Err(SvError::syntax(t, "disable statement, expecting fork or identifier" ))
                 }
-                n.attr.insert("value".to_owned(), t.value);
+                n.attr.insert("value".to_owned(), t.value.into());
                 expect_t!(ts,"disable statement",TokenKind::SemiColon);
             }
             TokenKind::Macro => {
@@ -759,7 +814,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             }
             TokenKind::KwSuper | TokenKind::KwThis => {parse_assign_or_call(ts,node,ExprCntxt::Stmt)?;},
             TokenKind::Ident => {
-                let name = t.value;
+                let name = String::from(t.value);
                 t = ts.next_t(true)?;
                 let has_label = t.kind == TokenKind::Colon;
                 if has_label {
@@ -840,7 +895,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             TokenKind::KwBreak | TokenKind::KwContinue => {
                 ts.flush(1);
                 let mut n = AstNode::new(AstNodeKind::Branch, t.pos);
-                n.attr.insert("kind".to_owned(),t.value);
+                n.attr.insert("kind".to_owned(),t.value.into());
                 node.child.push(n);
                 expect_t!(ts,"control flow",TokenKind::SemiColon);
             },
@@ -855,7 +910,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             TokenKind::At => {
                 ts.flush(1); // Consume At
                 let mut n = AstNode::new(AstNodeKind::EventCtrl, t.pos);
-                n.attr.insert("kind".to_owned(),t.value);
+                n.attr.insert("kind".to_owned(),t.value.into());
                 n.child.push(parse_sensitivity(ts,false)?);
                 t = ts.next_t(true)?;
                 if t.kind != TokenKind::SemiColon {
@@ -865,7 +920,8 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
                     // break;
                 }
             }
-            TokenKind::Hash => {
+            // Delay or cycle delay (##N)
+            TokenKind::Hash | TokenKind::Hash2 => {
                 let mut n = parse_delay(ts)?;
                 t = ts.next_t(true)?;
                 if t.kind != TokenKind::SemiColon {
@@ -878,7 +934,7 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
             }
             TokenKind::KwWait => {
                 let mut n = AstNode::new(AstNodeKind::Wait, t.pos);
-                n.attr.insert("kind".to_owned(),t.value);
+                n.attr.insert("kind".to_owned(),t.value.into());
                 ts.flush(1); // Consume token
                 t = ts.next_t(false)?;
                 match t.kind {
@@ -899,7 +955,9 @@ pub fn parse_class_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: boo
                 }
                 node.child.push(n);
             }
-            TokenKind::KwAssert => parse_assert(ts,node)?,
+            TokenKind::KwAssert |
+            TokenKind::KwCover  => parse_assert(ts,node)?,
+            TokenKind::Keyword if t.value=="assume" => parse_assert(ts,node)?,
             TokenKind::Casting => {
                 ts.flush(1); // Consume
                 if t.value!="void'" {
@@ -946,13 +1004,13 @@ pub fn parse_assign_or_call(ts : &mut TokenStream, node: &mut AstNode, cntxt: Ex
     let mut is_decl = false;
     let mut nm;
     if t.kind == TokenKind::KwAssign {
-        n.attr.insert("assign".to_owned(),t.value);
+        n.attr.insert("assign".to_owned(),t.value.into());
         ts.flush(1);
         t = ts.next_t(true)?;
     }
     match t.kind {
         TokenKind::OpIncrDecr => {
-            n.attr.insert("incr_decr".to_owned(),t.value);
+            n.attr.insert("incr_decr".to_owned(),t.value.into());
             n.attr.insert("op".to_owned(),"pre".to_owned());
             ts.flush(1);
             n.child.push(parse_member_or_call(ts, false)?);
@@ -979,16 +1037,16 @@ pub fn parse_assign_or_call(ts : &mut TokenStream, node: &mut AstNode, cntxt: Ex
     match t.kind {
         TokenKind::OpEq | TokenKind::OpCompAss if !is_decl => {
             ts.flush(1); // Consume the operand
-            n.attr.insert("kind".to_owned(),t.value);
+            n.attr.insert("kind".to_owned(),t.value.into());
             n.child.push(nm);
             n.child.push(parse_expr(ts,cntxt.clone(),false)?);
         }
         TokenKind::OpLTE if !is_decl => {
             ts.flush(1); // Consume the operand
-            n.attr.insert("kind".to_owned(),t.value);
+            n.attr.insert("kind".to_owned(),t.value.into());
             n.child.push(nm);
             t = ts.next_t(true)?;
-            if t.kind==TokenKind::Hash {
+            if t.kind==TokenKind::Hash || t.kind==TokenKind::Hash2 {
                 n.child.push(parse_delay(ts)?);
             } else {
                 ts.rewind(1);
@@ -997,7 +1055,7 @@ pub fn parse_assign_or_call(ts : &mut TokenStream, node: &mut AstNode, cntxt: Ex
         }
         TokenKind::OpIncrDecr if !is_decl => {
             ts.flush(1); // Consume the operand
-            n.attr.insert("incr_decr".to_owned(),t.value);
+            n.attr.insert("incr_decr".to_owned(),t.value.into());
             n.attr.insert("op".to_owned(),"post".to_owned());
             n.child.push(nm);
         }
@@ -1037,7 +1095,7 @@ pub fn parse_class_if_else(ts : &mut TokenStream, node: &mut AstNode, allow_assi
     let mut t = ts.next_t(false)?;
     let mut node_if = AstNode::new(AstNodeKind::Branch, t.pos);
     if t.kind==TokenKind::KwPriority || t.kind==TokenKind::KwUnique || t.kind==TokenKind::KwUnique0 {
-        node_if.attr.insert("prio".to_owned(),t.value);
+        node_if.attr.insert("prio".to_owned(),t.value.into());
         t = ts.next_t(false)?;
     }
     if t.kind==TokenKind::KwElse {
@@ -1118,7 +1176,7 @@ pub fn parse_class_for(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),
                 is_decl = true;
                 if t.kind==TokenKind::KwVar {
                     ts.flush(1);
-                    node.attr.insert("nettype".to_owned(), t.value);
+                    node.attr.insert("nettype".to_owned(), t.value.into());
                 } else {
                     ts.rewind(1);
                 }
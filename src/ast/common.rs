@@ -1,13 +1,17 @@
 // This file is part of sv_check and subject to the terms of MIT Licence
 // Copyright (c) 2019, clams@mail.com
 
+use std::path::PathBuf;
+
 use crate::reporter::{REPORTER, MsgID};
-use crate::lex::token::TokenKind;
+use crate::lex::token::{Token,TokenKind,KwVersion};
 use crate::lex::token_stream::{TokenStream};
 use crate::ast::{
     MacroDef,
     astnode::{AstNode, AstNodeKind},
-    class::{parse_func,parse_class_stmt_or_block}
+    class::{parse_func,parse_task,parse_class_stmt_or_block},
+    sva::{parse_sva_assert,parse_sva_expr},
+    constraint::parse_constraint
 };
 use crate::error::{SvErrorKind, SvError};
 
@@ -71,7 +75,11 @@ pub enum ExprCntxt {
     StmtList, Stmt, CaseItemList,
     FieldList, Sensitivity,
     BracketMsb, BracketLsb,
-    Question
+    Question,
+    Cover, // Coverpoint/bins expression: end on iff, with, { or ;
+    Sva,   // Boolean operand of a sequence/property: end on any sequence/property operator
+    Constraint,// Constraint expression: end on ;, ->, dist, := or :/
+    Specify    // Specify block value or event: end on ; : ) or &&&
 }
 
 /// Parse an import/export statement
@@ -79,7 +87,7 @@ pub fn parse_import(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvE
     ts.rewind(1);
     let mut t = expect_t!(ts,"import/export",TokenKind::KwImport,TokenKind::KwExport);
     let mut n = AstNode::new(AstNodeKind::Import, t.pos);
-    n.attr.insert("kind".to_owned(),t.value);
+    n.attr.insert("kind".to_owned(),t.value.into());
     t = ts.next_t(true)?;
     match t.kind {
         TokenKind::Ident => {
@@ -87,10 +95,11 @@ pub fn parse_import(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvE
             loop {
                 t = expect_t!(ts,"import",TokenKind::Ident);
                 let mut ni = AstNode::new(AstNodeKind::Identifier, t.pos);
-                ni.attr.insert("pkg_name".to_owned(),t.value);
+                ni.end = t.end;
+                ni.attr.insert("pkg_name".to_owned(),t.value.into());
                 expect_t!(ts,"import",TokenKind::Scope);
                 t = expect_t!(ts,"import",TokenKind::Ident,TokenKind::OpStar);
-                ni.attr.insert("name".to_owned(),t.value);
+                ni.attr.insert("name".to_owned(),t.value.into());
                 n.child.push(ni);
                 t = ts.next_t(false)?;
                 match t.kind {
@@ -104,14 +113,35 @@ pub fn parse_import(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvE
             if t.value!="DPI-C" && t.value!="DPI"  {
                 return Err(SvError::syntax(t, "import DPI. Expecting DPI-C, DPI or package identifier"))
             }
-            n.attr.insert("dpi".to_owned(),t.value);
+            n.attr.insert("dpi".to_owned(),t.value.into());
             ts.flush(1);
+            let is_import = n.attr["kind"]=="import";
+            t = ts.next_t(true)?;
+            if is_import && (t.kind==TokenKind::KwPure || t.kind==TokenKind::KwContext) {
+                ts.flush(1);
+                n.attr.insert("property".to_owned(),t.value.into());
+            } else {ts.rewind(1);}
+            // Optional C name: c_name = function/task
             t = ts.next_t(true)?;
-            if t.kind==TokenKind::KwPure || t.kind==TokenKind::KwContext {
+            if t.kind==TokenKind::Ident {
                 ts.flush(1);
-                n.attr.insert("property".to_owned(),t.value);
+                expect_t!(ts,"DPI C name",TokenKind::OpEq);
+                n.attr.insert("c_name".to_owned(),t.value.into());
             } else {ts.rewind(1);}
-            parse_func(ts, &mut n, false, true)?;
+            t = ts.next_t(true)?;
+            match t.kind {
+                TokenKind::KwFunction if is_import => parse_func(ts, &mut n, false, true)?,
+                TokenKind::KwTask     if is_import => parse_task(ts, &mut n, true)?,
+                // Export: only the name of the function/task
+                TokenKind::KwFunction | TokenKind::KwTask => {
+                    ts.flush(1);
+                    n.attr.insert("method".to_owned(),t.value.into());
+                    t = expect_t!(ts,"DPI export",TokenKind::Ident);
+                    n.attr.insert("name".to_owned(),t.value.into());
+                    expect_t!(ts,"DPI export",TokenKind::SemiColon);
+                }
+                _ => return Err(SvError::syntax(t, "DPI import/export. Expecting function or task"))
+            }
         }
         _ => return Err(SvError::syntax(t, "import. Expecting DPI-C or package identifier"))
     }
@@ -144,6 +174,7 @@ pub fn parse_param_decl(ts : &mut TokenStream, is_body: bool) -> Result<AstNode,
     let cntxt = if is_body {ExprCntxt::StmtList} else {ExprCntxt::ArgList};
     let mut ni = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
     parse_var_decl_name(ts,&mut ni,cntxt.clone(),false,true)?;
+    ni.end = ts.last_end;
     node.child.push(ni);
     // Parse extra name
     parse_opt_ident_list(ts,&mut node,cntxt)?; // TODO : allow type when node.attr.get("type")==Some(&"type".to_string())
@@ -171,7 +202,7 @@ pub fn parse_port_decl(ts : &mut TokenStream, allow_void : bool, cntxt: ExprCntx
     // direction/interface
     match t.kind {
         TokenKind::KwInput | TokenKind::KwOutput | TokenKind::KwInout | TokenKind::KwRef => {
-            node.attr.insert("dir".to_owned(), t.value);
+            node.attr.insert("dir".to_owned(), t.value.into());
             ts.flush_rd();
         }
         // Interface / User-defined type
@@ -187,13 +218,13 @@ pub fn parse_port_decl(ts : &mut TokenStream, allow_void : bool, cntxt: ExprCntx
                     if nnt.kind != TokenKind::Ident {
                         return Err(SvError::syntax(t, "port type"))
                     }
-                    node.attr.insert("intf".to_owned(), t.value);
-                    node.attr.insert("modport".to_owned(), nnt.value);
+                    node.attr.insert("intf".to_owned(), t.value.into());
+                    node.attr.insert("modport".to_owned(), nnt.value.into());
                     ts.flush_rd();
                 }
                 // Another ident : No modport, nt is the port name, rewind it
                 TokenKind::Ident => {
-                    node.attr.insert("type".to_owned(), t.value);
+                    node.attr.insert("type".to_owned(), t.value.into());
                     ts.flush(1);
                     ts.rewind(1);
                 }
@@ -203,7 +234,7 @@ pub fn parse_port_decl(ts : &mut TokenStream, allow_void : bool, cntxt: ExprCntx
                 }
                 // Hash : t is a parameterized class
                 TokenKind::Hash => {
-                    node.attr.insert("type".to_owned(), t.value);
+                    node.attr.insert("type".to_owned(), t.value.into());
                     ts.flush(2); // Consume Ident and hash
                     parse_port_connection(ts,&mut node,true)?;
                     // println!("parse_port_decl: {}", node);
@@ -228,8 +259,8 @@ pub fn parse_port_decl(ts : &mut TokenStream, allow_void : bool, cntxt: ExprCntx
                     if nnt.kind != TokenKind::Ident {
                         return Err(SvError::new(SvErrorKind::Syntax, t, format!("Unexpected {} ({:?}) for port type, expecting modport",nnt.value, nnt.kind)))
                     }
-                    node.attr.insert("intf".to_owned(), t.value);
-                    node.attr.insert("modport".to_owned(), nnt.value);
+                    node.attr.insert("intf".to_owned(), t.value.into());
+                    node.attr.insert("modport".to_owned(), nnt.value.into());
                     ts.flush_rd();
                 }
                 // Another ident : No modport, nt is the port name, rewind it
@@ -256,6 +287,7 @@ pub fn parse_port_decl(ts : &mut TokenStream, allow_void : bool, cntxt: ExprCntx
     }
     let mut ni = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
     parse_var_decl_name(ts,&mut ni,cntxt.clone(),false,false)?;
+    ni.end = ts.last_end;
     node.child.push(ni);
     parse_opt_ident_list(ts,&mut node,cntxt)?;
     // println!("port_decl: {:?}", node);
@@ -272,7 +304,7 @@ pub fn parse_signal_decl_list(ts : &mut TokenStream, node: &mut AstNode) -> Resu
     let t = ts.next_t(true)?;
     match t.kind {
         TokenKind::KwRand => {
-            node_sig.attr.insert("rand".to_owned(), t.value);
+            node_sig.attr.insert("rand".to_owned(), t.value.into());
             ts.flush(1);
         }
         _ => ts.rewind(1)
@@ -288,6 +320,7 @@ pub fn parse_signal_decl_list(ts : &mut TokenStream, node: &mut AstNode) -> Resu
     // Parse name
     let mut ni = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
     parse_var_decl_name(ts,&mut ni,ExprCntxt::StmtList,false,false)?;
+    ni.end = ts.last_end;
     node_sig.child.push(ni);
     // Continue parsing
     parse_opt_ident_list(ts,&mut node_sig,ExprCntxt::StmtList)?;
@@ -303,7 +336,7 @@ pub fn parse_signal_decl_list(ts : &mut TokenStream, node: &mut AstNode) -> Resu
 pub fn parse_var_decl_name(ts : &mut TokenStream, node : &mut AstNode, cntxt: ExprCntxt, need_value: bool, allow_type: bool) -> Result<(), SvError> {
     // Signal name
     let mut t = expect_t!(ts,"variable declaration",TokenKind::Ident);
-    node.attr.insert("name".to_owned(), t.value);
+    node.attr.insert("name".to_owned(), t.value.into());
     // Optional Unpacked dimension : [x][y:z]
     parse_opt_slice(ts,node,true,true)?;
     // Optional Default value i.e. "= expr"
@@ -328,7 +361,7 @@ pub fn parse_net_type(ts : &mut TokenStream, node : &mut AstNode) -> Result<(),
     }
     if t.kind==TokenKind::KwNetType || t.kind==TokenKind::KwSupply || t.kind==TokenKind::KwVar || t.kind==TokenKind::KwInterconnect {
         let allow_strength = t.kind!=TokenKind::KwVar;
-        node.attr.insert("nettype".to_owned(),t.value);
+        node.attr.insert("nettype".to_owned(),t.value.into());
         ts.flush(1);
         t = ts.next_t(true)?;
        // println!("[parse_net_type] next = {}", t);
@@ -340,7 +373,7 @@ pub fn parse_net_type(ts : &mut TokenStream, node : &mut AstNode) -> Result<(),
         }
         // Check for optional vector info
         if t.kind==TokenKind::KwVector{
-            node.attr.insert("vector".to_owned(),t.value);
+            node.attr.insert("vector".to_owned(),t.value.into());
             ts.flush(1);
         }
     }
@@ -354,10 +387,10 @@ pub fn parse_strength(ts : &mut TokenStream, node : &mut AstNode) -> Result<(),
     let mut t = ts.next_t(false)?;
     match t.kind {
         TokenKind::KwCharge => {
-            node.attr.insert("charge".to_owned(),t.value);
+            node.attr.insert("charge".to_owned(),t.value.into());
         }
         TokenKind::KwDrive | TokenKind::KwSupply => {
-            let mut s = t.value;
+            let mut s = String::from(t.value);
             t = ts.next_t(false)?;
             if t.kind!=TokenKind::Comma {
                 return Err(SvError::syntax(t, "drive strength declaration, expecting ,"))
@@ -396,7 +429,8 @@ pub fn parse_opt_scope(ts : &mut TokenStream, node : &mut AstNode) -> Result<(),
         return Ok(());
     }
     let mut n = AstNode::new(AstNodeKind::Scope, t_ident.pos);
-    n.attr.insert("name".to_owned(),t_ident.value);
+    n.end = t_ident.end;
+    n.attr.insert("name".to_owned(),t_ident.value.into());
     ts.flush(2);
     // TODO : check for another scope after
     node.child.push(n);
@@ -411,7 +445,7 @@ pub fn parse_data_type(ts : &mut TokenStream, node : &mut AstNode, allowed_flag:
     let mut get_next    = false;
     ts.rewind(0); // Ensure we start analyzing data from
     let mut t = ts.next_t(true)?;
-    let mut s = t.value.clone();
+    let mut s = t.value.to_string();
     // println!("[parse_data_type] First Token = {}", t);
     let allow_type_ref = node.attr.contains_key("nettype");
     // First word of a data type
@@ -451,7 +485,7 @@ pub fn parse_data_type(ts : &mut TokenStream, node : &mut AstNode, allowed_flag:
                 TokenKind::Scope => {
                     parse_opt_scope(ts,node)?;
                     let nnt = expect_t!(ts,"data type",TokenKind::Ident);
-                    s = nnt.value;
+                    s = nnt.value.into();
                     get_next=true;
                 }
                 // Another ident : t is the type and nt is the port/signal name
@@ -492,13 +526,13 @@ pub fn parse_data_type(ts : &mut TokenStream, node : &mut AstNode, allowed_flag:
     // println!("[parse_data_type] -> has_sign={} : {}", has_signing, t );
     //
     if has_signing && t.kind == TokenKind::KwSigning {
-        node.attr.insert("signing".to_owned(), t.value);
+        node.attr.insert("signing".to_owned(), t.value.into());
         ts.flush(1);
         t = ts.next_t(true)?;
     }
     // println!("[parse_data_type] -> has_width={} : {}", has_width, t );
     if has_width && t.kind == TokenKind::KwVector {
-        node.attr.insert("vector".to_owned(),t.value);
+        node.attr.insert("vector".to_owned(),t.value.into());
         ts.flush(1);
         t = ts.next_t(true)?;
     }
@@ -527,7 +561,7 @@ pub fn parse_opt_slice(ts : &mut TokenStream, node: &mut AstNode, allow_range: b
             TokenKind::SquareRight => ts.flush(1),
             TokenKind::OpStar if allow_type => {
                 // TODO: better align with specified type ...
-                n.attr.insert("associative".to_owned(),t.value);
+                n.attr.insert("associative".to_owned(),t.value.into());
                 ts.flush(1);
                 expect_t!(ts,"size",TokenKind::SquareRight);
             }
@@ -540,7 +574,7 @@ pub fn parse_opt_slice(ts : &mut TokenStream, node: &mut AstNode, allow_range: b
                     // The expression parser ends either on : or ]
                     t = ts.next_t(false)?;
                     if t.kind == TokenKind::Colon || t.kind == TokenKind::OpRange {
-                        n.attr.insert("range".to_owned(),t.value);
+                        n.attr.insert("range".to_owned(),t.value.into());
                         n.child.push(parse_expr(ts,ExprCntxt::BracketLsb,false)?);
                         ts.flush(1); // Consume the ]
                     }
@@ -559,7 +593,7 @@ pub fn parse_opt_slice(ts : &mut TokenStream, node: &mut AstNode, allow_range: b
 pub fn parse_opt_signess(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),SvError> {
     let t = ts.next_t(true)?;
     if t.kind == TokenKind::KwSigning {
-        node.attr.insert("signing".to_owned(), t.value);
+        node.attr.insert("signing".to_owned(), t.value.into());
         ts.flush(1);
     } else {
         ts.rewind(1);
@@ -584,7 +618,8 @@ pub fn parse_ident_hier(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     let mut node = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
     parse_opt_scope(ts,&mut node)?;
     let mut t = expect_t!(ts,"identifier", TokenKind::Ident, TokenKind::KwThis);
-    node.attr.insert("name".to_owned(),t.value);
+    node.end = t.end;
+    node.attr.insert("name".to_owned(),t.value.into());
     parse_opt_slice(ts,&mut node,true,false)?;
     t = ts.next_t(true)?;
     if t.kind == TokenKind::Dot {
@@ -598,6 +633,35 @@ pub fn parse_ident_hier(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     Ok(node)
 }
 
+/// Parse a bound of a range: expression or $
+pub fn parse_range_bound(ts : &mut TokenStream, cntxt: ExprCntxt) -> Result<AstNode, SvError> {
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::Dollar {
+        ts.flush(1);
+        let mut n = AstNode::new(AstNodeKind::Value, t.pos);
+        n.attr.insert("value".to_owned(),t.value.into());
+        return Ok(n);
+    }
+    ts.rewind(1);
+    parse_expr(ts,cntxt,false)
+}
+
+/// Check if the open square bracket (already peeked) starts a sequence repetition: [* [= [-> or [+]
+pub fn is_sva_repeat(ts : &mut TokenStream) -> Result<bool, SvError> {
+    let t = ts.next_t(true)?;
+    let is_repeat = match t.kind {
+        TokenKind::OpStar | TokenKind::OpEq | TokenKind::OpImpl => true,
+        TokenKind::OpPlus => {
+            let nt = ts.next_t(true)?;
+            ts.rewind(1);
+            nt.kind == TokenKind::SquareRight
+        }
+        _ => false
+    };
+    ts.rewind(1);
+    Ok(is_repeat)
+}
+
 pub fn parse_ident_list(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),SvError> {
     let mut expect_ident = true;
     loop {
@@ -605,7 +669,8 @@ pub fn parse_ident_list(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),
         match t.kind {
             TokenKind::Ident if expect_ident => {
                 let mut n = AstNode::new(AstNodeKind::Identifier, t.pos);
-                n.attr.insert("name".to_owned(),t.value);
+                n.end = t.end;
+                n.attr.insert("name".to_owned(),t.value.into());
                 parse_opt_slice(ts,&mut n,true, true)?; // TODO CHECKD IF TYPE IS ALLOWED
                 parse_opt_init_value(ts,&mut n,ExprCntxt::StmtList)?;
                 node.child.push(n);
@@ -649,7 +714,8 @@ pub fn parse_opt_ident_list(ts : &mut TokenStream, node: &mut AstNode,cntxt: Exp
         }
         ts.flush(2);
         let mut n = AstNode::new(AstNodeKind::Identifier, tid.pos);
-        n.attr.insert("name".to_owned(),tid.value);
+        n.end = tid.end;
+        n.attr.insert("name".to_owned(),tid.value.into());
         // Optional Unpacked dimension : [x][y:z]
         parse_opt_slice(ts,&mut n,true,true)?;
         // Optional init value
@@ -680,24 +746,23 @@ pub fn parse_enum(ts : &mut TokenStream, is_typedef: bool) -> Result<AstNode,SvE
     // Optionnal data type
     match t.kind {
         TokenKind::TypeIntAtom => {
-            let mut s = t.value;
+            node_e.attr.insert("type".to_owned(),t.value.into());
             // Check for optional signing info
             t = ts.next_t(true)?;
             if t.kind == TokenKind::KwSigning {
-                s.push_str(&t.value);
+                node_e.attr.insert("signing".to_owned(), t.value.into());
                 t = ts.next_t(true)?;
             }
-            node_e.attr.insert("type".to_owned(),s);
         }
         TokenKind::TypeIntVector => {
-            node_e.attr.insert("type".to_owned(),t.value);
+            node_e.attr.insert("type".to_owned(),t.value.into());
             ts.flush(1);
             // Check for optional signing info
             t = ts.next_t(true)?;
             // ts.display_status("");
             if t.kind == TokenKind::KwSigning {
                 ts.flush(1); 
-                node_e.attr.insert("signing".to_owned(), t.value);
+                node_e.attr.insert("signing".to_owned(), t.value.into());
                 t = ts.next_t(true)?;
             }
             // Check for optional dimension
@@ -708,7 +773,7 @@ pub fn parse_enum(ts : &mut TokenStream, is_typedef: bool) -> Result<AstNode,SvE
             }
         }
         TokenKind::Ident => {
-            node_e.attr.insert("type".to_owned(),t.value);
+            node_e.attr.insert("type".to_owned(),t.value.into());
             t = ts.next_t(true)?;
         }
         TokenKind::CurlyLeft => {}
@@ -729,7 +794,7 @@ pub fn parse_enum(ts : &mut TokenStream, is_typedef: bool) -> Result<AstNode,SvE
         // Capture enum identifier
         t = ts.next_t(false)?;
         let mut node_id = AstNode::new(AstNodeKind::EnumIdent, t.pos);
-        node_id.attr.insert("name".to_owned(), t.value);
+        node_id.attr.insert("name".to_owned(), t.value.into());
         // Optional range
         parse_opt_slice(ts,&mut node_e,true,false)?;
         // Optional value
@@ -768,7 +833,7 @@ pub fn parse_struct(ts : &mut TokenStream) -> Result<AstNode,SvError> {
         // Optional signing
         if t.kind==TokenKind::KwSigning {
             ts.flush_rd();
-            node.attr.insert("signing".to_owned(), t.value);
+            node.attr.insert("signing".to_owned(), t.value.into());
             t = ts.next_t(true)?;
         }
     }
@@ -850,7 +915,7 @@ pub fn parse_typedef(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
             ts.flush(1);
             let mut node_type = AstNode::new(AstNodeKind::Type, t.pos);
             node_type.attr.insert("type".to_owned(),"class".to_owned());
-            node_type.attr.insert("name".to_owned(),t.value);
+            node_type.attr.insert("name".to_owned(),t.value.into());
             node_def.child.push(node_type);
         }
         TokenKind::KwVirtual => node_def.child.push(parse_vintf_type(ts)?),
@@ -858,7 +923,7 @@ pub fn parse_typedef(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
     }
     // Parse type name
     t = expect_t!(ts,"typedef", TokenKind::Ident, TokenKind::Macro);
-    node_def.attr.insert("name".to_owned(),t.value);
+    node_def.attr.insert("name".to_owned(),t.value.into());
     // Optional unpacked dimension
     parse_opt_slice(ts,&mut node_def,true,true)?;
     // Expect semi-colon
@@ -888,14 +953,14 @@ pub fn parse_nettype(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
     }
     // type name
     t = expect_t!(ts,"user-defined nettype", TokenKind::Ident);
-    node_def.attr.insert("name".to_owned(),t.value);
+    node_def.attr.insert("name".to_owned(),t.value.into());
     // Optional resolution function name
     t = ts.next_t(true)?;
     match t.kind {
         TokenKind::KwWith => {
             ts.flush(1);
             t = expect_t!(ts,"nettype resolution function", TokenKind::Ident);
-            node_def.attr.insert("resolution".to_owned(),t.value);
+            node_def.attr.insert("resolution".to_owned(),t.value.into());
         }
         _ => ts.rewind(1)
     }
@@ -928,13 +993,13 @@ pub fn parse_port_connection(ts : &mut TokenStream, node: &mut AstNode, is_param
                 ts.flush(1); // Consume the dot
                 let mut nt = expect_t!(ts,"port name",TokenKind::Ident);
                 let mut node_p = AstNode::new( if is_param {AstNodeKind::Param} else {AstNodeKind::Port}, nt.pos);
-                node_p.attr.insert("name".to_owned(), nt.value);
+                node_p.attr.insert("name".to_owned(), nt.value.into());
                 nt = ts.next_t(true)?;
                 match nt.kind {
                     TokenKind::ParenLeft => {
                         ts.flush(1); // Consume the (
                         node_p.attr.insert("pos".to_owned(), format!("{}",cnt));
-                        node_p.child.push(parse_expr(ts,ExprCntxt::Arg,is_param)?);
+                        node_p.child.push(parse_port_actual(ts,ExprCntxt::Arg,is_param)?);
                         ts.flush(1); // Consume right parenthesis
                         node.child.push(node_p);
                         cnt += 1;
@@ -954,7 +1019,7 @@ pub fn parse_port_connection(ts : &mut TokenStream, node: &mut AstNode, is_param
                 ts.flush(1); // Consume the .*
                 allow_dot_star = false;
                 let mut node_p = AstNode::new( if is_param {AstNodeKind::Param} else {AstNodeKind::Port}, t.pos);
-                node_p.attr.insert("name".to_owned(), t.value);
+                node_p.attr.insert("name".to_owned(), t.value.into());
                 node.child.push(node_p);
             },
             TokenKind::ParenRight if is_first => break,
@@ -967,7 +1032,7 @@ pub fn parse_port_connection(ts : &mut TokenStream, node: &mut AstNode, is_param
                     let mut node_p = AstNode::new( if is_param {AstNodeKind::Param} else {AstNodeKind::Port}, t.pos);
                     node_p.attr.insert("name".to_owned(), "".to_owned());
                     node_p.attr.insert("pos".to_owned(), format!("{}",cnt));
-                    node_p.child.push(parse_expr(ts,ExprCntxt::ArgList,is_param)?);
+                    node_p.child.push(parse_port_actual(ts,ExprCntxt::ArgList,is_param)?);
                     node.child.push(node_p);
                     cnt += 1;
                 } else {
@@ -984,6 +1049,20 @@ pub fn parse_port_connection(ts : &mut TokenStream, node: &mut AstNode, is_param
 }
 
 
+// Port actual: checker instances accept events and sequence/property expressions,
+// kept as a simple expression when no sequence/property operator is used
+fn parse_port_actual(ts : &mut TokenStream, cntxt: ExprCntxt, is_param: bool) -> Result<AstNode, SvError> {
+    if is_param {
+        return parse_expr(ts,cntxt,is_param);
+    }
+    let mut node = parse_sva_expr(ts)?;
+    while node.kind==AstNodeKind::SvaExpr && node.child.len()==1
+        && !matches!(node.child[0].kind, AstNodeKind::Operation | AstNodeKind::Sensitivity | AstNodeKind::Branch) {
+        node = node.child.pop().unwrap();
+    }
+    Ok(node)
+}
+
 /// Parse
 pub fn parse_has_begin(ts : &mut TokenStream, node: &mut AstNode) -> Result<bool, SvError> {
     let mut is_block = false;
@@ -1012,7 +1091,7 @@ pub fn parse_label(ts : &mut TokenStream, node: &mut AstNode, attr_name: String)
         if t.kind!=TokenKind::Ident {
             return Err(SvError::syntax(t, "block name"))
         }
-        node.attr.insert(attr_name, t.value);
+        node.attr.insert(attr_name, t.value.into());
         Ok(true)
     } else {
         ts.rewind(1);
@@ -1052,22 +1131,25 @@ pub fn parse_delay (ts : &mut TokenStream) -> Result<AstNode, SvError> {
     ts.rewind(0);
     let mut t = ts.next_t(false)?;
     let mut node = AstNode::new(AstNodeKind::Wait, t.pos);
-    node.attr.insert("kind".to_owned(),t.value);
+    node.attr.insert("kind".to_owned(),t.value.into());
     t = ts.next_t(true)?;
     match t.kind {
         TokenKind::Integer | TokenKind::Real | TokenKind::Kw1step => {
             let mut nv = AstNode::new(AstNodeKind::Value, t.pos);
-            nv.attr.insert("value".to_owned(), t.value);
+            nv.end = t.end;
+            nv.attr.insert("value".to_owned(), t.value.into());
             ts.flush(1); // consume number
             // Optional time unit
             t = ts.next_t(true)?;
             match t.value.as_ref() {
                 "fs" |"ps" |"ns" |"us" |"ms" | "s" => {
-                    nv.attr.insert("value".to_owned(), t.value);
+                    nv.attr.get_mut("value").unwrap().push_str(&t.value);
+                    nv.end = t.end;
                     ts.flush(1);
                 }
                 _ => ts.rewind(1)
             }
+            node.child.push(nv);
         }
         TokenKind::Ident => node.child.push(parse_ident_hier(ts)?),
         TokenKind::ParenLeft => {
@@ -1085,7 +1167,7 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
     ts.rewind(0);
     let mut t = ts.next_t(false)?;
     let mut node_m = AstNode::new(AstNodeKind::Directive, t.pos);
-    node_m.attr.insert("name".to_owned(),t.value.clone());
+    node_m.attr.insert("name".to_owned(),t.value.to_string());
     // println!("[parse_macro] First token {:?}", t);
     match t.value.as_ref() {
         // Directive with no parameters
@@ -1095,17 +1177,25 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
         "`resetall"            |
         "`celldefine"          |
         "`endcelldefine"       |
-        "`nounconnected_drive" |
-        "`end_keywords"        => {}
+        "`nounconnected_drive" => {}
+        "`end_keywords"        => {
+            if ts.kw_stack.pop().is_none() {
+                return Err(SvError::syntax(t,"end_keywords. No matching `begin_keywords"));
+            }
+        }
         // Directive with one parameter
         "`ifndef" | "`ifdef" | "`elsif" | "`undef" => {
             t = expect_t!(ts,"Directive",TokenKind::Ident);
-            node_m.attr.insert("param".to_owned(), t.value);
+            node_m.attr.insert("param".to_owned(), t.value.into());
             ts.flush_rd();
         }
         "`begin_keywords" => {
             t = expect_t!(ts,"type",TokenKind::Str);
-            node_m.attr.insert("version".to_owned(),t.value);
+            match KwVersion::from_spec(&t.value) {
+                Some(v) => ts.kw_stack.push(v),
+                None => return Err(SvError::syntax(t,"begin_keywords. Unknown version specifier"))
+            }
+            node_m.attr.insert("version".to_owned(),t.value.into());
         }
         // Expect pull0 or pull1
         "`unconnected_drive" => {
@@ -1113,39 +1203,38 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
             if t.value != "pull0" && t.value != "pull1" {
                 return Err(SvError::syntax(t, "unconnected drive, Expecting pull0/1 !"));
             }
-            node_m.attr.insert("drive".to_owned(),t.value);
+            node_m.attr.insert("drive".to_owned(),t.value.into());
         }
         // Include directive : `include <file> , `include "file" or `include `mymacro
         "`include" => {
-            t = ts.next_t(true)?;        
+            t = ts.next_t(true)?;
+            let mut angle = false;
             match t.kind {
                 TokenKind::Macro => {}, // TO BE REMOVED
                 TokenKind::Str => {},
+                // File name between angle brackets is split in multiple tokens
                 TokenKind::OpLT => {
-                    t = ts.next_t(true)?;
-                    if t.kind!=TokenKind::Ident {
-                        return Err(SvError::syntax(t, "include directive"));
+                    let mut name = String::new();
+                    loop {
+                        let nt = ts.next_t(true)?;
+                        match nt.kind {
+                            TokenKind::OpGT => break,
+                            TokenKind::Ident | TokenKind::Dot | TokenKind::OpDiv | TokenKind::OpMinus if nt.pos.line==t.pos.line => name.push_str(&nt.value),
+                            _ => return Err(SvError::syntax(nt, "include directive"))
+                        }
                     }
-                    expect_t!(ts,"include directive",TokenKind::OpGT);
+                    t = Token::new(TokenKind::Str, name, t.pos);
+                    angle = true;
                 }
                 _ => return Err(SvError::syntax(t, "include directive"))
             }
             let fname = t.value.clone();
             // TO BE REMOVED
             ts.add_inc(&fname);
-            node_m.attr.insert("include".to_owned(),fname.clone());
-
-            // In case of include check if it has any define to be added to the tokenstream
-            if ts.project.ast_inc.contains_key(&fname) {
-                for (k,v) in ts.project.ast_inc[&fname].defines.clone() {
-                    // println!("[Include] {:?} -> {:?}", k,v);
-                    // println!("[parse_macro] Include Define {} ", k);
-                    ts.project.defines.insert(k,v);
-                }
-                // ts.project.ast_inc[&fname].defines.iter().for_each(|(k, v)| ts.project.defines.insert(k.to_string(),v));
-            } else {
-                ts.project.compile_inc(fname, t)?;
-            }
+            // Compile the include (if not already done) and add its defines to the tokenstream
+            let cur_file = ts.source.filename.clone();
+            let key = ts.project.compile_inc(&fname, angle, &cur_file, t)?;
+            node_m.attr.insert("include".to_owned(),key);
             ts.flush_rd();
         }
         // Define directive : first token is the name, followed by optional argument and then the content is all token until EOL
@@ -1159,20 +1248,17 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
             // TODO: temporary disable of macro redefined warning until compilation directive are properly handle
             // if ts.project.defines.contains_key(&name) {println!("[parse_macro] {} : line {} | Macro {} already defined", ts.source.get_filename(),t.pos,t.value);}
             let line_num = t.pos.line;
+            let def_pos = t.pos;
             ts.flush(1);
-            // Try next token: if new line, it was an empty define: add it to the list, rewind and return
-            // Also support End Of file
-            match ts.next_t(true) {
-                Ok(t) if t.pos.line != line_num => {
+            // Try next token: if new line (or end of file), it was an empty define: add it to the list and return
+            match ts.peek_on_line(line_num)? {
+                None => {
                     ts.project.defines.insert(name,None);
-                    ts.rewind(0);
                     return Ok(())
                 }
-                Err(e) => return if e.kind==SvErrorKind::Null {Ok(())} else {Err(e)},
-                // Err(e) => return if e.kind==SvErrorKind::Null || e.kind==SvErrorKind::Eof {Ok(())} else {Err(e)},
-                Ok(t_) => t = t_,
+                Some(t_) => t = t_,
             }
-            let mut macro_def = MacroDef::new();
+            let mut macro_def = MacroDef::new(ts.source.get_filename(), def_pos);
             if t.kind == TokenKind::ParenLeft {
                 // way to differentiate body from port might need more work ...
                 t = ts.next_t(true)?;
@@ -1183,7 +1269,7 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
                         t = ts.next_t(false)?;
                         match t.kind {
                             TokenKind::Ident => {
-                                let port_name = t.value;
+                                let port_name = String::from(t.value);
                                 // Optional Default value i.e. "= expr"
                                 t = ts.next_t(true)?;
                                 if t.kind == TokenKind::OpEq {
@@ -1210,22 +1296,34 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
             ts.project.defines.insert(name,Some(macro_def));
         }
         "`pragma" => {
+            let t_pragma = t.clone();
             t = expect_t!(ts,"type",TokenKind::Ident);
-            let line = t.pos.line;
-            node_m.attr.insert("pragma_name".to_owned(), t.value);
-            // Silently consume any token on current line
-            loop {
-                t = ts.next_t(true)?;
-                if t.pos.line != line {break;}
+            // Pragma expression is kept as raw text: it can contain anything until the end of line
+            let expr = ts.read_line();
+            let expr = expr.trim();
+            // Protected region: the content is encrypted (or meant to be) and is skipped
+            if t.value == "protect" {
+                let kw = expr.split(|c: char| c.is_whitespace() || c==',').find(|w| !w.is_empty()).unwrap_or("");
+                let kw_end = match kw {
+                    "begin" => Some("end"),
+                    "begin_protected" => Some("end_protected"),
+                    _ => None
+                };
+                if let Some(kw_end) = kw_end {
+                    rpt_s!(MsgID::InfoStatus, &format!("Skipping protected region starting at line {}", t.pos.line));
+                    ts.skip_protected(&t_pragma, kw_end)?;
+                    node_m.attr.insert("protected".to_owned(), kw.to_owned());
+                }
             }
-            ts.flush_keep(1); // Cleanup everything except last token
+            node_m.attr.insert("pragma_name".to_owned(), t.value.into());
+            node_m.attr.insert("pragma_expr".to_owned(), expr.to_owned());
         }
         "`default_nettype" => {
             t = ts.next_t(true)?;
             if t.kind!=TokenKind::KwNetType && (t.kind!=TokenKind::Ident || t.value != "none")  {
                 return Err(SvError::syntax(t,"default_nettype. Expecting net type (wire/tri/...) or none"));
             }
-            node_m.attr.insert("nettype".to_owned(),t.value);
+            node_m.attr.insert("nettype".to_owned(),t.value.into());
             ts.flush_rd();
         }
         "`timescale" => {
@@ -1236,11 +1334,19 @@ pub fn parse_macro(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvEr
         // Line : expect number string number
         "`line" => {
             t = expect_t!(ts,"type",TokenKind::Integer);
-            node_m.attr.insert("line".to_owned(),t.value);
+            node_m.attr.insert("line".to_owned(),t.value.into());
             t = expect_t!(ts,"type",TokenKind::Str);
-            node_m.attr.insert("filename".to_owned(),t.value);
+            node_m.attr.insert("filename".to_owned(),t.value.into());
             t = expect_t!(ts,"type",TokenKind::Integer);
-            node_m.attr.insert("level".to_owned(),t.value);
+            if t.value!="0" && t.value!="1" && t.value!="2" {
+                return Err(SvError::syntax(t,"line directive. Expecting level 0, 1 or 2"));
+            }
+            node_m.attr.insert("level".to_owned(),t.value.into());
+            // Remap position and filename of the following lines
+            let line = node_m.attr["line"].parse::<u32>().unwrap_or(1);
+            let fname = PathBuf::from(&node_m.attr["filename"]);
+            ts.source.set_line(line, node_m.pos.line, fname.clone());
+            rpt_line_fname!(fname);
         }
         // User define macro
         _ => {
@@ -1273,7 +1379,7 @@ pub fn parse_vintf_type(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     if t.kind!=TokenKind::Ident && t.kind!=TokenKind::Macro {
         return Err(SvError::syntax(t, "virtual interface. Expecting type identifier"));
     }
-    node_i.attr.insert("type".to_owned(),t.value);
+    node_i.attr.insert("type".to_owned(),t.value.into());
     // Optional parameter
     parse_opt_params!(ts,node_i);
     Ok(node_i)
@@ -1287,7 +1393,8 @@ pub fn parse_vintf(ts : &mut TokenStream, node : &mut AstNode) -> Result<(), SvE
         match t.kind {
             TokenKind::Ident => {
                 let mut n = AstNode::new(AstNodeKind::Identifier, t.pos);
-                n.attr.insert("name".to_owned(),t.value);
+                n.end = t.end;
+                n.attr.insert("name".to_owned(),t.value.into());
                 node_i.child.push(n);
                 loop_args_break_cont!(ts,"virtual interface",SemiColon);
             }
@@ -1321,7 +1428,7 @@ pub fn parse_struct_init(ts : &mut TokenStream, node : &mut AstNode) -> Result<(
                 TokenKind::KwDefault => {}
                 _ => return Err(SvError::syntax(t, "struct init. Expecting identifier/integer/default"))
             }
-            s = t.value;
+            s = t.value.into();
             expect_t!(ts,"struct init",TokenKind::Colon);
         }
         let mut n = parse_expr(ts,ExprCntxt::FieldList,false)?;
@@ -1342,13 +1449,13 @@ pub fn parse_case(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvErr
     let mut node_c = AstNode::new(AstNodeKind::Case, t.pos);
     // println!("[parse_case] First Token {}", t);
     if t.kind==TokenKind::KwPriority || t.kind==TokenKind::KwUnique || t.kind==TokenKind::KwUnique0 {
-        node_c.attr.insert("prio".to_owned(),t.value);
+        node_c.attr.insert("prio".to_owned(),t.value.into());
         t = ts.next_t(false)?;
     }
     if t.kind!=TokenKind::KwCase {
         return Err(SvError::syntax(t,"case statement. Expecting case"));
     }
-    node_c.attr.insert("kind".to_owned(),t.value);
+    node_c.attr.insert("kind".to_owned(),t.value.into());
     // Parse case expression
     expect_t!(ts,"case",TokenKind::ParenLeft);
     node_c.child.push(parse_expr(ts,ExprCntxt::Arg,false)?);
@@ -1358,11 +1465,11 @@ pub fn parse_case(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvErr
     match t.kind {
         TokenKind::KwMatch => {
             ts.flush_rd();
-            node_c.attr.insert("matching".to_owned(),t.value);
+            node_c.attr.insert("matching".to_owned(),t.value.into());
         }
         TokenKind::KwInside if node_c.attr["kind"]=="case" => {
             ts.flush_rd();
-            node_c.attr.insert("matching".to_owned(),t.value);
+            node_c.attr.insert("matching".to_owned(),t.value.into());
         }
         _ => ts.rewind(0)
     }
@@ -1414,6 +1521,7 @@ pub fn parse_case(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvErr
         node_c.child.push(node_i);
     }
     ts.flush_rd();
+    node_c.end = ts.last_end;
     // println!("[parse_case] {}", node_c);
     node.child.push(node_c);
     Ok(())
@@ -1432,8 +1540,8 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
         // println!("[parse_expr] Token = {}, (cntxt={:?}, first={}, allow ident={} / op={} )", t,cntxt, is_first, allow_ident,allow_op);
         match t.kind {
             // Statement: end on semi-colon or comma: rewind it and end
-            TokenKind::SemiColon if cntxt==ExprCntxt::StmtList || cntxt==ExprCntxt::Stmt => { ts.rewind(0); break; },
-            TokenKind::SemiColon if cntxt!=ExprCntxt::StmtList && cntxt!=ExprCntxt::Stmt  => return Err(SvError::syntax(t, "expression")),
+            TokenKind::SemiColon if cntxt==ExprCntxt::StmtList || cntxt==ExprCntxt::Stmt || cntxt==ExprCntxt::Cover || cntxt==ExprCntxt::Sva || cntxt==ExprCntxt::Constraint || cntxt==ExprCntxt::Specify => { ts.rewind(0); break; },
+            TokenKind::SemiColon => return Err(SvError::syntax(t, "expression")),
             // End on comma (if not inside curly braces)
             TokenKind::Comma => {
                 if cntxt==ExprCntxt::Stmt || cntxt==ExprCntxt::Arg || cntxt==ExprCntxt::ExprGroup  {
@@ -1444,6 +1552,8 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             }
             // Count parenthesis/braces to check if it is balanced
             TokenKind::TickCurly if is_first  => parse_struct_init(ts, &mut node_e)?,
+            // Start of bins list after a coverpoint expression
+            TokenKind::CurlyLeft if cntxt==ExprCntxt::Cover && !allow_ident => {ts.rewind(1);break;},
             TokenKind::CurlyLeft => {
                 ts.flush_rd();
                 allow_op = true;
@@ -1489,12 +1599,26 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                 // println!("{}", node_e);
             }
             TokenKind::CurlyRight  => {
-                if cntxt==ExprCntxt::FieldList {
+                if cntxt==ExprCntxt::FieldList || cntxt==ExprCntxt::Constraint {
                     ts.rewind(1); // reset to } token to be used by caller
                     break;
                 }
                 return Err(SvError::syntax(t, "expression"));
             }
+            // Sequence operators
+            TokenKind::Hash2      |
+            TokenKind::OpSeqRel   |
+            TokenKind::OpEquiv    |
+            TokenKind::KwOr       |
+            TokenKind::KwIff      |
+            TokenKind::KwElse     |
+            TokenKind::KwPrimIn   |
+            TokenKind::Keyword    if cntxt==ExprCntxt::Sva => {ts.rewind(1);break;},
+            TokenKind::SquareLeft if cntxt==ExprCntxt::Sva && is_sva_repeat(ts)? => {ts.rewind(1);break;},
+            // Constraint operators
+            TokenKind::OpImpl  |
+            TokenKind::OpDist  |
+            TokenKind::Keyword if cntxt==ExprCntxt::Constraint => {ts.rewind(1);break;},
             //
             TokenKind::SquareLeft  => {
                 ts.rewind(1);
@@ -1517,6 +1641,8 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             TokenKind::Colon if cntxt == ExprCntxt::BracketMsb   => {ts.rewind(1);break;},
             TokenKind::Colon if cntxt == ExprCntxt::Question     => {ts.rewind(1);break;},
             TokenKind::Colon if cntxt == ExprCntxt::CaseItemList => {ts.rewind(1);break;},
+            TokenKind::Colon if cntxt == ExprCntxt::Specify      => {ts.rewind(1);break;},
+            TokenKind::OpTimingAnd if cntxt == ExprCntxt::Specify => {ts.rewind(1);break;},
             TokenKind::ParenLeft if allow_ident => {
                 ts.flush(1); // Consume left parenthesis
                 node_e.child.push(parse_expr(ts,ExprCntxt::ExprGroup,false)?);
@@ -1526,20 +1652,21 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             },
             TokenKind::ParenRight => {
                 match cntxt {
-                    ExprCntxt::ArgList | ExprCntxt::Arg | ExprCntxt::ExprGroup | ExprCntxt::Sensitivity => {
+                    ExprCntxt::ArgList | ExprCntxt::Arg | ExprCntxt::ExprGroup | ExprCntxt::Sensitivity | ExprCntxt::Sva | ExprCntxt::Specify => {
                         ts.rewind(1);
                         break;
                     },
                     _ => return Err(SvError::syntax(t, "expression"))
                 }
             }
-            TokenKind::KwIff if cntxt==ExprCntxt::Sensitivity => {ts.rewind(1);break;},
+            TokenKind::KwIff if cntxt==ExprCntxt::Sensitivity || cntxt==ExprCntxt::Cover => {ts.rewind(1);break;},
+            TokenKind::KwWith if cntxt==ExprCntxt::Cover => {ts.rewind(1);break;},
             TokenKind::KwOr if cntxt==ExprCntxt::Sensitivity => {ts.rewind(1);break;},
             TokenKind::Casting => {
                 ts.flush(1); // Consume Casting operator
                 expect_t!(ts,"casting expression",TokenKind::ParenLeft);
                 let mut nc = parse_expr(ts,ExprCntxt::Arg,false)?;
-                nc.attr.insert("casting".to_owned(),t.value);
+                nc.attr.insert("casting".to_owned(),t.value.into());
                 ts.flush(1); // Consume right parenthesis
                 node_e.child.push(nc);
                 allow_ident = false;
@@ -1577,7 +1704,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                         "fs" |"ps" |"ns" |"us" |"ms" | "s" => {
                             match prev_tkind {
                                 TokenKind::Integer | TokenKind::Real => {
-                                    node_e.attr.insert("value".to_owned(), t.value);
+                                    node_e.attr.insert("value".to_owned(), t.value.into());
                                     ts.flush(1);
                                 }
                                 _ => return Err(SvError::syntax(t, "expression. Unexpected identifier"))
@@ -1601,7 +1728,8 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             TokenKind::KwNull  |
             TokenKind::Real     if allow_ident => {
                 let mut nc = AstNode::new(AstNodeKind::Value, t.pos);
-                nc.attr.insert("value".to_owned(), t.value);
+                nc.end = t.end;
+                nc.attr.insert("value".to_owned(), t.value.into());
                 node_e.child.push(nc);
                 allow_ident = false;
                 allow_op    = true;
@@ -1609,7 +1737,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             }
             TokenKind::Integer if prev_tkind==TokenKind::Integer => {
                 if t.value.starts_with('\'') {
-                    node_e.child.last_mut().unwrap().attr.insert("value".to_owned(), t.value);
+                    node_e.child.last_mut().unwrap().attr.insert("value".to_owned(), t.value.into());
                     // node_e.child.last().unwrap().attr.insert("value".to_owned(), format!("{}{}", node_e.attr["value"],t.value));
                     ts.flush(1);
                 } else {
@@ -1623,7 +1751,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                 allow_op    = false;
                 ts.flush(1);
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 node_e.child.push(nc);
                 // println!("{}", node_e);
             }
@@ -1641,7 +1769,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                 allow_op    = false;
                 ts.flush(1);
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 node_e.child.push(nc);
                 // println!("{}", node_e);
             },
@@ -1652,14 +1780,14 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                 allow_op    = false;
                 ts.flush(1);
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 // Check optional slice size : type/identifier/constant expression
                 t = ts.next_t(true)?;
                 match t.kind {
                     TokenKind::TypeIntVector |
                     TokenKind::TypeIntAtom   => {
                         let mut ncc = AstNode::new(AstNodeKind::Type, t.pos);
-                        ncc.attr.insert("type".to_owned(), t.value.clone());
+                        ncc.attr.insert("type".to_owned(), t.value.to_string());
                         ts.flush(1);
                         parse_opt_signess(ts,&mut ncc)?;
                         nc.child.push(ncc);
@@ -1667,7 +1795,8 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                     TokenKind::Ident => nc.child.push(parse_ident_hier(ts)?),
                     TokenKind::Integer => {
                         let mut ncc = AstNode::new(AstNodeKind::Value, t.pos);
-                        ncc.attr.insert("value".to_owned(), t.value);
+                        ncc.end = t.end;
+                        ncc.attr.insert("value".to_owned(), t.value.into());
                         nc.child.push(ncc);
                     }
                     _ => ts.rewind(1)
@@ -1707,12 +1836,12 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
                 allow_op    = false;
                 ts.flush(1);
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 node_e.child.push(nc);
             },
             TokenKind::KwInside if !is_first => {
                 let mut nc = AstNode::new(AstNodeKind::Constraint, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 ts.flush(1);
                 expect_t!(ts,"inside expression",TokenKind::CurlyLeft);
                 loop {
@@ -1726,7 +1855,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             }
             TokenKind::OpIncrDecr => {
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 if is_first || !allow_op {
                     nc.attr.insert("op".to_owned(),"pre".to_owned());
                     allow_ident = true;
@@ -1745,7 +1874,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             // Composed assignement are allowed in expression when surrounded by parenthesis
             TokenKind::OpCompAss if cntxt==ExprCntxt::ExprGroup && !is_first && allow_op => {
                 let mut nc = AstNode::new(AstNodeKind::Operation, t.pos);
-                nc.attr.insert("kind".to_owned(),t.value.clone());
+                nc.attr.insert("kind".to_owned(),t.value.to_string());
                 node_e.child.push(nc);
                 allow_ident = true;
                 allow_op    = false;
@@ -1774,7 +1903,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             //
             TokenKind::TypeIntVector if allow_type => {
                 node_e.kind = AstNodeKind::Type;
-                node_e.attr.insert("type".to_owned(), t.value.clone());
+                node_e.attr.insert("type".to_owned(), t.value.to_string());
                 ts.flush(1);
                 parse_opt_signess(ts,&mut node_e)?;
                 parse_opt_slice(ts,&mut node_e,true,false)?;
@@ -1783,7 +1912,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             // Unbounded
             TokenKind::Dollar if allow_type => {
                 node_e.kind = AstNodeKind::Value;
-                node_e.attr.insert("value".to_owned(), t.value.clone());
+                node_e.attr.insert("value".to_owned(), t.value.to_string());
                 ts.flush(1);
                 break; // next character should be , or ) : no need to consume it, will be checked by caller
             }
@@ -1792,7 +1921,7 @@ pub fn parse_expr(ts : &mut TokenStream, cntxt: ExprCntxt, allow_type: bool) ->
             TokenKind::TypeString  |
             TokenKind::TypeCHandle if allow_type => {
                 node_e.kind = AstNodeKind::Type;
-                node_e.attr.insert("type".to_owned(), t.value.clone());
+                node_e.attr.insert("type".to_owned(), t.value.to_string());
                 ts.flush(1);
                 if t.kind == TokenKind::TypeIntAtom {
                     parse_opt_signess(ts,&mut node_e)?;
@@ -1824,6 +1953,7 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
     ts.rewind(0);
     let t0 = ts.next_t(false)?;
     let mut n = AstNode::new(AstNodeKind::Identifier, t0.pos);
+    n.end = t0.end;
     if t0.kind!=TokenKind::Ident && t0.kind!=TokenKind::KwNew {
         if (t0.kind != TokenKind::KwThis && t0.kind != TokenKind::KwSuper) || !is_first {
             return Err(SvError::syntax(t0, "member. Expecting identifier"));
@@ -1844,18 +1974,18 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
         // println!("[parse_member_or_call] static method call from parameterized class : next token = {}", t);
         match t.kind {
             TokenKind::Scope => {
-                ns.attr.insert("name".to_owned(),t0.value);
+                ns.attr.insert("name".to_owned(),t0.value.into());
                 ts.flush(1);
                 parse_opt_scope(ts,&mut ns)?;
                 n.child.push(ns);
                 t = expect_t!(ts,"type",TokenKind::Ident);
-                n.attr.insert("name".to_owned(),t.value);
+                n.attr.insert("name".to_owned(),t.value.into());
                 t = ts.next_t(true)?;
             }
             TokenKind::Comma | TokenKind::ParenRight => {
                 n = ns;
                 n.kind = AstNodeKind::Type;
-                n.attr.insert("name".to_owned(),t0.value);
+                n.attr.insert("name".to_owned(),t0.value.into());
                 ts.rewind(1);
                 return Ok(n);
             }
@@ -1863,7 +1993,7 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
             TokenKind::Ident => {
                 n = ns; // Might need to update some attr of ns ? Kind ?
                 n.kind = AstNodeKind::Declaration;
-                n.attr.insert("name".to_owned(),t0.value);
+                n.attr.insert("name".to_owned(),t0.value.into());
                 ts.rewind(1);
                 parse_var_decl_name(ts, &mut n,ExprCntxt::StmtList,false,false)?;
                 loop {
@@ -1889,17 +2019,17 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
     else if t.kind == TokenKind::Scope {
         ts.flush(1);
         let mut ns = AstNode::new(AstNodeKind::Scope, t0.pos);
-        ns.attr.insert("name".to_owned(),t0.value);
+        ns.attr.insert("name".to_owned(),t0.value.into());
         parse_opt_scope(ts,&mut ns)?;
         n.child.push(ns);
         t = ts.next_t(false)?;
         match t.kind {
             TokenKind::Ident => {
-                n.attr.insert("name".to_owned(),t.value);
+                n.attr.insert("name".to_owned(),t.value.into());
                 t = ts.next_t(true)?;
             }
             TokenKind::Casting => {
-                n.attr.insert("type".to_owned(),t.value);
+                n.attr.insert("type".to_owned(),t.value.into());
                 n.kind = AstNodeKind::Type;
                 n.attr.insert("kind".to_owned(),"casting".to_owned());
                 // ts.flush(1); // Consume Casting operator
@@ -1912,7 +2042,7 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
         }
 
     } else {
-        n.attr.insert("name".to_owned(),t0.value);
+        n.attr.insert("name".to_owned(),t0.value.into());
     }
     // Check for function call
     if t.kind == TokenKind::ParenLeft {
@@ -1922,8 +2052,8 @@ pub fn parse_member_or_call(ts : &mut TokenStream, is_first: bool) -> Result<Ast
         t = ts.next_t(true)?;
     }
     // ts.display_status("parse_member_or_call: post parse_func_call");
-    // Check for array selection
-    if t.kind == TokenKind::SquareLeft {
+    // Check for array selection (and not a sequence repetition)
+    if t.kind == TokenKind::SquareLeft && !is_sva_repeat(ts)? {
         ts.rewind(1);
         parse_opt_slice(ts,&mut n,true,false)?;
         t = ts.next_t(true)?;
@@ -1965,7 +2095,7 @@ pub fn parse_system_task(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     ts.rewind(0);
     let mut t = expect_t!(ts,"system task",TokenKind::SystemTask);
     let mut n = AstNode::new(AstNodeKind::SystemTask, t.pos);
-    let mut name = t.value.clone();
+    let mut name = t.value.to_string();
     let mut allow_type = false;
     // Handle special cases
     match t.value.as_ref() {
@@ -2026,7 +2156,7 @@ pub fn parse_func_call(ts : &mut TokenStream, node: &mut AstNode, is_param: bool
                 ts.flush_rd(); // Consume the dot
                 let mut nt = expect_t!(ts,"function argument name",TokenKind::Ident);
                 let mut node_p = AstNode::new(AstNodeKind::Port, nt.pos);
-                node_p.attr.insert("name".to_owned(), nt.value);
+                node_p.attr.insert("name".to_owned(), nt.value.into());
                 nt = ts.next_t(true)?;
                 match nt.kind {
                     TokenKind::ParenLeft => {
@@ -2054,11 +2184,11 @@ pub fn parse_func_call(ts : &mut TokenStream, node: &mut AstNode, is_param: bool
                 ts.flush(1);
                 let mut node_p = AstNode::new(AstNodeKind::Param, t.pos);
                 node_p.attr.insert("name".to_owned(), "".to_owned());
-                node_p.attr.insert("type".to_owned(), t.value);
+                node_p.attr.insert("type".to_owned(), t.value.into());
                 node_p.attr.insert("pos".to_owned(), format!("{}",cnt));
                 t = ts.next_t(true)?;
                 if t.kind == TokenKind::KwSigning {
-                    node_p.attr.insert("signing".to_owned(), t.value);
+                    node_p.attr.insert("signing".to_owned(), t.value.into());
                     ts.flush(1);
                 } else {
                     ts.rewind(1);
@@ -2072,12 +2202,12 @@ pub fn parse_func_call(ts : &mut TokenStream, node: &mut AstNode, is_param: bool
                 ts.flush(1);
                 let mut node_p = AstNode::new(AstNodeKind::Param, t.pos);
                 node_p.attr.insert("name".to_owned(), "".to_owned());
-                node_p.attr.insert("type".to_owned(), t.value);
+                node_p.attr.insert("type".to_owned(), t.value.into());
                 node_p.attr.insert("pos".to_owned(), format!("{}",cnt));
                 nps.child.push(node_p);
             }
             TokenKind::KwVirtual if is_param => {
-                let mut s = t.value;
+                let mut s = String::from(t.value);
                 ts.flush(1);
                 t = ts.next_t(false)?;
                 if t.kind == TokenKind::KwIntf {
@@ -2100,7 +2230,7 @@ pub fn parse_func_call(ts : &mut TokenStream, node: &mut AstNode, is_param: bool
                 ts.flush(1);
                 let mut node_p = AstNode::new(AstNodeKind::Port, t.pos);
                 node_p.attr.insert("name".to_owned(), "".to_owned());
-                node_p.attr.insert("value".to_owned(), t.value);
+                node_p.attr.insert("value".to_owned(), t.value.into());
                 node_p.attr.insert("pos".to_owned(), format!("{}",cnt));
                 nps.child.push(node_p);
             }
@@ -2152,29 +2282,30 @@ pub fn parse_time(ts : &mut TokenStream) -> Result<String,SvError> {
     Ok(format!("{}{}",t1.value,t2.value))
 }
 
-/// Parse an assetr/else
+/// Parse an assert/assume/cover/restrict with optional else
 pub fn parse_assert(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
     let mut n = AstNode::new(AstNodeKind::Assert, ts.get_pos());
-    ts.flush(1); // Consume assert
     let mut t = ts.next_t(false)?;
+    n.attr.insert("kind".to_string(),t.value.into());
+    t = ts.next_t(false)?;
     // Support for deferred assertion (#0/final)
     match t.kind {
         TokenKind::Hash => {
             t = expect_t!(ts,"deferred assertion",TokenKind::Integer);
             if t.value!="0" {rpt_t!(MsgID::ErrInvalid,&t,"deferred assertion. Expecting #0 or final")}
-            n.attr.insert("deferred".to_string(),t.value);
+            n.attr.insert("deferred".to_string(),t.value.into());
             t = ts.next_t(false)?;
         }
         TokenKind::KwFinal => {
-            n.attr.insert("deferred".to_string(),t.value);
+            n.attr.insert("deferred".to_string(),t.value.into());
             t = ts.next_t(false)?;
         }
         _ => {}
     }
     match t.kind {
-        TokenKind::KwProperty => {
-            ts.skip_until(TokenKind::ParenRight)?;
-        }
+        // Concurrent assertion
+        TokenKind::Keyword if t.value=="sequence" => parse_sva_assert(ts,&mut n,t)?,
+        TokenKind::KwProperty => parse_sva_assert(ts,&mut n,t)?,
         TokenKind::ParenLeft => {
             n.child.push(parse_expr(ts,ExprCntxt::Arg,false)?);
             ts.flush(1); // Consume right parenthesis
@@ -2207,45 +2338,3 @@ pub fn parse_assert(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvE
     node.child.push(n);
     Ok(())
 }
-
-/// Parse constraint block
-/// Temporary implementation : just get the name of the constraint
-pub fn parse_constraint(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
-    let t = ts.next_t(false)?;
-    let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
-    if t.kind == TokenKind::KwConstraint {
-        let nt = expect_t!(ts,"constraint",TokenKind::Ident);
-        n.attr.insert("name".to_owned(),nt.value);
-    }
-    n.attr.insert("kind".to_owned(),t.value);
-    node.child.push(n);
-    ts.skip_until(TokenKind::CurlyRight)?;
-    // ts.display_status("parse_constraint done");
-    Ok(())
-}
-
-/// Parse covergroup block
-/// Temporary implementation : just get the name of the covergroup
-pub fn parse_covergroup(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
-    let mut n = AstNode::new(AstNodeKind::Covergroup, ts.get_pos());
-    ts.flush(1); // Consume the covergroup word
-    let t = expect_t!(ts,"covergroup",TokenKind::Ident);
-    n.attr.insert("name".to_owned(),t.value);
-    ts.skip_until(TokenKind::KwEndGroup)?;
-    check_label(ts, &n.attr["name"])?;
-    node.child.push(n);
-    Ok(())
-}
-
-/// Parse property block
-/// Temporary implementation : just get the name of the covergroup
-pub fn parse_sva_property(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
-    let mut n = AstNode::new(AstNodeKind::SvaProperty, ts.get_pos());
-    ts.flush(1); // Consume the property word
-    let t = expect_t!(ts,"SVA property",TokenKind::Ident);
-    n.attr.insert("name".to_owned(),t.value);
-    ts.skip_until(TokenKind::KwEndProperty)?;
-    check_label(ts, &n.attr["name"])?;
-    node.child.push(n);
-    Ok(())
-}
\ No newline at end of file
@@ -0,0 +1,145 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+
+/// Parse a configuration: design statement followed by default/instance/cell rules
+/// Each rule is a ConfigRule node with the attribute kind set to design, default, instance or cell
+/// Suppose the config keyword was peeked
+pub fn parse_config(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Config, t.pos);
+    let nt = expect_t!(ts,"config",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),nt.value.into());
+    expect_t!(ts,"config",TokenKind::SemiColon);
+    let res = parse_config_body(ts,&mut n);
+    // Keep the configuration even if incomplete
+    node.child.push(n);
+    res
+}
+
+fn parse_config_body(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        match parse_config_item(ts,node) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next rule and continue
+            Err(e) => ts.resync(node,e)?,
+        }
+    }
+    Ok(())
+}
+
+// Parse one statement of a configuration: return true when endconfig is reached
+fn parse_config_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<bool, SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::ConfigRule, t.pos);
+    match t.kind {
+        // Design statement: one rule per top cell
+        TokenKind::Keyword if t.value == "design" => {
+            loop {
+                let nt = ts.next_t(true)?;
+                if nt.kind == TokenKind::SemiColon {
+                    ts.flush(1);
+                    break;
+                }
+                ts.rewind(1);
+                let mut nd = AstNode::new(AstNodeKind::ConfigRule, nt.pos);
+                nd.attr.insert("kind".to_owned(), "design".to_owned());
+                parse_cell_id(ts,&mut nd,"cell")?;
+                node.child.push(nd);
+            }
+            return Ok(false);
+        }
+        TokenKind::KwDefault => {
+            let nt = expect_t!(ts,"default rule",TokenKind::Keyword);
+            if nt.value != "liblist" {
+                return Err(SvError::syntax(nt, "default rule. Expecting liblist"));
+            }
+            n.attr.insert("kind".to_owned(), "default".to_owned());
+            parse_liblist(ts,&mut n)?;
+            node.child.push(n);
+            return Ok(false);
+        }
+        // Instance path: top.u1.u2
+        TokenKind::Keyword if t.value == "instance" => {
+            n.attr.insert("kind".to_owned(), t.value.into());
+            let mut path = String::from(expect_t!(ts,"instance rule",TokenKind::Ident).value);
+            loop {
+                let nt = ts.next_t(true)?;
+                if nt.kind != TokenKind::Dot {
+                    ts.rewind(1);
+                    break;
+                }
+                ts.flush(1);
+                path.push('.');
+                path.push_str(&expect_t!(ts,"instance rule",TokenKind::Ident).value);
+            }
+            n.attr.insert("path".to_owned(), path);
+        }
+        TokenKind::Keyword if t.value == "cell" => {
+            n.attr.insert("kind".to_owned(), t.value.into());
+            parse_cell_id(ts,&mut n,"cell")?;
+        }
+        TokenKind::Keyword if t.value == "endconfig" => return Ok(true),
+        TokenKind::SemiColon => return Ok(false),
+        _ => return Err(SvError::syntax(t, "config. Expecting design, default, instance or cell"))
+    }
+    // Expansion clause of instance/cell rules: liblist or use
+    let nt = expect_t!(ts,"config rule",TokenKind::Keyword);
+    match nt.value.as_ref() {
+        "liblist" => parse_liblist(ts,&mut n)?,
+        "use" => {
+            parse_cell_id(ts,&mut n,"use")?;
+            // Optional hierarchical configuration
+            let nt = ts.next_t(true)?;
+            if nt.kind == TokenKind::Colon {
+                ts.flush(1);
+                let nt = expect_t!(ts,"use clause",TokenKind::Keyword);
+                if nt.value != "config" {
+                    return Err(SvError::syntax(nt, "use clause. Expecting config"));
+                }
+                n.attr.insert("config".to_owned(), "".to_owned());
+            } else {
+                ts.rewind(1);
+            }
+            expect_t!(ts,"use clause",TokenKind::SemiColon);
+        }
+        _ => return Err(SvError::syntax(nt, "config rule. Expecting liblist or use"))
+    }
+    node.child.push(n);
+    Ok(false)
+}
+
+// Parse a cell identifier with an optional library: [lib.]cell
+// The cell name is stored in the attribute given as argument and the library in lib (or use_lib)
+fn parse_cell_id(ts : &mut TokenStream, node: &mut AstNode, attr: &str) -> Result<(), SvError> {
+    let t = expect_t!(ts,"cell identifier",TokenKind::Ident);
+    let nt = ts.next_t(true)?;
+    if nt.kind == TokenKind::Dot {
+        ts.flush(1);
+        let nt = expect_t!(ts,"cell identifier",TokenKind::Ident);
+        let lib_attr = if attr == "use" {"use_lib"} else {"lib"};
+        node.attr.insert(lib_attr.to_owned(), t.value.into());
+        node.attr.insert(attr.to_owned(), nt.value.into());
+    } else {
+        ts.rewind(1);
+        node.attr.insert(attr.to_owned(), t.value.into());
+    }
+    Ok(())
+}
+
+// Parse a library list until the semi-colon: libraries are stored space separated in attribute liblist
+fn parse_liblist(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut libs = Vec::new();
+    loop {
+        let t = expect_t!(ts,"liblist",TokenKind::Ident,TokenKind::SemiColon);
+        if t.kind == TokenKind::SemiColon {break;}
+        libs.push(t.value);
+    }
+    node.attr.insert("liblist".to_owned(), libs.join(" "));
+    Ok(())
+}
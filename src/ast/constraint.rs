@@ -0,0 +1,231 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+
+/// Parse a constraint block: constraint name { ... } or inline constraint with { ... }
+/// Out-of-block constraints (constraint class_name::name { ... }) keep the class as a Scope child
+/// Suppose the constraint/with keyword was peeked
+pub fn parse_constraint(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+    if t.kind == TokenKind::KwConstraint {
+        parse_opt_scope(ts,&mut n)?;
+        let nt = expect_t!(ts,"constraint",TokenKind::Ident);
+        n.attr.insert("name".to_owned(),nt.value.into());
+    }
+    n.attr.insert("kind".to_owned(),t.value.into());
+    let t = expect_t!(ts,"constraint",TokenKind::CurlyLeft,TokenKind::SemiColon);
+    // Constraint prototype: body is defined outside the class
+    if t.kind == TokenKind::CurlyLeft {
+        parse_constraint_block(ts,&mut n)?;
+    }
+    node.child.push(n);
+    // ts.display_status("parse_constraint done");
+    Ok(())
+}
+
+// Parse all constraint items until the closing curly brace
+fn parse_constraint_block(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        let t = ts.next_t(true)?;
+        if t.kind == TokenKind::CurlyRight {
+            ts.flush(1);
+            break;
+        }
+        ts.rewind(1);
+        parse_constraint_item(ts,node)?;
+    }
+    Ok(())
+}
+
+// Parse a constraint set: a single constraint item or a block of items
+fn parse_constraint_set(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::CurlyLeft {
+        ts.flush(1);
+        parse_constraint_block(ts,node)
+    } else {
+        ts.rewind(1);
+        parse_constraint_item(ts,node)
+    }
+}
+
+// Parse one constraint item: expression, implication, if/else, foreach, solve before, soft, unique, disable soft
+fn parse_constraint_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(true)?;
+    match t.kind {
+        TokenKind::KwIf => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Branch, t.pos);
+            n.attr.insert("kind".to_owned(),"if".to_owned());
+            expect_t!(ts,"constraint if",TokenKind::ParenLeft);
+            n.child.push(parse_expr(ts,ExprCntxt::Arg,false)?);
+            expect_t!(ts,"constraint if",TokenKind::ParenRight);
+            parse_constraint_set(ts,&mut n)?;
+            node.child.push(n);
+            let nt = ts.next_t(true)?;
+            if nt.kind == TokenKind::KwElse {
+                ts.flush(1);
+                let mut ne = AstNode::new(AstNodeKind::Branch, nt.pos);
+                ne.attr.insert("kind".to_owned(),"else".to_owned());
+                parse_constraint_set(ts,&mut ne)?;
+                node.child.push(ne);
+            } else {
+                ts.rewind(1);
+            }
+        }
+        TokenKind::KwForeach => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Loop, t.pos);
+            n.attr.insert("kind".to_owned(), t.value.into());
+            expect_t!(ts,"constraint foreach",TokenKind::ParenLeft);
+            n.child.push(parse_ident_hier(ts)?);
+            expect_t!(ts,"constraint foreach",TokenKind::ParenRight);
+            parse_constraint_set(ts,&mut n)?;
+            node.child.push(n);
+        }
+        // Unique constraint: list of variables/ranges
+        TokenKind::KwUnique => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+            n.attr.insert("kind".to_owned(), t.value.into());
+            expect_t!(ts,"unique constraint",TokenKind::CurlyLeft);
+            parse_constraint_range_list(ts,&mut n)?;
+            expect_t!(ts,"unique constraint",TokenKind::SemiColon);
+            node.child.push(n);
+        }
+        TokenKind::KwDisable => {
+            ts.flush(1);
+            let nt = expect_t!(ts,"disable soft",TokenKind::Keyword);
+            if nt.value != "soft" {
+                return Err(SvError::syntax(nt, "disable soft. Expecting soft"));
+            }
+            let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+            n.attr.insert("kind".to_owned(), "disable soft".to_owned());
+            ts.next_t(true)?; // Peek identifier to get its position
+            n.child.push(parse_ident_hier(ts)?);
+            expect_t!(ts,"disable soft",TokenKind::SemiColon);
+            node.child.push(n);
+        }
+        // Solve order: each variable is tagged with the list it belongs to
+        TokenKind::Keyword if t.value == "solve" => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+            n.attr.insert("kind".to_owned(), t.value.into());
+            let mut nt;
+            loop {
+                ts.next_t(true)?; // Peek identifier to get its position
+                let mut nc = parse_ident_hier(ts)?;
+                nc.attr.insert("solve".to_owned(), "solve".to_owned());
+                n.child.push(nc);
+                nt = ts.next_t(false)?;
+                if nt.kind != TokenKind::Comma {break;}
+            }
+            if nt.kind != TokenKind::Keyword || nt.value != "before" {
+                return Err(SvError::syntax(nt, "solve. Expecting before"));
+            }
+            loop {
+                ts.next_t(true)?; // Peek identifier to get its position
+                let mut nc = parse_ident_hier(ts)?;
+                nc.attr.insert("solve".to_owned(), "before".to_owned());
+                n.child.push(nc);
+                loop_args_break_cont!(ts,"solve before",SemiColon);
+            }
+            node.child.push(n);
+        }
+        TokenKind::Keyword if t.value == "soft" => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+            n.attr.insert("kind".to_owned(), t.value.into());
+            let ne = parse_expr(ts,ExprCntxt::Constraint,false)?;
+            n.child.push(parse_opt_dist(ts,ne)?);
+            expect_t!(ts,"soft constraint",TokenKind::SemiColon);
+            node.child.push(n);
+        }
+        // Nested constraint set
+        TokenKind::CurlyLeft => {
+            ts.flush(1);
+            parse_constraint_block(ts,node)?;
+        }
+        TokenKind::SemiColon => ts.flush(1),
+        _ => {
+            ts.rewind(1);
+            let ne = parse_expr(ts,ExprCntxt::Constraint,false)?;
+            let nt = ts.next_t(true)?;
+            if nt.kind == TokenKind::OpImpl {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Constraint, nt.pos);
+                n.attr.insert("kind".to_owned(), nt.value.into());
+                n.child.push(ne);
+                parse_constraint_set(ts,&mut n)?;
+                node.child.push(n);
+            } else {
+                ts.rewind(1);
+                node.child.push(parse_opt_dist(ts,ne)?);
+                expect_t!(ts,"constraint",TokenKind::SemiColon);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Parse an optional distribution following an expression: expr dist { range := weight, ... }
+fn parse_opt_dist(ts : &mut TokenStream, ne: AstNode) -> Result<AstNode, SvError> {
+    let t = ts.next_t(true)?;
+    if t.kind != TokenKind::Keyword || t.value != "dist" {
+        ts.rewind(1);
+        return Ok(ne);
+    }
+    ts.flush(1);
+    let mut n = AstNode::new(AstNodeKind::Constraint, t.pos);
+    n.attr.insert("kind".to_owned(), t.value.into());
+    n.child.push(ne);
+    expect_t!(ts,"dist",TokenKind::CurlyLeft);
+    loop {
+        parse_constraint_range(ts,&mut n)?;
+        // Optional weight
+        let nt = ts.next_t(true)?;
+        if nt.kind == TokenKind::OpDist {
+            ts.flush(1);
+            let mut nw = parse_expr(ts,ExprCntxt::Constraint,false)?;
+            nw.attr.insert("weight".to_owned(), nt.value.into());
+            n.child.push(nw);
+        } else {
+            ts.rewind(1);
+        }
+        loop_args_break_cont!(ts,"dist",CurlyRight);
+    }
+    Ok(n)
+}
+
+// Parse a list of values or ranges until the closing curly brace
+fn parse_constraint_range_list(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        parse_constraint_range(ts,node)?;
+        loop_args_break_cont!(ts,"range list",CurlyRight);
+    }
+    Ok(())
+}
+
+// Parse a value or a range [lo:hi]
+fn parse_constraint_range(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::SquareLeft {
+        ts.flush(1);
+        let mut n = AstNode::new(AstNodeKind::Slice, t.pos);
+        n.child.push(parse_range_bound(ts,ExprCntxt::BracketMsb)?);
+        expect_t!(ts,"range",TokenKind::Colon);
+        n.child.push(parse_range_bound(ts,ExprCntxt::BracketLsb)?);
+        expect_t!(ts,"range",TokenKind::SquareRight);
+        node.child.push(n);
+    } else {
+        ts.rewind(1);
+        node.child.push(parse_expr(ts,ExprCntxt::Constraint,false)?);
+    }
+    Ok(())
+}
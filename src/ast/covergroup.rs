@@ -0,0 +1,418 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+use crate::ast::class::parse_func;
+use crate::ast::module_body::parse_sensitivity;
+
+/// Parse covergroup block
+/// Suppose the covergroup keyword was peeked
+pub fn parse_covergroup(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut n = AstNode::new(AstNodeKind::Covergroup, ts.get_pos());
+    ts.flush(1); // Consume the covergroup word
+    let t = expect_t!(ts,"covergroup",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),t.value.into());
+    // Optional arguments
+    let mut t = ts.next_t(true)?;
+    if t.kind == TokenKind::ParenLeft {
+        ts.flush(1);
+        n.child.push(parse_cg_ports(ts)?);
+        t = ts.next_t(true)?;
+    }
+    // Optional coverage event: clocking event, sample method or block event
+    match t.kind {
+        TokenKind::At => {
+            ts.flush(1);
+            n.child.push(parse_sensitivity(ts,false)?);
+        }
+        TokenKind::At2 => {
+            ts.flush(1);
+            expect_t!(ts,"covergroup block event",TokenKind::ParenLeft);
+            ts.skip_until(TokenKind::ParenRight)?;
+        }
+        TokenKind::KwWith => {
+            ts.flush(1);
+            expect_t!(ts,"covergroup sample method",TokenKind::KwFunction);
+            let t = expect_t!(ts,"covergroup sample method",TokenKind::Ident);
+            if t.value != "sample" {
+                return Err(SvError::syntax(t,"covergroup sample method. Expecting sample"));
+            }
+            expect_t!(ts,"covergroup sample method",TokenKind::ParenLeft);
+            let mut node_p = parse_cg_ports(ts)?;
+            node_p.attr.insert("name".to_owned(),"sample".to_owned());
+            n.child.push(node_p);
+        }
+        _ => ts.rewind(0)
+    }
+    expect_t!(ts,"covergroup",TokenKind::SemiColon);
+    // Coverage spec or option
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::KwEndGroup => {ts.flush(1); break;}
+            TokenKind::SemiColon => ts.flush(1),
+            _ => {
+                ts.rewind(1);
+                if let Err(e) = parse_cg_item(ts,&mut n) {
+                    ts.resync(&mut n,e)?;
+                }
+            }
+        }
+    }
+    check_label(ts, &n.attr["name"])?;
+    n.end = ts.last_end;
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse the list of arguments of a covergroup or of its sample method
+// Suppose the open parenthesis was consumed
+fn parse_cg_ports(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let mut node_p = AstNode::new(AstNodeKind::Ports, ts.get_pos());
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::ParenRight {
+        ts.flush(1);
+        return Ok(node_p);
+    }
+    ts.rewind(1);
+    loop {
+        node_p.child.push(parse_port_decl(ts,false,ExprCntxt::ArgList)?);
+        loop_args_break_cont!(ts,"covergroup port declaration",ParenRight);
+    }
+    Ok(node_p)
+}
+
+// Parse a coverpoint, a cross or an option
+fn parse_cg_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(true)?;
+    if is_option(&t.value) {
+        ts.rewind(1);
+        node.child.push(parse_cg_option(ts)?);
+        return Ok(());
+    }
+    let mut n = AstNode::new(AstNodeKind::Coverpoint, t.pos);
+    // Optional data type and label
+    if t.kind != TokenKind::Keyword {
+        let nt = ts.next_t(true)?;
+        if t.kind != TokenKind::Ident || nt.kind != TokenKind::Colon {
+            parse_data_type(ts,&mut n,0)?;
+        }
+        ts.rewind(0);
+        let t = expect_t!(ts,"coverpoint label",TokenKind::Ident);
+        n.attr.insert("name".to_owned(),t.value.into());
+        n.pos = t.pos;
+        n.end = t.end;
+        expect_t!(ts,"coverpoint label",TokenKind::Colon);
+    }
+    let t = expect_t!(ts,"covergroup",TokenKind::Keyword);
+    match t.value.as_ref() {
+        "coverpoint" => parse_coverpoint(ts,&mut n)?,
+        "cross" => {
+            n.kind = AstNodeKind::CoverCross;
+            if n.attr.contains_key("type") {
+                return Err(SvError::syntax(t,"covergroup. Type not allowed on cross"));
+            }
+            parse_cross(ts,&mut n)?;
+        }
+        _ => return Err(SvError::syntax(t,"covergroup. Expecting coverpoint, cross or option"))
+    }
+    node.child.push(n);
+    Ok(())
+}
+
+// Check if an identifier start a coverage option
+fn is_option(s: &str) -> bool {
+    s == "option" || s == "type_option"
+}
+
+/// Parse a coverage option: option.name = value;
+fn parse_cg_option(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let t = ts.next_t(false)?;
+    expect_t!(ts,"coverage option",TokenKind::Dot);
+    let nt = expect_t!(ts,"coverage option",TokenKind::Ident);
+    let mut n = AstNode::new(AstNodeKind::CoverOption, nt.pos);
+    n.end = nt.end;
+    n.attr.insert("kind".to_owned(),t.value.into());
+    n.attr.insert("name".to_owned(),nt.value.into());
+    expect_t!(ts,"coverage option",TokenKind::OpEq);
+    n.child.push(parse_expr(ts,ExprCntxt::Stmt,false)?);
+    expect_t!(ts,"coverage option",TokenKind::SemiColon);
+    Ok(n)
+}
+
+/// Parse a coverpoint: expression, iff condition and list of bins
+/// Suppose the coverpoint keyword was consumed
+fn parse_coverpoint(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let ne = parse_expr(ts,ExprCntxt::Cover,false)?;
+    // Without label, a coverpoint on a variable is named after it
+    if !node.attr.contains_key("name") && ne.kind == AstNodeKind::Identifier && ne.child.is_empty() {
+        node.attr.insert("name".to_owned(),ne.attr["name"].clone());
+    }
+    node.child.push(ne);
+    parse_opt_iff(ts,node)?;
+    let t = expect_t!(ts,"coverpoint",TokenKind::SemiColon,TokenKind::CurlyLeft);
+    if t.kind == TokenKind::SemiColon {
+        return Ok(());
+    }
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::CurlyRight => {ts.flush(1); break;}
+            TokenKind::SemiColon => ts.flush(1),
+            TokenKind::Ident if is_option(&t.value) => {
+                ts.rewind(1);
+                node.child.push(parse_cg_option(ts)?);
+            }
+            TokenKind::Keyword => {
+                ts.rewind(1);
+                if let Err(e) = parse_cg_bins(ts,node) {
+                    ts.resync(node,e)?;
+                }
+            }
+            _ => return Err(SvError::syntax(t,"coverpoint. Expecting bins or option"))
+        }
+    }
+    Ok(())
+}
+
+// Parse bins keyword and name: [wildcard] bins|illegal_bins|ignore_bins name
+fn parse_bins_name(ts : &mut TokenStream, allow_wildcard: bool) -> Result<AstNode, SvError> {
+    let mut t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::CoverBins, t.pos);
+    if t.value == "wildcard" && allow_wildcard {
+        n.attr.insert("wildcard".to_owned(),"".to_owned());
+        t = ts.next_t(false)?;
+    }
+    match t.value.as_ref() {
+        "bins" | "illegal_bins" | "ignore_bins" if t.kind == TokenKind::Keyword => {
+            n.attr.insert("kind".to_owned(),t.value.into());
+        }
+        _ => return Err(SvError::syntax(t,"bins declaration. Expecting bins, illegal_bins or ignore_bins"))
+    }
+    let t = expect_t!(ts,"bins declaration",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),t.value.into());
+    Ok(n)
+}
+
+/// Parse bins of a coverpoint
+fn parse_cg_bins(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut n = parse_bins_name(ts,true)?;
+    // Optional array: [] or [size]
+    let mut t = ts.next_t(true)?;
+    if t.kind == TokenKind::SquareLeft {
+        ts.flush(1);
+        t = ts.next_t(true)?;
+        if t.kind == TokenKind::SquareRight {
+            ts.flush(1);
+            n.attr.insert("array".to_owned(),"".to_owned());
+        } else {
+            ts.rewind(1);
+            let mut ns = parse_expr(ts,ExprCntxt::BracketLsb,false)?;
+            ns.attr.insert("kind".to_owned(),"size".to_owned());
+            n.child.push(ns);
+            expect_t!(ts,"bins declaration",TokenKind::SquareRight);
+            n.attr.insert("array".to_owned(),"".to_owned());
+        }
+    } else {
+        ts.rewind(1);
+    }
+    expect_t!(ts,"bins declaration",TokenKind::OpEq);
+    t = ts.next_t(true)?;
+    match t.kind {
+        // List of values/ranges
+        TokenKind::CurlyLeft => {
+            ts.flush(1);
+            parse_cg_range_list(ts,&mut n)?;
+        }
+        // Default bins
+        TokenKind::KwDefault => {
+            ts.flush(1);
+            n.attr.insert("default".to_owned(),"".to_owned());
+            t = ts.next_t(true)?;
+            if t.kind == TokenKind::Keyword && t.value == "sequence" {
+                ts.flush(1);
+                n.attr.insert("default".to_owned(),"sequence".to_owned());
+            } else {
+                ts.rewind(1);
+            }
+        }
+        // Transitions
+        TokenKind::ParenLeft => {
+            ts.rewind(1);
+            n.attr.insert("transition".to_owned(),"".to_owned());
+            parse_cg_trans(ts,&mut n)?;
+        }
+        // Set expression or other coverpoint
+        _ => {
+            ts.rewind(1);
+            let mut ne = parse_expr(ts,ExprCntxt::Cover,false)?;
+            ne.attr.insert("kind".to_owned(),"set".to_owned());
+            n.child.push(ne);
+        }
+    }
+    // Optional with clause
+    t = ts.next_t(true)?;
+    if t.kind == TokenKind::KwWith {
+        ts.flush(1);
+        expect_t!(ts,"bins with clause",TokenKind::ParenLeft);
+        let mut ne = parse_expr(ts,ExprCntxt::ExprGroup,false)?;
+        ne.attr.insert("kind".to_owned(),"with".to_owned());
+        n.child.push(ne);
+        expect_t!(ts,"bins with clause",TokenKind::ParenRight);
+    } else {
+        ts.rewind(1);
+    }
+    parse_opt_iff(ts,&mut n)?;
+    expect_t!(ts,"bins declaration",TokenKind::SemiColon);
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse a list of values/ranges of a bins: { value, [low:high], ... }
+// Suppose the open curly brace was consumed
+fn parse_cg_range_list(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        let t = ts.next_t(true)?;
+        if t.kind == TokenKind::SquareLeft {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::Slice, t.pos);
+            n.child.push(parse_range_bound(ts,ExprCntxt::BracketMsb)?);
+            expect_t!(ts,"bins range",TokenKind::Colon);
+            n.child.push(parse_range_bound(ts,ExprCntxt::BracketLsb)?);
+            expect_t!(ts,"bins range",TokenKind::SquareRight);
+            node.child.push(n);
+        } else {
+            ts.rewind(1);
+            node.child.push(parse_expr(ts,ExprCntxt::FieldList,false)?);
+        }
+        loop_args_break_cont!(ts,"bins values",CurlyRight);
+    }
+    Ok(())
+}
+
+// Parse a list of transitions: (a => b[*2] => c), (...)
+// Only the identifiers are kept, for the check of declaration
+fn parse_cg_trans(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut cnt_p = 0;
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::ParenLeft => {ts.flush(1); cnt_p += 1;}
+            TokenKind::ParenRight => {ts.flush(1); cnt_p -= 1;}
+            TokenKind::Ident => node.child.push(parse_ident_hier(ts)?),
+            TokenKind::Comma if cnt_p == 0 => ts.flush(1),
+            _ if cnt_p == 0 => {ts.rewind(1); break;}
+            _ => ts.flush(1),
+        }
+    }
+    Ok(())
+}
+
+// Parse an optional iff (expression)
+fn parse_opt_iff(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(true)?;
+    if t.kind != TokenKind::KwIff {
+        ts.rewind(1);
+        return Ok(());
+    }
+    ts.flush(1);
+    expect_t!(ts,"iff condition",TokenKind::ParenLeft);
+    let mut ne = parse_expr(ts,ExprCntxt::ExprGroup,false)?;
+    ne.attr.insert("kind".to_owned(),"iff".to_owned());
+    node.child.push(ne);
+    expect_t!(ts,"iff condition",TokenKind::ParenRight);
+    Ok(())
+}
+
+/// Parse a cross: list of coverpoints/variables, iff condition and bins selection
+/// Suppose the cross keyword was consumed
+fn parse_cross(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        let t = expect_t!(ts,"cross",TokenKind::Ident);
+        let mut n = AstNode::new(AstNodeKind::Identifier, t.pos);
+        n.end = t.end;
+        n.attr.insert("name".to_owned(),t.value.into());
+        node.child.push(n);
+        let t = ts.next_t(true)?;
+        if t.kind != TokenKind::Comma {
+            ts.rewind(1);
+            break;
+        }
+        ts.flush(1);
+    }
+    parse_opt_iff(ts,node)?;
+    let t = expect_t!(ts,"cross",TokenKind::SemiColon,TokenKind::CurlyLeft);
+    if t.kind == TokenKind::SemiColon {
+        return Ok(());
+    }
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::CurlyRight => {ts.flush(1); break;}
+            TokenKind::SemiColon => ts.flush(1),
+            TokenKind::KwFunction => parse_func(ts,node,false,false)?,
+            TokenKind::Ident if is_option(&t.value) => {
+                ts.rewind(1);
+                node.child.push(parse_cg_option(ts)?);
+            }
+            TokenKind::Keyword => {
+                ts.rewind(1);
+                if let Err(e) = parse_cross_bins(ts,node) {
+                    ts.resync(node,e)?;
+                }
+            }
+            _ => return Err(SvError::syntax(t,"cross. Expecting bins, option or function"))
+        }
+    }
+    Ok(())
+}
+
+/// Parse a bins selection of a cross: bins name = select_expression [iff (expr)];
+/// Only the binsof target, intersect values and other identifiers are kept
+fn parse_cross_bins(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut n = parse_bins_name(ts,false)?;
+    expect_t!(ts,"bins selection",TokenKind::OpEq);
+    let mut cnt_p = 0;
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::Keyword if t.value == "binsof" => {
+                ts.flush(1);
+                expect_t!(ts,"binsof",TokenKind::ParenLeft);
+                let mut ni = parse_ident_hier(ts)?;
+                ni.attr.insert("binsof".to_owned(),"".to_owned());
+                n.child.push(ni);
+                expect_t!(ts,"binsof",TokenKind::ParenRight);
+            }
+            TokenKind::Keyword if t.value == "intersect" => {
+                ts.flush(1);
+                expect_t!(ts,"intersect",TokenKind::CurlyLeft);
+                parse_cg_range_list(ts,&mut n)?;
+            }
+            TokenKind::KwWith => {
+                ts.flush(1);
+                expect_t!(ts,"bins with clause",TokenKind::ParenLeft);
+                let mut ne = parse_expr(ts,ExprCntxt::ExprGroup,false)?;
+                ne.attr.insert("kind".to_owned(),"with".to_owned());
+                n.child.push(ne);
+                expect_t!(ts,"bins with clause",TokenKind::ParenRight);
+            }
+            TokenKind::Ident => n.child.push(parse_ident_hier(ts)?),
+            TokenKind::ParenLeft => {ts.flush(1); cnt_p += 1;}
+            TokenKind::ParenRight if cnt_p > 0 => {ts.flush(1); cnt_p -= 1;}
+            TokenKind::OpBang     |
+            TokenKind::OpLogicAnd |
+            TokenKind::OpLogicOr  => ts.flush(1),
+            TokenKind::KwIff | TokenKind::SemiColon if cnt_p == 0 => {ts.rewind(1); break;}
+            _ => return Err(SvError::syntax(t,"bins selection"))
+        }
+    }
+    parse_opt_iff(ts,&mut n)?;
+    expect_t!(ts,"bins selection",TokenKind::SemiColon);
+    node.child.push(n);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use std::collections::HashMap;
+
+use crate::ast::astnode::AstNode;
+
+/// Kind of element in the concrete syntax tree
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CstKind {
+    Token,
+    Comment,
+    Attribute,
+    Whitespace,
+    /// Raw text consumed without being tokenized (e.g. `pragma expression, protected region)
+    Skipped,
+}
+
+/// Element of the concrete syntax tree: exact text from the source and its byte offset
+#[derive(Debug, Clone)]
+pub struct CstElem {
+    pub kind : CstKind,
+    pub text : String,
+    pub byte : u32,
+}
+
+impl CstElem {
+    pub fn is_trivia(&self) -> bool {
+        self.kind != CstKind::Token
+    }
+}
+
+/// Rebuild the exact source code from the list of elements
+pub fn to_source(elems: &[CstElem]) -> String {
+    elems.iter().map(|e| e.text.as_str()).collect()
+}
+
+// Collect start/end byte of every node in pre-order
+fn collect_spans(node: &AstNode, v: &mut Vec<(u32,u32)>) {
+    v.push((node.pos.byte, node.end.byte));
+    for c in &node.child {
+        collect_spans(c, v);
+    }
+}
+
+// Move trivia to the node at the same pre-order index
+fn add_trivia(node: &mut AstNode, idx: &mut usize, leading: &mut HashMap<usize,Vec<CstElem>>, trailing: &mut HashMap<usize,Vec<CstElem>>) {
+    if let Some(v) = leading.remove(idx) {
+        node.leading = v;
+    }
+    if let Some(v) = trailing.remove(idx) {
+        node.trailing = v;
+    }
+    *idx += 1;
+    for c in &mut node.child {
+        add_trivia(c, idx, leading, trailing);
+    }
+}
+
+/// Attach trivia (comments, attributes, whitespace) to the nearest node:
+///  - trivia following a token on the same line (up to the line return) is trailing trivia
+///    of the outermost node ending before it,
+///  - any other trivia is leading trivia of the outermost node starting after it.
+///
+/// Trivia with no node after it is attached as trailing trivia of the root.
+pub fn attach_trivia(tree: &mut AstNode, elems: &[CstElem]) {
+    let mut spans = Vec::new();
+    collect_spans(tree, &mut spans);
+    // Node sorted by start (outermost first) and by end (outermost last), excluding the root
+    let mut by_start : Vec<(u32,usize)> = spans.iter().enumerate().skip(1).map(|(i,s)| (s.0,i)).collect();
+    by_start.sort_unstable();
+    let mut by_end : Vec<(u32,usize)> = spans.iter().enumerate().skip(1).map(|(i,s)| (s.1,i)).collect();
+    by_end.sort_unstable_by(|a,b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let mut leading : HashMap<usize,Vec<CstElem>> = HashMap::new();
+    let mut trailing : HashMap<usize,Vec<CstElem>> = HashMap::new();
+    let mut after_token = false;
+    for e in elems {
+        if !e.is_trivia() {
+            after_token = true;
+            continue;
+        }
+        let mut e_lead = Some(e.clone());
+        if after_token {
+            // Split whitespace after the first line return: the remaining goes to the next node
+            let mut e_trail = e.clone();
+            e_lead = None;
+            if let Some(i) = e.text.find('\n') {
+                after_token = false;
+                if i+1 < e.text.len() {
+                    e_trail.text = e.text[..i+1].to_owned();
+                    e_lead = Some(CstElem{kind: e.kind, text: e.text[i+1..].to_owned(), byte: e.byte + i as u32 + 1});
+                }
+            }
+            let n = by_end.partition_point(|x| x.0 < e_trail.byte);
+            let idx = if n > 0 {by_end[n-1].1} else {0};
+            trailing.entry(idx).or_default().push(e_trail);
+        }
+        if let Some(e) = e_lead {
+            let end = e.byte + e.text.len() as u32;
+            let n = by_start.partition_point(|x| x.0 < end);
+            match by_start.get(n) {
+                Some(x) => leading.entry(x.1).or_default().push(e),
+                None => trailing.entry(0).or_default().push(e),
+            }
+        }
+    }
+    let mut idx = 0;
+    add_trivia(tree, &mut idx, &mut leading, &mut trailing);
+}
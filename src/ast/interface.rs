@@ -2,13 +2,15 @@
 // Copyright (c) 2019, clams@mail.com
 
 use crate::error::{SvError};
-use crate::lex::token::{TokenKind};
+use crate::lex::token::{Token,TokenKind};
 use crate::lex::token_stream::TokenStream;
 use crate::ast::astnode::*;
 use crate::ast::common::*;
 use crate::ast::module_hdr::{parse_module_hdr};
 use crate::ast::module_body::*;
 use crate::ast::class::{parse_func,parse_task};
+use crate::ast::sva::parse_sva_decl;
+use crate::ast::checker::{parse_checker,parse_let};
 
 // TODO: rework to reuse a maximum of what s already done in module body parser
 
@@ -23,147 +25,180 @@ pub fn parse_interface(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     loop {
         let t = ts.next_t(true)?;
         // println!("[parse_module_body] Token = {}", t);
-        match t.kind {
-            // Modport
-            TokenKind::KwModport => parse_modport(ts,&mut node_b)?,
-            // clocking block
-            TokenKind::KwDefault | TokenKind::KwClocking | TokenKind::KwGlobal => parse_clocking(ts,&mut node_b)?,
-            // Import statement
-            TokenKind::KwImport => parse_import(ts,&mut node_b)?,
-            // Only local param declaration
-            TokenKind::KwLParam => {
-                ts.rewind(1); // put back the token so that it can be read by the parse param function
-                // potential list of param (the parse function extract only one at a time)
-                loop {
-                    let node_param = parse_param_decl(ts,true)?;
-                    node_b.child.push(node_param);
-                    let nt = ts.next_t(false)?;
-                    match nt.kind {
-                        TokenKind::Comma => {}, // Comma indicate a list -> continue
-                        TokenKind::SemiColon => {break;}, // Semi colon indicate end of statement, stop the loop
-                        _ => return Err(SvError::syntax(t, "param declaration, expecting , or ;"))
-                    }
+        match parse_interface_item(ts,&mut node_b,&node.attr["name"],t) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(&mut node_b,e)?,
+        }
+    }
+    node.end = ts.last_end;
+    node_b.end = ts.last_end;
+    node.child.push(node_b);
+    Ok(node)
+}
+
+// Parse one item of an interface body: return true when the end of the interface is reached
+fn parse_interface_item(ts : &mut TokenStream, node : &mut AstNode, name: &str, t: Token) -> Result<bool, SvError> {
+    match t.kind {
+        // Modport
+        TokenKind::KwModport => parse_modport(ts,node)?,
+        // clocking block
+        TokenKind::KwDefault | TokenKind::KwClocking | TokenKind::KwGlobal => parse_clocking(ts,node)?,
+        // Import statement
+        TokenKind::KwImport | TokenKind::KwExport => parse_import(ts,node)?,
+        // Only local param declaration
+        TokenKind::KwLParam => {
+            ts.rewind(1); // put back the token so that it can be read by the parse param function
+            // potential list of param (the parse function extract only one at a time)
+            loop {
+                let node_param = parse_param_decl(ts,true)?;
+                node.child.push(node_param);
+                let nt = ts.next_t(false)?;
+                match nt.kind {
+                    TokenKind::Comma => {}, // Comma indicate a list -> continue
+                    TokenKind::SemiColon => {break;}, // Semi colon indicate end of statement, stop the loop
+                    _ => return Err(SvError::syntax(t, "param declaration, expecting , or ;"))
                 }
             }
-            // Nettype (might need another function to parse the signal to include strength/charge, delay, ...)
-            TokenKind::KwNetType |
-            TokenKind::KwSupply  =>  parse_signal_decl_list(ts,&mut node_b)?,
-            // Basetype
-            TokenKind::KwReg         |
-            TokenKind::TypeIntAtom   |
-            TokenKind::TypeIntVector |
-            TokenKind::TypeReal      |
-            TokenKind::TypeString    |
-            TokenKind::TypeCHandle   |
-            TokenKind::TypeEvent     => parse_signal_decl_list(ts,&mut node_b)?,
-            TokenKind::KwEnum        => {
-                let mut node_e = parse_enum(ts,false)?;
-                parse_ident_list(ts,&mut node_e)?;
-                node_b.child.push(node_e);
-            }
-            TokenKind::KwStruct |
-            TokenKind::KwUnion  => {
-                let mut node_s = parse_struct(ts)?;
-                parse_ident_list(ts,&mut node_s)?;
-                node_b.child.push(node_s);
-            }
-            TokenKind::KwTypedef => parse_typedef(ts,&mut node_b)?,
-            TokenKind::TypeGenvar => {
-                ts.flush_rd();
-                loop {
-                    let nt = ts.next_t(false)?;
-                    match nt.kind {
-                        TokenKind::Ident => {
-                            let mut n = AstNode::new(AstNodeKind::Declaration, nt.pos);
-                            n.attr.insert("type".to_owned(), "genvar".to_owned());
-                            n.attr.insert("name".to_owned(),t.value.clone());
-                            node_b.child.push(n);
-                            loop_args_break_cont!(ts,"genvar declaration",SemiColon);
-                        }
-                        _ =>  return Err(SvError::syntax(t,"virtual interface. Expecting identifier")),
+        }
+        // Nettype (might need another function to parse the signal to include strength/charge, delay, ...)
+        TokenKind::KwNetType |
+        TokenKind::KwSupply  =>  parse_signal_decl_list(ts,node)?,
+        // Basetype
+        TokenKind::KwReg         |
+        TokenKind::TypeIntAtom   |
+        TokenKind::TypeIntVector |
+        TokenKind::TypeReal      |
+        TokenKind::TypeString    |
+        TokenKind::TypeCHandle   |
+        TokenKind::TypeEvent     => parse_signal_decl_list(ts,node)?,
+        TokenKind::KwEnum        => {
+            let mut node_e = parse_enum(ts,false)?;
+            parse_ident_list(ts,&mut node_e)?;
+            node.child.push(node_e);
+        }
+        TokenKind::KwStruct |
+        TokenKind::KwUnion  => {
+            let mut node_s = parse_struct(ts)?;
+            parse_ident_list(ts,&mut node_s)?;
+            node.child.push(node_s);
+        }
+        TokenKind::KwTypedef => parse_typedef(ts,node)?,
+        TokenKind::TypeGenvar => {
+            ts.flush_rd();
+            loop {
+                let nt = ts.next_t(false)?;
+                match nt.kind {
+                    TokenKind::Ident => {
+                        let mut n = AstNode::new(AstNodeKind::Declaration, nt.pos);
+                        n.attr.insert("type".to_owned(), "genvar".to_owned());
+                        n.attr.insert("name".to_owned(),t.value.to_string());
+                        node.child.push(n);
+                        loop_args_break_cont!(ts,"genvar declaration",SemiColon);
                     }
+                    _ =>  return Err(SvError::syntax(t,"virtual interface. Expecting identifier")),
                 }
             }
-            // Identifier -> lookahead to detect if it is a signal declaration or an instantiation
-            TokenKind::Ident => {
-                let nt = ts.next_t(true)?;
-                // println!("[Module body] Ident followed by {}", nt.kind);
-                match nt.kind {
-                    // Scope -> this is a type definition
-                    TokenKind::Scope => parse_signal_decl_list(ts,&mut node_b)?,
-                    // Identifier : could be a signal declaration or a module/interface instantiation
-                    TokenKind::Ident => {
-                        let nnt = ts.next_t(true)?;
-                        // println!("[Module body] (Ident Ident) followed by {}", nnt.kind);
-                        match nnt.kind {
-                            // Opening parenthesis indicates
-                            // Semi colon or comma indicate signal declaration
-                            TokenKind::SemiColon |
-                            TokenKind::Comma     =>  parse_signal_decl_list(ts,&mut node_b)?,
-                            // Slice -> can be either an unpacked array declaration or an array of instance ...
-                            // TODO: handle case of array of instances
-                            TokenKind::SquareLeft =>  {
-                                parse_signal_decl_list(ts,&mut node_b)?;
-                            }
-                            // Open parenthesis -> instance
-                            TokenKind::ParenLeft => {
-                                let node_inst = parse_instance(ts)?;
-                                node_b.child.push(node_inst);
-                            }
-                            _ => return Err(SvError::syntax(t, " signal declaration or instance"))
-                        }
+        }
+        // Identifier -> lookahead to detect if it is a signal declaration or an instantiation
+        TokenKind::Ident => {
+            let nt = ts.next_t(true)?;
+            // println!("[Module body] Ident followed by {}", nt.kind);
+            match nt.kind {
+                // Scope -> this is a type definition
+                TokenKind::Scope => parse_signal_decl_list(ts,node)?,
+                // Colon : labeled assertion
+                TokenKind::Colon => {
+                    ts.flush(2);
+                    let nnt = ts.next_t(true)?;
+                    match nnt.kind {
+                        TokenKind::KwAssert |
+                        TokenKind::KwCover  => parse_assert(ts,node)?,
+                        TokenKind::Keyword if nnt.value=="assume" || nnt.value=="restrict" => parse_assert(ts,node)?,
+                        _ => return Err(SvError::syntax(nnt, "labeled statement. Expecting assertion"))
                     }
-                    // Dash is a clear indicator of an instance -> TODO
-                    TokenKind::Hash => {
-                        let node_inst = parse_instance(ts)?;
-                        node_b.child.push(node_inst);
+                    if let Some(n) = node.child.last_mut() {
+                        n.attr.insert("label".to_owned(),t.value.into());
                     }
-                    // Untreated token are forbidden
-                    _ => return Err(SvError::syntax(t, " signal declaration or instance, expecting type or instance"))
                 }
-            }
-            // End module -> parsing of body is done
-            TokenKind::KwAssign => {
-                ts.rewind(1);
-                node_b.child.push(parse_assign_c(ts)?);
-            }
-            // Always keyword
-            TokenKind::KwAlways  |
-            TokenKind::KwAlwaysC |
-            TokenKind::KwAlwaysF |
-            TokenKind::KwAlwaysL => parse_always(ts,&mut node_b)?,
-            TokenKind::KwInitial  => parse_initial(ts,&mut node_b)?,
-            TokenKind::KwFunction => parse_func(ts,&mut node_b, false, false)?,
-            TokenKind::KwTask     => parse_task(ts,&mut node_b)?,
-            //
-            TokenKind::Macro => parse_macro(ts,&mut node_b)?,
-            TokenKind::CompDir => parse_macro(ts,&mut node_b)?,
-            // TokenKind::KwGenerate if cntxt==ModuleCntxt::Top => parse_module_body(ts,node_b,ModuleCntxt::Generate)?,
-            TokenKind::KwFor  => parse_for(ts,&mut node_b,true)?,
-            TokenKind::KwIf   => {
-                ts.flush_rd();
-                parse_if_else(ts,&mut node_b, true)?;
-            }
-            // End of loop depends on context
-            // TokenKind::KwEnd         if cntxt == ModuleCntxt::ForBlock => break,
-            // TokenKind::KwEnd         if cntxt == ModuleCntxt::IfBlock  => break,
-            // TokenKind::KwEndGenerate if cntxt == ModuleCntxt::Generate => break,
-            // End module -> parsing of body is done
-            TokenKind::KwEndIntf => {
-                ts.flush(1);
-                check_label(ts, &node.attr["name"])?;
-                break;
-            },
-            // Any un-treated token is an error
-            _ => {
-                // println!("{}", node_b);
-                return Err(SvError::syntax(t, "interface"))
+                // Identifier : could be a signal declaration or a module/interface instantiation
+                TokenKind::Ident => {
+                    let nnt = ts.next_t(true)?;
+                    // println!("[Module body] (Ident Ident) followed by {}", nnt.kind);
+                    match nnt.kind {
+                        // Opening parenthesis indicates
+                        // Semi colon or comma indicate signal declaration
+                        TokenKind::SemiColon |
+                        TokenKind::Comma     =>  parse_signal_decl_list(ts,node)?,
+                        // Slice -> can be either an unpacked array declaration or an array of instance ...
+                        // TODO: handle case of array of instances
+                        TokenKind::SquareLeft =>  {
+                            parse_signal_decl_list(ts,node)?;
+                        }
+                        // Open parenthesis -> instance
+                        TokenKind::ParenLeft => {
+                            let node_inst = parse_instance(ts)?;
+                            node.child.push(node_inst);
+                        }
+                        _ => return Err(SvError::syntax(t, " signal declaration or instance"))
+                    }
+                }
+                // Dash is a clear indicator of an instance -> TODO
+                TokenKind::Hash => {
+                    let node_inst = parse_instance(ts)?;
+                    node.child.push(node_inst);
+                }
+                // Untreated token are forbidden
+                _ => return Err(SvError::syntax(t, " signal declaration or instance, expecting type or instance"))
             }
         }
+        // End module -> parsing of body is done
+        TokenKind::KwAssign => {
+            ts.rewind(1);
+            node.child.push(parse_assign_c(ts)?);
+        }
+        // Always keyword
+        TokenKind::KwAlways  |
+        TokenKind::KwAlwaysC |
+        TokenKind::KwAlwaysF |
+        TokenKind::KwAlwaysL => parse_always(ts,node)?,
+        TokenKind::KwInitial  => parse_initial(ts,node)?,
+        TokenKind::KwFunction => parse_func(ts,node, false, false)?,
+        TokenKind::KwTask     => parse_task(ts,node,false)?,
+        TokenKind::KwAssert   |
+        TokenKind::KwCover    => parse_assert(ts,node)?,
+        TokenKind::Keyword if t.value=="assume" || t.value=="restrict" => parse_assert(ts,node)?,
+        TokenKind::KwProperty => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="sequence" => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="checker" => parse_checker(ts,node)?,
+        TokenKind::Keyword if t.value=="let"     => parse_let(ts,node)?,
+        //
+        TokenKind::Macro => parse_macro(ts,node)?,
+        TokenKind::CompDir => parse_macro(ts,node)?,
+        // TokenKind::KwGenerate if cntxt==ModuleCntxt::Top => parse_module_body(ts,node_b,ModuleCntxt::Generate)?,
+        TokenKind::KwFor  => parse_for(ts,node,true)?,
+        TokenKind::KwIf   => {
+            ts.flush_rd();
+            parse_if_else(ts,node, true)?;
+        }
+        // End of loop depends on context
+        // TokenKind::KwEnd         if cntxt == ModuleCntxt::ForBlock => break,
+        // TokenKind::KwEnd         if cntxt == ModuleCntxt::IfBlock  => break,
+        // TokenKind::KwEndGenerate if cntxt == ModuleCntxt::Generate => break,
+        // End module -> parsing of body is done
+        TokenKind::KwEndIntf => {
+            ts.flush(1);
+            check_label(ts, name)?;
+            return Ok(true);
+        },
+        // Any un-treated token is an error
+        _ => {
+            // println!("{}", node_b);
+            return Err(SvError::syntax(t, "interface"))
+        }
     }
-    // ts.flush_rd();
-    node.child.push(node_b);
-    Ok(node)
+    Ok(false)
 }
 
 /// Parse an always block
@@ -175,59 +210,58 @@ pub fn parse_modport(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
     if t.kind!=TokenKind::Ident {
         return Err(SvError::syntax(t,"modport. Expecting Identifier"));
     }
-    node_mp.attr.insert("name".to_owned(),t.value);
+    node_mp.attr.insert("name".to_owned(),t.value.into());
     // Expect open parenthesis
     t = ts.next_t(false)?;
     if t.kind!=TokenKind::ParenLeft {
         return Err(SvError::syntax(t,"modport. Expecting ("));
     }
-    // Expect a list of (input|output|inout|ref|clocking|import|export) id1, id0, ...
-    // In case of import should also support import with task prototype
-    // In case of port (in/out/inout) need to support port expresison in the form .ID(expr)
+    // Expect a list of (input|output|inout|ref|clocking|import|export) items
+    // Ports are a name or a port expression .name(expr), methods a name or a task/function prototype.
+    // Items without keyword take the direction (or import/export) of the previous one
+    let mut prev : Option<AstNode> = None;
     loop {
         t = ts.next_t(false)?;
         let mut node_p = AstNode::new(AstNodeKind::Port, t.pos);
         match t.kind {
             TokenKind::KwInput | TokenKind::KwOutput | TokenKind::KwInout | TokenKind::KwRef => {
-                node_p.attr.insert("dir".to_owned(), t.value);
+                node_p.attr.insert("dir".to_owned(), t.value.into());
                 t = ts.next_t(false)?;
-                match t.kind {
-                    TokenKind::Ident =>  {node_p.attr.insert("name".to_owned(), t.value);}
-                    // modport expression
-                    TokenKind::Dot => {
-                        t = ts.next_t(false)?;
-                        match t.kind {
-                            TokenKind::Ident => {node_p.attr.insert("name".to_owned(), t.value);}
-                            _ =>  return Err(SvError::syntax(t,"modport. Expecting identifier")),
-                        }
-                    }
-                    _ =>  return Err(SvError::syntax(t,"modport. Expecting port name/expression")),
-                }
+                parse_modport_port(ts,&mut node_p,t)?;
             }
             TokenKind::KwClocking => {
                 node_p.kind = AstNodeKind::Clocking;
                 t = ts.next_t(false)?;
                 match t.kind {
-                    TokenKind::Ident => {node_p.attr.insert("name".to_owned(), t.value);}
-                    _ =>  return Err(SvError::syntax(t,"modport. Expecting port name/expression")),
+                    TokenKind::Ident => {node_p.attr.insert("name".to_owned(), t.value.into());}
+                    _ =>  return Err(SvError::syntax(t,"modport. Expecting clocking block name")),
                 }
             }
-            TokenKind::Ident => {
-                node_p.attr.insert("name".to_owned(), t.value);
-            }
             TokenKind::KwImport | TokenKind::KwExport => {
                 node_p.kind = AstNodeKind::Import;
-                node_p.attr.insert("kind".to_owned(), t.value);
-                t = ts.next_t(false)?;
-                match t.kind {
-                    TokenKind::Ident => {node_p.attr.insert("name".to_owned(), t.value);}
-                    // TODO: support task prototype
-                    _ =>  return Err(SvError::syntax(t,"modport. Expecting port name/expression")),
+                node_p.attr.insert("kind".to_owned(), t.value.into());
+                parse_modport_method(ts,&mut node_p)?;
+            }
+            // Same direction/import/export as the previous item
+            TokenKind::Ident | TokenKind::Dot | TokenKind::KwTask | TokenKind::KwFunction if prev.is_some() => {
+                let np = prev.as_ref().unwrap();
+                node_p.kind = np.kind.clone();
+                match np.kind {
+                    AstNodeKind::Port => {
+                        node_p.attr.insert("dir".to_owned(), np.attr["dir"].clone());
+                        parse_modport_port(ts,&mut node_p,t)?;
+                    }
+                    AstNodeKind::Import => {
+                        node_p.attr.insert("kind".to_owned(), np.attr["kind"].clone());
+                        ts.rewind(1);
+                        parse_modport_method(ts,&mut node_p)?;
+                    }
+                    _ => return Err(SvError::syntax(t,"modport. Expecting direction/clocking/import/export"))
                 }
             }
-            // TODO : support port expression
-            _ =>  return Err(SvError::syntax(t,"modport. Expecting direction/identifier/cloking/import/export (")),
+            _ =>  return Err(SvError::syntax(t,"modport. Expecting direction/clocking/import/export")),
         }
+        prev = Some(AstNode{child: Vec::new(), ..node_p.clone()});
         node_mp.child.push(node_p);
         t = ts.next_t(false)?;
         match t.kind {
@@ -245,58 +279,257 @@ pub fn parse_modport(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
     Ok(())
 }
 
-/// Parse an always block
+// Parse a modport port: name or port expression .name(expr) (the expression is the child of the port)
+// The first token was already consumed
+fn parse_modport_port(ts : &mut TokenStream, node: &mut AstNode, t: Token) -> Result<(), SvError> {
+    match t.kind {
+        TokenKind::Ident => {node.attr.insert("name".to_owned(), t.value.into());}
+        TokenKind::Dot => {
+            let nt = expect_t!(ts,"modport port expression",TokenKind::Ident);
+            node.attr.insert("name".to_owned(), nt.value.into());
+            expect_t!(ts,"modport port expression",TokenKind::ParenLeft);
+            let nt = ts.next_t(true)?;
+            if nt.kind == TokenKind::ParenRight {
+                ts.flush(1);
+            } else {
+                ts.rewind(1);
+                node.child.push(parse_expr(ts,ExprCntxt::Arg,false)?);
+                expect_t!(ts,"modport port expression",TokenKind::ParenRight);
+            }
+        }
+        _ =>  return Err(SvError::syntax(t,"modport. Expecting port name/expression")),
+    }
+    Ok(())
+}
+
+// Parse a modport import/export: method name or prototype task name(ports) / function type name(ports)
+// The prototype is a Task/Function child of the node
+fn parse_modport_method(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let kind = match t.kind {
+        TokenKind::Ident => {
+            node.attr.insert("name".to_owned(), t.value.into());
+            return Ok(());
+        }
+        TokenKind::KwTask => AstNodeKind::Task,
+        TokenKind::KwFunction => AstNodeKind::Function,
+        _ =>  return Err(SvError::syntax(t,"modport. Expecting method name or prototype")),
+    };
+    let mut node_m = AstNode::new(kind.clone(), t.pos);
+    // Function return type (optional)
+    if kind == AstNodeKind::Function {
+        ts.next_t(true)?;
+        let nt = ts.next_t(true)?;
+        if nt.kind != TokenKind::ParenLeft && nt.kind != TokenKind::Comma && nt.kind != TokenKind::ParenRight {
+            let mut nr = AstNode::new(AstNodeKind::Type, ts.get_pos());
+            parse_data_type(ts,&mut nr, 1)?;
+            node_m.child.push(nr);
+        } else {
+            ts.rewind(0);
+        }
+    }
+    let nt = expect_t!(ts,"modport method prototype",TokenKind::Ident);
+    node.attr.insert("name".to_owned(), nt.value.to_string());
+    node_m.attr.insert("name".to_owned(), nt.value.into());
+    let nt = ts.next_t(true)?;
+    if nt.kind == TokenKind::ParenLeft {
+        ts.flush(1);
+        let nt = ts.next_t(true)?;
+        if nt.kind != TokenKind::ParenRight {
+            ts.rewind(1);
+            let mut node_ports = AstNode::new(AstNodeKind::Ports, ts.get_pos());
+            loop {
+                node_ports.child.push(parse_port_decl(ts, true,ExprCntxt::ArgList)?);
+                loop_args_break_cont!(ts,"modport method prototype",ParenRight);
+            }
+            node_m.child.push(node_ports);
+        } else {
+            ts.flush(1);
+        }
+    } else {
+        ts.rewind(1);
+    }
+    node.child.push(node_m);
+    Ok(())
+}
+
+/// Parse a clocking block: event, default skews, signals and property/sequence declarations
+/// Each signal is a Port node with its direction, skews and the optional hierarchical expression as child
+/// A default clocking without event is a reference to a clocking block declared in the same scope
 pub fn parse_clocking(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
     let mut node_c = AstNode::new(AstNodeKind::Clocking, ts.last_pos);
     // Optionnal default/global
     let mut t = ts.next_t(false)?;
-    let has_id = t.kind!=TokenKind::KwDefault;
-    let need_event = t.kind==TokenKind::KwClocking;
+    let need_id = t.kind==TokenKind::KwClocking;
+    let is_default = t.kind==TokenKind::KwDefault;
     if t.kind== TokenKind::KwDefault || t.kind== TokenKind::KwGlobal {
-        node_c.attr.insert("scope".to_owned(), t.value);
+        node_c.attr.insert("scope".to_owned(), t.value.into());
         t = ts.next_t(false)?;
     }
     // Expect clocking keyword
     if t.kind!=TokenKind::KwClocking {
-        return Err(SvError::syntax(t,"clocking block. Expecting ;"));
+        return Err(SvError::syntax(t,"clocking block. Expecting clocking"));
     }
     t = ts.next_t(false)?;
-    // Clocking block identifier : optional when default
+    // Clocking block identifier : optional when default/global
     if t.kind == TokenKind::Ident {
-        node_c.attr.insert("name".to_owned(), t.value);
+        node_c.attr.insert("name".to_owned(), t.value.into());
         t = ts.next_t(false)?;
-    } else if has_id {
+    } else if need_id {
         return Err(SvError::syntax(t,"clocking block. Expecting identifier"));
     }
     // Expect clocking event
     match t.kind {
         TokenKind::At => {},
-        TokenKind::SemiColon if !need_event => {
+        TokenKind::SemiColon if is_default && node_c.attr.contains_key("name") => {
             node.child.push(node_c);
             return Ok(());
         },
         _ => return Err(SvError::syntax(t,"clocking block. Expecting @"))
     }
     node_c.child.push(parse_sensitivity(ts,true)?);
-    //
-    t = ts.next_t(false)?;
-    if t.kind!=TokenKind::SemiColon {
-        return Err(SvError::syntax(t,"clocking block. Expecting ;"));
-    }
+    expect_t!(ts,"clocking block",TokenKind::SemiColon);
+    let res = parse_clocking_body(ts,&mut node_c);
+    // Keep the clocking block even if incomplete
+    node.child.push(node_c);
+    res
+}
+
+fn parse_clocking_body(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
     loop {
+        match parse_clocking_item(ts,node) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(node,e)?,
+        }
+    }
+    node.end = ts.last_end;
+    Ok(())
+}
+
+// Parse one item of a clocking block: return true when endclocking is reached
+fn parse_clocking_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<bool, SvError> {
+    let t = ts.next_t(true)?;
+    match t.kind {
+        TokenKind::KwEndClocking => {
+            ts.flush(1);
+            if let Some(name) = node.attr.get("name") {
+                check_label(ts,name)?;
+            }
+            return Ok(true);
+        }
+        // Default skews: default input skew [output skew]; or default output skew;
+        TokenKind::KwDefault => {
+            ts.flush(1);
+            let mut nt = expect_t!(ts,"default skew",TokenKind::KwInput,TokenKind::KwOutput);
+            loop {
+                match parse_clocking_skew(ts)? {
+                    Some(s) => {node.attr.insert(format!("{}_skew",nt.value), s);}
+                    None => return Err(SvError::syntax(ts.next_t(false)?,"default skew. Expecting edge or delay"))
+                }
+                if nt.kind==TokenKind::KwOutput {
+                    expect_t!(ts,"default skew",TokenKind::SemiColon);
+                    break;
+                }
+                nt = expect_t!(ts,"default skew",TokenKind::KwOutput,TokenKind::SemiColon);
+                if nt.kind==TokenKind::SemiColon {break;}
+            }
+        }
+        // Signals: direction with optional skew followed by a list of name [= expression]
+        TokenKind::KwInput | TokenKind::KwOutput | TokenKind::KwInout => {
+            ts.flush(1);
+            let mut np = AstNode::new(AstNodeKind::Port, t.pos);
+            np.attr.insert("dir".to_owned(), t.value.to_string());
+            if t.kind != TokenKind::KwInout {
+                if let Some(s) = parse_clocking_skew(ts)? {
+                    np.attr.insert(format!("{}_skew",t.value), s);
+                }
+                // Input and output: equivalent to inout with different skews
+                if t.kind == TokenKind::KwInput {
+                    let nt = ts.next_t(true)?;
+                    if nt.kind == TokenKind::KwOutput {
+                        ts.flush(1);
+                        np.attr.insert("dir".to_owned(), "inout".to_owned());
+                        if let Some(s) = parse_clocking_skew(ts)? {
+                            np.attr.insert("output_skew".to_owned(), s);
+                        }
+                    } else {
+                        ts.rewind(1);
+                    }
+                }
+            }
+            loop {
+                let nt = expect_t!(ts,"clocking signal",TokenKind::Ident);
+                let mut ns = np.clone();
+                ns.pos = nt.pos;
+                ns.attr.insert("name".to_owned(), nt.value.into());
+                let nt = ts.next_t(true)?;
+                if nt.kind == TokenKind::OpEq {
+                    ts.flush(1);
+                    ns.child.push(parse_expr(ts,ExprCntxt::StmtList,false)?);
+                } else {
+                    ts.rewind(1);
+                }
+                node.child.push(ns);
+                let nt = expect_t!(ts,"clocking signal",TokenKind::Comma,TokenKind::SemiColon);
+                if nt.kind == TokenKind::SemiColon {break;}
+            }
+        }
+        TokenKind::KwProperty => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="sequence" => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="let" => parse_let(ts,node)?,
+        TokenKind::SemiColon => ts.flush(1),
+        _ => return Err(SvError::syntax(t,"clocking block. Expecting default, input, output, inout, property or sequence"))
+    }
+    Ok(false)
+}
+
+// Parse an optional clocking skew: edge and/or delay, returned as a string (e.g. "posedge #1ns")
+fn parse_clocking_skew(ts : &mut TokenStream) -> Result<Option<String>, SvError> {
+    let mut skew = Vec::new();
+    let mut t = ts.next_t(true)?;
+    if t.kind == TokenKind::KwEdge {
+        ts.flush(1);
+        skew.push(String::from(t.value));
+        t = ts.next_t(true)?;
+    }
+    if t.kind != TokenKind::Hash {
+        ts.rewind(1);
+    } else {
+        ts.flush(1);
         t = ts.next_t(false)?;
+        let mut v = format!("#{}",t.value);
         match t.kind {
-            TokenKind::KwEndClocking => {
-                if has_id {
-                    check_label(ts,&node_c.attr["name"])?;
+            TokenKind::Integer | TokenKind::Real => {
+                // Optional time unit
+                t = ts.next_t(true)?;
+                match t.value.as_ref() {
+                    "fs" |"ps" |"ns" |"us" |"ms" | "s" => {
+                        v.push_str(&t.value);
+                        ts.flush(1);
+                    }
+                    _ => ts.rewind(1)
                 }
-                break;
-            },
-            // TODO: actual parsing of clocking block
-            _ => {},
+            }
+            TokenKind::Kw1step | TokenKind::Ident => {}
+            // Delay expression: keep the tokens up to the closing parenthesis
+            TokenKind::ParenLeft => {
+                let mut depth = 1;
+                loop {
+                    t = ts.next_t(false)?;
+                    match t.kind {
+                        TokenKind::ParenLeft  => depth += 1,
+                        TokenKind::ParenRight => depth -= 1,
+                        _ => {}
+                    }
+                    v.push_str(&t.value);
+                    if depth == 0 {break;}
+                }
+            }
+            _ => return Err(SvError::syntax(t,"clocking skew. Expecting delay value"))
         }
+        skew.push(v);
     }
-
-    node.child.push(node_c);
-    Ok(())
+    Ok(if skew.is_empty() {None} else {Some(skew.join(" "))})
 }
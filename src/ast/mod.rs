@@ -2,6 +2,7 @@
 // Copyright (c) 2019, clams@mail.com
 
 pub mod astnode;
+pub mod cst;
 #[macro_use]
 mod common;
 mod module_hdr;
@@ -9,6 +10,13 @@ mod module_body;
 mod package;
 mod interface;
 mod class;
+mod covergroup;
+mod sva;
+mod constraint;
+mod specify;
+mod checker;
+mod udp;
+mod config;
 pub mod uvm_macro;
 
 use std::collections::HashMap;
@@ -16,7 +24,9 @@ use std::path::PathBuf;
 
 
 use astnode::*;
+use cst::CstElem;
 use common::*;
+pub use common::parse_macro;
 use module_hdr::*;
 use module_body::*;
 use crate::lex::{
@@ -31,10 +41,13 @@ use crate::reporter::{REPORTER, MsgID};
 pub struct MacroDef {
     pub ports : Vec<(String,Vec<Token>)>,
     pub body  : Vec<Token>,
+    // Definition site
+    pub fname : String,
+    pub pos   : Position,
 }
 
 impl MacroDef {
-    pub fn new() -> MacroDef { MacroDef{ports:Vec::new(),body:Vec::new()}}
+    pub fn new(fname: String, pos: Position) -> MacroDef { MacroDef{ports:Vec::new(),body:Vec::new(),fname,pos}}
 }
 
 pub type Defines = HashMap<String,Option<MacroDef>>;
@@ -44,6 +57,12 @@ pub struct Ast {
     pub filename: PathBuf,
     pub tree    : AstNode,
     pub defines : Defines,
+    /// Concrete syntax tree: all tokens and trivia in source order (only in lossless mode)
+    pub cst     : Vec<CstElem>,
+    /// Syntax errors found while building the AST (the parser skips the faulty part and continues)
+    pub errors  : Vec<SvError>,
+    /// Library the design elements of this file belong to (from the library map)
+    pub lib     : String,
 }
 
 impl Ast {
@@ -53,99 +72,155 @@ impl Ast {
             filename,
             tree: AstNode::new(AstNodeKind::Root, Position::new()),
             defines: HashMap::new(),
+            cst: Vec::new(),
+            errors: Vec::new(),
+            lib: "work".to_owned(),
         }
     }
 
+    /// Rebuild the exact source code (empty if the AST was not built in lossless mode)
+    pub fn to_source(&self) -> String {
+        cst::to_source(&self.cst)
+    }
+
     pub fn build(&mut self, ts : &mut TokenStream) -> Result<(),SvError> {
         loop {
-            match ts.next_t(true) {
-                Ok(t) => {
-                    // rpt_t!(MsgID::InfoStatus,&t, "top token");
-                    match t.kind {
-                        // Skip Comment / Attribute (TEMP)
-                        // TODO: actually use them to try associate comment with a node
-                        TokenKind::Comment => {},
-                        TokenKind::Macro => parse_macro(ts,&mut self.tree)?,
-                        TokenKind::CompDir => parse_macro(ts,&mut self.tree)?,
-                        TokenKind::KwModule => {
-                            ts.flush_rd();
-                            let mut node_m = AstNode::new(AstNodeKind::Module, t.pos);
-                            parse_module_hdr(ts,&mut node_m)?;
-                            let mut node_b = AstNode::new(AstNodeKind::Body, t.pos);
-                            parse_module_body(ts,&mut node_b, ModuleCntxt::Top)?;
-                            node_m.child.push(node_b);
-                            check_label(ts, &node_m.attr["name"])?;
-                            self.tree.child.push(node_m);
-                        },
-                        TokenKind::KwIntf => {
-                            let nt = ts.next_t(true)?;
-                            ts.rewind(0);
-                            match nt.kind {
-                                TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
-                                _ => self.tree.child.push(interface::parse_interface(ts)?)
-                            }
-                        },
-                        TokenKind::KwPackage => {
-                            ts.rewind(1);
-                            self.tree.child.push(package::parse_package(ts)?);
-                        },
-                        TokenKind::KwTypedef => parse_typedef(ts,&mut self.tree)?,
-                        TokenKind::KwImport | TokenKind::KwExport  => parse_import(ts,&mut self.tree)?,
-                        TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
-                        TokenKind::KwVirtual => {
-                            let nt = ts.next_t(true)?;
-                            match nt.kind {
-                                TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
-                                _ => return Err(SvError::syntax(nt, "virtual declaration. Expecting class"))
-                            }
-                        }
-                        // Parameters
-                        TokenKind::KwParam |
-                        TokenKind::KwLParam => {
-                            ts.rewind(1); // put back the token so that it can be read by the parse param function
-                            // potential list of param (the parse function extract only one at a time)
-                            loop {
-                                self.tree.child.push(parse_param_decl(ts,true)?);
-                                loop_args_break_cont!(ts,"parameter declaration",SemiColon);
-                            }
-                        }
-                        // Signal declaration
-                        TokenKind::KwConst       |
-                        TokenKind::KwReg         |
-                        TokenKind::KwVar         |
-                        TokenKind::TypeIntAtom   |
-                        TokenKind::TypeIntVector |
-                        TokenKind::TypeReal      |
-                        TokenKind::TypeString    |
-                        TokenKind::TypeCHandle   |
-                        TokenKind::TypeEvent     |
-                        TokenKind::Ident          => parse_signal_decl_list(ts,&mut self.tree)?,
-
-                        TokenKind::KwFunction => class::parse_func(ts, &mut self.tree, true, false)?,
-                        TokenKind::KwTask     => class::parse_task(ts, &mut self.tree)?,
-                        //
-                        TokenKind::SemiColon => ts.flush(1),
-                        // Display all un-implemented token (TEMP)
-                        _ => {
-                            rpt_s!(MsgID::DbgSkip, &format!("{}:{} | Parser Skipping {}", ts.source.get_filename(), t.pos, t.kind));
-                            // println!("[Warning] {:?} -- Root skipping {}",ts.source.get_filename(), t);
-                            ts.flush_rd();
-                        }
+            let t = match ts.next_t(true) {
+                Ok(t) => t,
+                Err(e) => match e.kind {
+                    SvErrorKind::Null |
+                    SvErrorKind::Eof  => break,
+                    // Lexical error: the faulty characters are consumed, continue with the next token
+                    SvErrorKind::Syntax |
+                    SvErrorKind::Token if ts.errors.last().map(|x| x.token.pos) != Some(e.token.pos) => {
+                        ts.errors.push(e);
+                        continue;
                     }
+                    _ => return Err(e),
                 }
-                Err(t) => {
-                    match t.kind {
-                        SvErrorKind::Null |
-                        SvErrorKind::Eof  => {
-                            // rpt_s!(MsgID::DbgStatus, &format!("{:?}", ts.project.defines.keys()));
-                            self.defines = ts.project.defines.clone();
-                            return Ok(());
-                        }
-                        _ => return Err(t),
-                    }
+            };
+            // rpt_t!(MsgID::InfoStatus,&t, "top token");
+            match self.parse_item(ts,t) {
+                Ok(()) => {}
+                // End of file reached inside a construct: keep what was parsed so far
+                Err(e) if e.kind==SvErrorKind::Null => {
+                    ts.errors.push(SvError::eof(ts.last_end));
+                    break;
+                }
+                Err(e) if e.kind==SvErrorKind::Eof => {
+                    ts.errors.push(e);
+                    break;
+                }
+                // Syntax error: skip to the next item and continue
+                Err(e) => ts.resync(&mut self.tree,e)?,
+            }
+        }
+        // rpt_s!(MsgID::DbgStatus, &format!("{:?}", ts.project.defines.keys()));
+        self.defines = ts.project.defines.clone();
+        self.errors = std::mem::take(&mut ts.errors);
+        self.tree.update_span();
+        if let Some(cst) = ts.cst_finish() {
+            cst::attach_trivia(&mut self.tree, &cst);
+            self.cst = cst;
+        }
+        Ok(())
+    }
+
+    // Parse one item at the top level of a file
+    fn parse_item(&mut self, ts : &mut TokenStream, t: Token) -> Result<(),SvError> {
+        match t.kind {
+            // Skip Comment: in lossless mode they are attached to the nodes from the CST
+            TokenKind::Comment => {},
+            TokenKind::Macro => parse_macro(ts,&mut self.tree)?,
+            TokenKind::CompDir => parse_macro(ts,&mut self.tree)?,
+            TokenKind::KwModule => {
+                ts.flush_rd();
+                let mut node_m = AstNode::new(AstNodeKind::Module, t.pos);
+                parse_module_hdr(ts,&mut node_m)?;
+                let mut node_b = AstNode::new(AstNodeKind::Body, t.pos);
+                let res = parse_module_body(ts,&mut node_b, ModuleCntxt::Top)
+                    .and_then(|_| check_label(ts, &node_m.attr["name"]));
+                node_m.end = ts.last_end;
+                node_b.end = ts.last_end;
+                // Keep the module even if incomplete (e.g. end of file reached in the body)
+                node_m.child.push(node_b);
+                self.tree.child.push(node_m);
+                res?;
+            },
+            TokenKind::Keyword if t.value=="program" => {
+                ts.flush_rd();
+                let mut node_p = AstNode::new(AstNodeKind::Program, t.pos);
+                parse_module_hdr(ts,&mut node_p)?;
+                let mut node_b = AstNode::new(AstNodeKind::Body, t.pos);
+                let res = parse_module_body(ts,&mut node_b, ModuleCntxt::Program)
+                    .and_then(|_| check_label(ts, &node_p.attr["name"]));
+                node_p.end = ts.last_end;
+                node_b.end = ts.last_end;
+                node_p.child.push(node_b);
+                self.tree.child.push(node_p);
+                res?;
+            },
+            TokenKind::Keyword if t.value=="checker" => checker::parse_checker(ts,&mut self.tree)?,
+            TokenKind::Keyword if t.value=="let"     => checker::parse_let(ts,&mut self.tree)?,
+            TokenKind::Keyword if t.value=="primitive" => udp::parse_udp(ts,&mut self.tree)?,
+            TokenKind::Keyword if t.value=="config"    => config::parse_config(ts,&mut self.tree)?,
+            TokenKind::KwIntf => {
+                let nt = ts.next_t(true)?;
+                ts.rewind(0);
+                match nt.kind {
+                    TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
+                    _ => self.tree.child.push(interface::parse_interface(ts)?)
+                }
+            },
+            TokenKind::KwPackage => {
+                ts.rewind(1);
+                self.tree.child.push(package::parse_package(ts)?);
+            },
+            TokenKind::KwTypedef => parse_typedef(ts,&mut self.tree)?,
+            TokenKind::KwImport | TokenKind::KwExport  => parse_import(ts,&mut self.tree)?,
+            TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
+            TokenKind::KwVirtual => {
+                let nt = ts.next_t(true)?;
+                match nt.kind {
+                    TokenKind::KwClass => self.tree.child.push(class::parse_class(ts)?),
+                    _ => return Err(SvError::syntax(nt, "virtual declaration. Expecting class"))
                 }
             }
+            // Parameters
+            TokenKind::KwParam |
+            TokenKind::KwLParam => {
+                ts.rewind(1); // put back the token so that it can be read by the parse param function
+                // potential list of param (the parse function extract only one at a time)
+                loop {
+                    self.tree.child.push(parse_param_decl(ts,true)?);
+                    loop_args_break_cont!(ts,"parameter declaration",SemiColon);
+                }
+            }
+            // Signal declaration
+            TokenKind::KwConst       |
+            TokenKind::KwReg         |
+            TokenKind::KwVar         |
+            TokenKind::TypeIntAtom   |
+            TokenKind::TypeIntVector |
+            TokenKind::TypeReal      |
+            TokenKind::TypeString    |
+            TokenKind::TypeCHandle   |
+            TokenKind::TypeEvent     |
+            TokenKind::Ident          => parse_signal_decl_list(ts,&mut self.tree)?,
+
+            TokenKind::KwFunction => class::parse_func(ts, &mut self.tree, true, false)?,
+            TokenKind::KwTask     => class::parse_task(ts, &mut self.tree, false)?,
+            TokenKind::KwConstraint => constraint::parse_constraint(ts, &mut self.tree)?,
+            //
+            TokenKind::SemiColon => ts.flush(1),
+            // Display all un-implemented token (TEMP)
+            _ => {
+                rpt_s!(MsgID::DbgSkip, &format!("{}:{} | Parser Skipping {}", ts.source.get_filename(), t.pos, t.kind));
+                // println!("[Warning] {:?} -- Root skipping {}",ts.source.get_filename(), t);
+                ts.flush_rd();
+            }
         }
+        Ok(())
     }
 
 }
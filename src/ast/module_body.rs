@@ -3,19 +3,24 @@
 
 use crate::reporter::{REPORTER, MsgID};
 use crate::error::SvError;
-use crate::lex::token::{TokenKind};
+use crate::lex::token::{Token,TokenKind};
 use crate::lex::token_stream::TokenStream;
 use crate::ast::astnode::*;
 use crate::ast::common::*;
 use crate::ast::class::{parse_class,parse_func,parse_task,parse_class_stmt,parse_assign_or_call};
+use crate::ast::covergroup::parse_covergroup;
+use crate::ast::sva::parse_sva_decl;
+use crate::ast::specify::{parse_specify,parse_spec_value};
+use crate::ast::checker::{parse_checker,parse_let};
+use crate::ast::interface::parse_clocking;
 
 // TODO
 // - when parsing named block, ensure the name is unique
 
 #[allow(dead_code)]
-#[derive(PartialEq,Debug)]
+#[derive(PartialEq,Debug,Clone,Copy)]
 pub enum ModuleCntxt {
-    Top, Generate, Block, ForStmt, IfStmt
+    Top, Program, Checker, Generate, Block, ForStmt, IfStmt
 }
 
 
@@ -24,246 +29,273 @@ pub fn parse_module_body(ts : &mut TokenStream, node : &mut AstNode, cntxt : Mod
     loop {
         let t = ts.next_t(true)?;
         // println!("[parse_module_body] Token = {}", t);
-        match t.kind {
-            // Import statement
-            TokenKind::KwImport => parse_import(ts,node)?,
-            // Param/local param declaration
-            TokenKind::KwParam | TokenKind::KwLParam | TokenKind::KwSpecParam => {
-                ts.rewind(1); // put back the token so that it can be read by the parse param function
-                // potential list of param (the parse function extract only one at a time)
-                loop {
-                    node.child.push(parse_param_decl(ts,true)?);
-                    loop_args_break_cont!(ts,"parameter declaration",SemiColon);
-                }
-            }
-            // Port
-            TokenKind::KwInput | TokenKind::KwOutput | TokenKind::KwInout | TokenKind::KwRef => {
-                ts.rewind(1); // put back the token so that it can be read by the parse param function
-                node.child.push(parse_port_decl(ts,false,ExprCntxt::StmtList)?);
+        match parse_module_item(ts,node,cntxt,t) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(node,e)?,
+        }
+
+        if cntxt == ModuleCntxt::ForStmt || cntxt == ModuleCntxt::IfStmt {
+            break;
+        }
+    }
+    // ts.flush_rd();
+    Ok(())
+    // Err(SvError {kind:SvErrorKind::NotSupported, pos: t.pos, txt: "Module body".to_owned()})
+}
+
+// Parse one item of a module body: return true when the end of the body is reached
+fn parse_module_item(ts : &mut TokenStream, node : &mut AstNode, cntxt : ModuleCntxt, t: Token) -> Result<bool, SvError> {
+    match t.kind {
+        // Import statement
+        TokenKind::KwImport | TokenKind::KwExport => parse_import(ts,node)?,
+        // Param/local param declaration
+        TokenKind::KwParam | TokenKind::KwLParam | TokenKind::KwSpecParam => {
+            ts.rewind(1); // put back the token so that it can be read by the parse param function
+            // potential list of param (the parse function extract only one at a time)
+            loop {
+                node.child.push(parse_param_decl(ts,true)?);
+                loop_args_break_cont!(ts,"parameter declaration",SemiColon);
             }
-            // Nettype
-            TokenKind::KwNetType |
-            TokenKind::KwSupply  =>  parse_signal_decl_list(ts,node)?,
-            // Basetype
-            TokenKind::KwConst       |
-            TokenKind::KwReg         |
-            TokenKind::KwVar         |
-            TokenKind::TypeIntAtom   |
-            TokenKind::TypeIntVector |
-            TokenKind::TypeReal      |
-            TokenKind::TypeString    |
-            TokenKind::TypeCHandle   |
-            TokenKind::TypeEvent     => parse_signal_decl_list(ts,node)?,
-            TokenKind::KwInterconnect => {
-                let mut node_d = AstNode::new(AstNodeKind::Declaration, t.pos);
-                node.attr.insert("type".to_owned(), "interconnect".to_string());
+        }
+        // Port
+        TokenKind::KwInput | TokenKind::KwOutput | TokenKind::KwInout | TokenKind::KwRef => {
+            ts.rewind(1); // put back the token so that it can be read by the parse param function
+            node.child.push(parse_port_decl(ts,false,ExprCntxt::StmtList)?);
+        }
+        // Nettype
+        TokenKind::KwNetType |
+        TokenKind::KwSupply  =>  parse_signal_decl_list(ts,node)?,
+        // Basetype
+        TokenKind::KwConst       |
+        TokenKind::KwReg         |
+        TokenKind::KwVar         |
+        TokenKind::TypeIntAtom   |
+        TokenKind::TypeIntVector |
+        TokenKind::TypeReal      |
+        TokenKind::TypeString    |
+        TokenKind::TypeCHandle   |
+        TokenKind::TypeEvent     => parse_signal_decl_list(ts,node)?,
+        TokenKind::KwInterconnect => {
+            let mut node_d = AstNode::new(AstNodeKind::Declaration, t.pos);
+            node.attr.insert("type".to_owned(), "interconnect".to_string());
+            ts.flush(1);
+            let mut nt = ts.next_t(true)?;
+            if nt.kind == TokenKind::KwSigning {
+                node_d.attr.insert("signing".to_owned(), nt.value.into());
                 ts.flush(1);
-                let mut nt = ts.next_t(true)?;
-                if nt.kind == TokenKind::KwSigning {
-                    node_d.attr.insert("signing".to_owned(), nt.value);
-                    ts.flush(1);
-                    nt = ts.next_t(true)?;
-                }
-                if nt.kind == TokenKind::SquareLeft {
-                    ts.rewind(1);
-                    parse_opt_slice(ts,&mut node_d,true,false)?;
-                }
-                parse_var_decl_name(ts, &mut node_d,ExprCntxt::StmtList,false,false)?;
-                // allow list of interconnect
-                loop {
-                    loop_args_break_cont!(ts,"interconnect declaration",SemiColon);
-                    let mut node_l = AstNode::new(AstNodeKind::Declaration, ts.get_pos());
-                    parse_var_decl_name(ts, &mut node_l,ExprCntxt::StmtList,false,false)?;
-                    node_d.child.push(node_l);
-                }
-                node.child.push(node_d);
+                nt = ts.next_t(true)?;
             }
-            TokenKind::KwEnum        => {
-                let mut node_e = parse_enum(ts,false)?;
-                parse_ident_list(ts,&mut node_e)?;
-                node.child.push(node_e);
+            if nt.kind == TokenKind::SquareLeft {
+                ts.rewind(1);
+                parse_opt_slice(ts,&mut node_d,true,false)?;
             }
-            TokenKind::KwStruct |
-            TokenKind::KwUnion  => {
-                let mut node_s = parse_struct(ts)?;
-                parse_ident_list(ts,&mut node_s)?;
-                node.child.push(node_s);
+            parse_var_decl_name(ts, &mut node_d,ExprCntxt::StmtList,false,false)?;
+            // allow list of interconnect
+            loop {
+                loop_args_break_cont!(ts,"interconnect declaration",SemiColon);
+                let mut node_l = AstNode::new(AstNodeKind::Declaration, ts.get_pos());
+                parse_var_decl_name(ts, &mut node_l,ExprCntxt::StmtList,false,false)?;
+                node_d.child.push(node_l);
             }
-            TokenKind::KwTypedef => parse_typedef(ts,node)?,
-            TokenKind::KwNettype => parse_nettype(ts,node)?,
-            TokenKind::TypeGenvar => {
-                ts.flush_rd();
-                loop {
-                    let nt = ts.next_t(false)?;
-                    match nt.kind {
-                        TokenKind::Ident => {
-                            let mut n = AstNode::new(AstNodeKind::Declaration, nt.pos);
-                            n.attr.insert("type".to_owned(), "genvar".to_owned());
-                            n.attr.insert("name".to_owned(),t.value.clone());
-                            node.child.push(n);
-                            loop_args_break_cont!(ts,"genvar declaration",SemiColon);
-                        }
-                        _ =>  return Err(SvError::syntax(t,"genvar. Expecting identifier")),
+            node.child.push(node_d);
+        }
+        TokenKind::KwEnum        => {
+            let mut node_e = parse_enum(ts,false)?;
+            parse_ident_list(ts,&mut node_e)?;
+            node.child.push(node_e);
+        }
+        TokenKind::KwStruct |
+        TokenKind::KwUnion  => {
+            let mut node_s = parse_struct(ts)?;
+            parse_ident_list(ts,&mut node_s)?;
+            node.child.push(node_s);
+        }
+        TokenKind::KwTypedef => parse_typedef(ts,node)?,
+        TokenKind::KwNettype => parse_nettype(ts,node)?,
+        TokenKind::TypeGenvar => {
+            ts.flush_rd();
+            loop {
+                let nt = ts.next_t(false)?;
+                match nt.kind {
+                    TokenKind::Ident => {
+                        let mut n = AstNode::new(AstNodeKind::Declaration, nt.pos);
+                        n.attr.insert("type".to_owned(), "genvar".to_owned());
+                        n.attr.insert("name".to_owned(),t.value.to_string());
+                        node.child.push(n);
+                        loop_args_break_cont!(ts,"genvar declaration",SemiColon);
                     }
+                    _ =>  return Err(SvError::syntax(t,"genvar. Expecting identifier")),
                 }
             }
-            // Class (interface class)
-            TokenKind::KwIntf |
-            TokenKind::KwVirtual |
-            TokenKind::KwClass => node.child.push(parse_class(ts)?),
-            // Primite
-            TokenKind::KwOr       |
-            TokenKind::KwPrimCmos |
-            TokenKind::KwPrimMos  |
-            TokenKind::KwPrimEn   |
-            TokenKind::KwPrimIn   |
-            TokenKind::KwPrimOut  |
-            TokenKind::KwPrimTran |
-            TokenKind::KwPrimTranif => node.child.push(parse_primitive(ts)?),
-            // Identifier -> lookahead to detect if it is a signal declaration or an instantiation
-            TokenKind::Ident => {
-                let mut nt = ts.next_t(true)?;
-                // println!("[Module body] Ident followed by {}", nt.kind);
-                match nt.kind {
-                    // Scope -> this is a type definition
-                    TokenKind::Scope => parse_signal_decl_list(ts,node)?,
-                    // Colon : this was a label
-                    TokenKind::Colon => {
-                        ts.flush(2);
-                        let mut n = AstNode::new(AstNodeKind::Statement, t.pos);
-                        n.attr.insert("label".to_owned(),t.value);
-                        nt = ts.next_t(true)?;
-                        match nt.kind {
-                            TokenKind::KwAssert => parse_assert(ts,node)?,
-                            u => {
-                                println!("[parse_module_body] Labeled stateent {} not supported", u);
-                                // Expect assertion: not support for the moment ...
-                                ts.skip_until(TokenKind::SemiColon)?;
-                            }
+        }
+        // Class (interface class)
+        TokenKind::KwIntf |
+        TokenKind::KwVirtual |
+        TokenKind::KwClass => node.child.push(parse_class(ts)?),
+        // Primite
+        TokenKind::KwOr       |
+        TokenKind::KwPrimCmos |
+        TokenKind::KwPrimMos  |
+        TokenKind::KwPrimEn   |
+        TokenKind::KwPrimIn   |
+        TokenKind::KwPrimOut  |
+        TokenKind::KwPrimTran |
+        TokenKind::KwPrimTranif => node.child.push(parse_primitive(ts)?),
+        TokenKind::Keyword if t.value=="pullup" || t.value=="pulldown" => node.child.push(parse_primitive(ts)?),
+        // Identifier -> lookahead to detect if it is a signal declaration or an instantiation
+        TokenKind::Ident => {
+            let mut nt = ts.next_t(true)?;
+            // println!("[Module body] Ident followed by {}", nt.kind);
+            match nt.kind {
+                // Scope -> this is a type definition
+                TokenKind::Scope => parse_signal_decl_list(ts,node)?,
+                // Colon : this was a label
+                TokenKind::Colon => {
+                    ts.flush(2);
+                    nt = ts.next_t(true)?;
+                    match nt.kind {
+                        TokenKind::KwAssert |
+                        TokenKind::KwCover  => parse_assert(ts,node)?,
+                        TokenKind::Keyword if nt.value=="assume" || nt.value=="restrict" => parse_assert(ts,node)?,
+                        u => {
+                            println!("[parse_module_body] Labeled stateent {} not supported", u);
+                            // Expect assertion: not support for the moment ...
+                            ts.skip_until(TokenKind::SemiColon)?;
+                            return Ok(false);
                         }
-
                     }
-                    // Identifier : could be a signal declaration or a module/interface instantiation
-                    TokenKind::Ident => {
-                        nt = ts.next_t(true)?;
-                        // println!("[Module body] (Ident Ident) followed by {}", nt.kind);
-                        match nt.kind {
-                            // Semi colon, comma or equal indicate signal declaration
-                            TokenKind::SemiColon |
-                            TokenKind::OpEq      |
-                            TokenKind::Comma     =>  parse_signal_decl_list(ts,node)?,
-                            // Slice -> can be either an unpacked array declaration or an array of instance ...
-                            TokenKind::SquareLeft =>  {
-                                ts.peek_until(TokenKind::SquareRight)?;
-                                nt = ts.next_t(true)?;
-                                if nt.kind == TokenKind::ParenLeft {
-                                    node.child.push(parse_instance(ts)?);
-                                } else {
-                                    parse_signal_decl_list(ts,node)?;
-                                }
-                            }
-                            // Open parenthesis -> instance
-                            TokenKind::ParenLeft => {
-                                let node_inst = parse_instance(ts)?;
-                                node.child.push(node_inst);
+                    if let Some(n) = node.child.last_mut() {
+                        n.attr.insert("label".to_owned(),t.value.into());
+                    }
+                }
+                // Identifier : could be a signal declaration or a module/interface instantiation
+                TokenKind::Ident => {
+                    nt = ts.next_t(true)?;
+                    // println!("[Module body] (Ident Ident) followed by {}", nt.kind);
+                    match nt.kind {
+                        // Semi colon, comma or equal indicate signal declaration
+                        TokenKind::SemiColon |
+                        TokenKind::OpEq      |
+                        TokenKind::Comma     =>  parse_signal_decl_list(ts,node)?,
+                        // Slice -> can be either an unpacked array declaration or an array of instance ...
+                        TokenKind::SquareLeft =>  {
+                            ts.peek_until(TokenKind::SquareRight)?;
+                            nt = ts.next_t(true)?;
+                            if nt.kind == TokenKind::ParenLeft {
+                                node.child.push(parse_instance(ts)?);
+                            } else {
+                                parse_signal_decl_list(ts,node)?;
                             }
-                            _ => return Err(SvError::syntax(t, "signal declaration/instance"))
                         }
+                        // Open parenthesis -> instance
+                        TokenKind::ParenLeft => {
+                            let node_inst = parse_instance(ts)?;
+                            node.child.push(node_inst);
+                        }
+                        _ => return Err(SvError::syntax(t, "signal declaration/instance"))
                     }
-                    // Open bracket indicate a packet dimension, i.e. a signal declaration
-                    TokenKind::SquareLeft =>  parse_signal_decl_list(ts,node)?,
-                    // Dash : Can be a parametiyed class of a parameterized interface
-                    TokenKind::Hash => {
-                        let node_inst = parse_instance(ts)?;
-                        node.child.push(node_inst);
-                    }
-                    // Untreated token are forbidden
-                    _ => return Err(SvError::syntax(t, "signal declaration. Expecting type or instance"))
                 }
-            }
-            TokenKind::KwBind => parse_bind(ts,node)?,
-            //
-            TokenKind::KwAssign | TokenKind::KwDefparam => {
-                ts.rewind(1);
-                node.child.push(parse_assign_c(ts)?);
-            }
-            // Always keyword
-            TokenKind::KwAlways  |
-            TokenKind::KwAlwaysC |
-            TokenKind::KwAlwaysF |
-            TokenKind::KwAlwaysL  => parse_always(ts, node)?,
-            TokenKind::KwInitial  => parse_initial(ts, node)?,
-            TokenKind::KwFinal    => parse_initial(ts, node)?,
-            TokenKind::KwFunction => parse_func(ts, node, false, false)?,
-            TokenKind::KwTask     => parse_task(ts, node)?,
-            //
-            TokenKind::KwTimeunit | TokenKind::KwTimeprec => parse_timescale(ts,node)?,
-            //
-            TokenKind::KwGenerate if cntxt==ModuleCntxt::Top => {
-                ts.flush_rd();
-                parse_module_body(ts,node,ModuleCntxt::Generate)?;
-            }
-            TokenKind::KwFor  => parse_for(ts,node,true)?,
-            TokenKind::KwIf   => parse_if_else(ts,node, true)?,
-            TokenKind::KwBegin => {
-                ts.flush_rd();
-                let mut n = AstNode::new(AstNodeKind::Block, t.pos);
-                parse_label(ts,&mut n,"block".to_owned())?;
-                parse_module_body(ts,&mut n, ModuleCntxt::Block)?;
-                if n.attr["block"]!="" {
-                    check_label(ts, &n.attr["block"])?;
+                // Open bracket indicate a packet dimension, i.e. a signal declaration
+                TokenKind::SquareLeft =>  parse_signal_decl_list(ts,node)?,
+                // Open parenthesis -> unnamed instance (user-defined primitive)
+                TokenKind::ParenLeft => node.child.push(parse_instance(ts)?),
+                // Dash : Can be a parametiyed class of a parameterized interface
+                TokenKind::Hash => {
+                    let node_inst = parse_instance(ts)?;
+                    node.child.push(node_inst);
                 }
+                // Untreated token are forbidden
+                _ => return Err(SvError::syntax(t, "signal declaration. Expecting type or instance"))
             }
-            TokenKind::KwAssert     => parse_assert(ts,node)?,
-            TokenKind::KwCovergroup => parse_covergroup(ts,node)?,
-            TokenKind::KwProperty   => parse_sva_property(ts,node)?,
-            TokenKind::SemiColon    => {ts.flush(1);}, // TODO: generate a warning
-            // End of loop depends on context
-            TokenKind::KwEnd         if cntxt == ModuleCntxt::Block    => {ts.flush(1); break},
-            TokenKind::KwEndGenerate if cntxt == ModuleCntxt::Generate => {ts.flush(1); break},
-            TokenKind::KwEndModule   if cntxt == ModuleCntxt::Top      => {ts.flush(1); break},
-            TokenKind::Macro => parse_macro(ts,node)?,
-            TokenKind::CompDir => parse_macro(ts,node)?,
-            TokenKind::SystemTask => {
-                // allow only elaboration system task: fatal/error/warning/info
-                // But still parse the whole systemTask and try to continue
-                let node_c = parse_system_task(ts)?;
-                match t.value.as_ref() {
-                    "$error" | "$fatal" | "$warning" | "$info" => node.child.push(node_c),
-                    _ => rpt_t!(MsgID::ErrInvalid,&t,"module body")
-                }
-
-            }
-            // Any un-treated token is an error
-            _ => {
-                // println!("{}", node);
-                return Err(SvError::syntax(t, "module body"))
+        }
+        TokenKind::KwBind => parse_bind(ts,node)?,
+        //
+        TokenKind::KwAssign | TokenKind::KwDefparam => {
+            ts.rewind(1);
+            node.child.push(parse_assign_c(ts)?);
+        }
+        // Always keyword
+        TokenKind::KwAlways  |
+        TokenKind::KwAlwaysC |
+        TokenKind::KwAlwaysF |
+        TokenKind::KwAlwaysL  => parse_always(ts, node)?,
+        TokenKind::KwInitial  => parse_initial(ts, node)?,
+        TokenKind::KwFinal    => parse_initial(ts, node)?,
+        TokenKind::KwFunction => parse_func(ts, node, false, false)?,
+        TokenKind::KwTask     => parse_task(ts, node, false)?,
+        //
+        TokenKind::KwTimeunit | TokenKind::KwTimeprec => parse_timescale(ts,node)?,
+        //
+        TokenKind::KwGenerate if cntxt==ModuleCntxt::Top || cntxt==ModuleCntxt::Program || cntxt==ModuleCntxt::Checker => {
+            ts.flush_rd();
+            parse_module_body(ts,node,ModuleCntxt::Generate)?;
+        }
+        TokenKind::KwFor  => parse_for(ts,node,true)?,
+        TokenKind::KwIf   => parse_if_else(ts,node, true)?,
+        TokenKind::KwBegin => {
+            ts.flush_rd();
+            let mut n = AstNode::new(AstNodeKind::Block, t.pos);
+            parse_label(ts,&mut n,"block".to_owned())?;
+            parse_module_body(ts,&mut n, ModuleCntxt::Block)?;
+            if n.attr["block"]!="" {
+                check_label(ts, &n.attr["block"])?;
             }
+            n.end = ts.last_end;
         }
+        TokenKind::KwAssert     |
+        TokenKind::KwCover      => parse_assert(ts,node)?,
+        TokenKind::Keyword if t.value=="assume" || t.value=="restrict" => parse_assert(ts,node)?,
+        TokenKind::KwDefault | TokenKind::KwClocking | TokenKind::KwGlobal => parse_clocking(ts,node)?,
+        TokenKind::KwCovergroup => parse_covergroup(ts,node)?,
+        TokenKind::KwProperty   => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="sequence" => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="specify"  => parse_specify(ts,node)?,
+        TokenKind::Keyword if t.value=="checker"  => parse_checker(ts,node)?,
+        TokenKind::Keyword if t.value=="let"      => parse_let(ts,node)?,
+        TokenKind::SemiColon    => {ts.flush(1);}, // TODO: generate a warning
+        // End of loop depends on context
+        TokenKind::KwEnd         if cntxt == ModuleCntxt::Block    => {ts.flush(1); return Ok(true)},
+        TokenKind::KwEndGenerate if cntxt == ModuleCntxt::Generate => {ts.flush(1); return Ok(true)},
+        TokenKind::KwEndModule   if cntxt == ModuleCntxt::Top      => {ts.flush(1); return Ok(true)},
+        TokenKind::Keyword if t.value=="endprogram" && cntxt == ModuleCntxt::Program => {ts.flush(1); return Ok(true)},
+        TokenKind::Keyword if t.value=="endchecker" && cntxt == ModuleCntxt::Checker => {ts.flush(1); return Ok(true)},
+        TokenKind::Macro => parse_macro(ts,node)?,
+        TokenKind::CompDir => parse_macro(ts,node)?,
+        TokenKind::SystemTask => {
+            // allow only elaboration system task: fatal/error/warning/info
+            // But still parse the whole systemTask and try to continue
+            let node_c = parse_system_task(ts)?;
+            match t.value.as_ref() {
+                "$error" | "$fatal" | "$warning" | "$info" => node.child.push(node_c),
+                _ => rpt_t!(MsgID::ErrInvalid,&t,"module body")
+            }
 
-        if cntxt == ModuleCntxt::ForStmt || cntxt == ModuleCntxt::IfStmt {
-            break;
+        }
+        // Any un-treated token is an error
+        _ => {
+            // println!("{}", node);
+            return Err(SvError::syntax(t, "module body"))
         }
     }
-    // ts.flush_rd();
-    Ok(())
-    // Err(SvError {kind:SvErrorKind::NotSupported, pos: t.pos, txt: "Module body".to_owned()})
+    Ok(false)
 }
 
 // Parse a continous assignment / defparam
 pub fn parse_assign_c(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     let mut t = ts.next_t(false)?; // Get first word: expect assign or defparam
     let mut node = AstNode::new(AstNodeKind::Assign, t.pos);
-    node.attr.insert("kind".to_owned(),t.value);
+    node.attr.insert("kind".to_owned(),t.value.into());
     t = ts.next_t(true)?;
     if t.kind==TokenKind::ParenLeft {
         ts.flush(1);
         t = expect_t!(ts,"drive strength",TokenKind::KwDrive);
         let drive_is_0 = t.value.ends_with('0');
         if drive_is_0 {
-            node.attr.insert("strength0".to_owned(),t.value);
+            node.attr.insert("strength0".to_owned(),t.value.into());
         } else {
-            node.attr.insert("strength1".to_owned(),t.value);
+            node.attr.insert("strength1".to_owned(),t.value.into());
         }
         expect_t!(ts,"drive strength",TokenKind::Comma);
         t = expect_t!(ts,"drive strength",TokenKind::KwDrive);
@@ -271,12 +303,12 @@ pub fn parse_assign_c(ts : &mut TokenStream) -> Result<AstNode, SvError> {
             if t.value.ends_with('0') {
                 return Err(SvError::syntax(t, "drive strength. Expecting drive strength 1"))
             }
-            node.attr.insert("strength1".to_owned(),t.value);
+            node.attr.insert("strength1".to_owned(),t.value.into());
         } else {
             if t.value.ends_with('1') {
                 return Err(SvError::syntax(t, "drive strength. Expecting drive strength 0"))
             }
-            node.attr.insert("strength0".to_owned(),t.value);
+            node.attr.insert("strength0".to_owned(),t.value.into());
         }
         expect_t!(ts,"drive strength",TokenKind::ParenRight);
     }
@@ -315,11 +347,11 @@ pub fn parse_assign_bnb(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     node.child.push(parse_ident_hier(ts)?);
     // Expect <=, = or composed asisgnement
     let mut t = expect_t!(ts,"assign",TokenKind::OpLTE,TokenKind::OpEq,TokenKind::OpCompAss);
-    node.attr.insert("kind".to_owned(),t.value);
+    node.attr.insert("kind".to_owned(),t.value.into());
     // Optional delay
     if t.kind==TokenKind::OpLTE {
         t = ts.next_t(true)?;
-        if t.kind==TokenKind::Hash {
+        if t.kind==TokenKind::Hash || t.kind==TokenKind::Hash2 {
             node.child.push(parse_delay(ts)?);
         } else {
             ts.rewind(1);
@@ -339,17 +371,28 @@ pub fn parse_instance(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     ts.rewind(0);
     let mut t = ts.next_t(false)?;
     let mut node = AstNode::new(AstNodeKind::Instances, t.pos);
-    node.attr.insert("type".to_owned(), t.value);
+    node.attr.insert("type".to_owned(), t.value.into());
     t = ts.next_t(true)?;
     parse_opt_params!(ts,node,t);
     ts.rewind(0);
     // Instances can be a list
     loop {
-        t = expect_t!(ts,"instance name",TokenKind::Ident);
+        t = ts.next_t(true)?;
         let mut node_i = AstNode::new(AstNodeKind::Instance, t.pos);
-        node_i.attr.insert("name".to_owned(), t.value);
-        // Test for array of instance
-        parse_opt_slice(ts, &mut node_i, true,false)?;
+        match t.kind {
+            TokenKind::Ident => {
+                ts.flush(1);
+                node_i.attr.insert("name".to_owned(), t.value.into());
+                // Test for array of instance
+                parse_opt_slice(ts, &mut node_i, true,false)?;
+            }
+            // Instance name is optional for user-defined primitive
+            TokenKind::ParenLeft => {
+                ts.rewind(1);
+                node_i.attr.insert("name".to_owned(), "".to_owned());
+            }
+            _ => return Err(SvError::syntax(t, "instance name. Expecting Ident"))
+        }
         parse_port_connection(ts,&mut node_i,false)?;
         node.child.push(node_i);
         loop_args_break_cont!(ts,"param declaration",SemiColon);
@@ -373,7 +416,7 @@ pub fn parse_bind(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvErr
 pub fn parse_always(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
     let t0 = ts.next_t(false)?;
     let mut n = AstNode::new(AstNodeKind::Process, t0.pos);
-    n.attr.insert("kind".to_owned(),t0.value.clone());
+    n.attr.insert("kind".to_owned(),t0.value.to_string());
     let t = ts.next_t(true)?;
     // println!("[parse_always] Node {}\nFirst Token {}",n, t);
     if t.kind == TokenKind::At {
@@ -393,6 +436,7 @@ pub fn parse_always(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvE
     }
     //
     parse_class_stmt(ts, &mut n, false, false, false, true)?;
+    n.end = ts.last_end;
     // parse_class_stmt_or_block(ts,&mut n)?;
     node.child.push(n);
     Ok(())
@@ -411,7 +455,7 @@ pub fn parse_sensitivity(ts : &mut TokenStream, is_process: bool) -> Result<AstN
         TokenKind::SensiAll => {ts.flush(1); return Ok(node);}
         TokenKind::Ident if !is_process => {
             node.child.push(parse_ident_hier(ts)?);
-            // node.attr.insert("clk_event".to_owned(), t.value);
+            // node.attr.insert("clk_event".to_owned(), t.value.into());
             return Ok(node);
         }
         TokenKind::ParenLeft => {
@@ -431,7 +475,7 @@ pub fn parse_sensitivity(ts : &mut TokenStream, is_process: bool) -> Result<AstN
         // Capture optionnal edge
         let mut n = AstNode::new(AstNodeKind::Event, t.pos);
         if t.kind == TokenKind::KwEdge {
-            n.attr.insert("edge".to_owned(),t.value );
+            n.attr.insert("edge".to_owned(),t.value.into());
             ts.flush_rd(); // consume keyword
         } else {
             ts.rewind(1);
@@ -465,8 +509,9 @@ pub fn parse_initial(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), Sv
     ts.rewind(1);
     let t = ts.next_t(false)?;
     let mut n = AstNode::new(AstNodeKind::Process, t.pos);
-    n.attr.insert("kind".to_owned(),t.value);
+    n.attr.insert("kind".to_owned(),t.value.into());
     parse_class_stmt(ts, &mut n, false, false, false, true)?;
+    n.end = ts.last_end;
     node.child.push(n);
     Ok(())
 }
@@ -499,7 +544,7 @@ pub fn parse_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: bool) ->
                 match t.kind {
                     TokenKind::ParenLeft => {
                         let mut n = AstNode::new(AstNodeKind::MethodCall, t.pos);
-                        n.attr.insert("name".to_owned(),t.value);
+                        n.attr.insert("name".to_owned(),t.value.into());
                         ts.flush(1); // consume identifier
                         parse_func_call(ts, &mut n, false)?;
                         expect_t!(ts,"type",TokenKind::SemiColon);
@@ -523,6 +568,7 @@ pub fn parse_stmt(ts : &mut TokenStream, node: &mut AstNode, is_block: bool) ->
                 if n.attr["block"]!="" {
                     check_label(ts, &n.attr["block"])?;
                 }
+                n.end = ts.last_end;
                 node.child.push(n);
             }
             _ => return Err(SvError::syntax(t, "statement"))
@@ -540,7 +586,7 @@ pub fn parse_if_else(ts : &mut TokenStream, node: &mut AstNode, is_gen: bool) ->
     let mut t = ts.next_t(false)?;
     let mut node_if = AstNode::new(AstNodeKind::Branch, t.pos);
     if t.kind==TokenKind::KwPriority || t.kind==TokenKind::KwUnique || t.kind==TokenKind::KwUnique0 {
-        node_if.attr.insert("prio".to_owned(),t.value);
+        node_if.attr.insert("prio".to_owned(),t.value.into());
         t = ts.next_t(false)?;
     }
     if t.kind==TokenKind::KwElse {
@@ -672,7 +718,7 @@ pub fn parse_timescale(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),
     let mut node_ts = AstNode::new(AstNodeKind::Timescale, t.pos);
     let allow_timeprec = t.kind==TokenKind::KwTimeunit;
     let mut time = parse_time(ts)?;
-    node_ts.attr.insert(t.value, time);
+    node_ts.attr.insert(t.value.into(), time);
     // Check if followed
     t = ts.next_t(false)?;
     match t.kind {
@@ -692,41 +738,83 @@ pub fn parse_timescale(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),
 }
 
 
-/// Parse primitive
+/// Parse a gate instantiation: type, optional strength and delay, list of instances
 pub fn parse_primitive(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     ts.rewind(0);
     // Capture primitive type: allows to know expected arguments
     let mut t = ts.next_t(false)?;
     let mut node = AstNode::new(AstNodeKind::Primitive, t.pos);
-    // let nb_port;
     match t.kind {
-        TokenKind::KwPrimCmos   => {} //{nb_port = 4;}
-        TokenKind::KwPrimMos    => {} //{nb_port = 3;}
-        TokenKind::KwPrimEn     => {} //{nb_port = 3;}
-        TokenKind::KwPrimIn     => {} //{nb_port = 3;}
-        TokenKind::KwPrimOut    => {} //{nb_port = 3;}
-        TokenKind::KwPrimTran   => {} //{nb_port = 2;}
-        TokenKind::KwPrimTranif => {} //{nb_port = 3;}
+        TokenKind::KwOr         |
+        TokenKind::KwPrimCmos   |
+        TokenKind::KwPrimMos    |
+        TokenKind::KwPrimEn     |
+        TokenKind::KwPrimIn     |
+        TokenKind::KwPrimOut    |
+        TokenKind::KwPrimTran   |
+        TokenKind::KwPrimTranif => {}
+        TokenKind::Keyword if t.value=="pullup" || t.value=="pulldown" => {}
         _ => return Err(SvError::syntax(t, "primitive. Expecting primitive keyword"))
     }
-    node.attr.insert("type".to_owned(), t.value);
+    node.attr.insert("type".to_owned(), t.value.into());
     t = ts.next_t(true)?;
-    // TODO: Hanndle optionnal strength
-    // TODO: Hanndle optionnal delay
-    // Optionnal identifier
-    if t.kind==TokenKind::Ident {
-        node.attr.insert("name".to_owned(), t.value);
-        ts.flush(1); // consume the identifier
+    // Optional strength: (strength0, strength1), or a single one for pullup/pulldown
+    if t.kind==TokenKind::ParenLeft {
+        let nt = ts.next_t(true)?;
+        if nt.kind==TokenKind::KwDrive || nt.kind==TokenKind::KwSupply {
+            ts.flush(1);
+            let mut s = String::new();
+            loop {
+                let nt = expect_t!(ts,"gate strength",TokenKind::KwDrive,TokenKind::KwSupply);
+                s.push_str(&nt.value);
+                let nt = expect_t!(ts,"gate strength",TokenKind::Comma,TokenKind::ParenRight);
+                if nt.kind==TokenKind::ParenRight {break;}
+                s.push(',');
+            }
+            node.attr.insert("drive".to_owned(), s);
+            t = ts.next_t(true)?;
+        } else {
+            ts.rewind(1);
+        }
     }
-    parse_opt_slice(ts, &mut node, true, false)?;
-    expect_t!(ts,"primitive",TokenKind::ParenLeft);
+    // Optional delay: single value or list of min:typ:max values
+    if t.kind==TokenKind::Hash {
+        let nt = ts.next_t(true)?;
+        if nt.kind==TokenKind::ParenLeft {
+            ts.flush(2);
+            let mut node_d = AstNode::new(AstNodeKind::Wait, t.pos);
+            node_d.attr.insert("kind".to_owned(),t.value.into());
+            loop {
+                node_d.child.push(parse_spec_value(ts)?);
+                loop_args_break_cont!(ts,"gate delay",ParenRight);
+            }
+            node.child.push(node_d);
+        } else {
+            node.child.push(parse_delay(ts)?);
+        }
+    }
+    ts.rewind(0);
+    // List of instances with optional name and range
     loop {
-        let mut node_p = AstNode::new(AstNodeKind::Port, ts.get_pos());
-        node_p.child.push(parse_expr(ts,ExprCntxt::ArgList,false)?);
-        node.child.push(node_p);
-        loop_args_break_cont!(ts,"port connection",ParenRight);
+        t = ts.next_t(true)?;
+        let mut node_i = AstNode::new(AstNodeKind::Instance, t.pos);
+        if t.kind==TokenKind::Ident {
+            node_i.attr.insert("name".to_owned(), t.value.into());
+            ts.flush(1); // consume the identifier
+            parse_opt_slice(ts, &mut node_i, true, false)?;
+        } else {
+            ts.rewind(1);
+        }
+        expect_t!(ts,"primitive",TokenKind::ParenLeft);
+        loop {
+            let mut node_p = AstNode::new(AstNodeKind::Port, ts.next_t(true)?.pos);
+            ts.rewind(1);
+            node_p.child.push(parse_expr(ts,ExprCntxt::ArgList,false)?);
+            node_i.child.push(node_p);
+            loop_args_break_cont!(ts,"port connection",ParenRight);
+        }
+        node.child.push(node_i);
+        loop_args_break_cont!(ts,"primitive",SemiColon);
     }
-    // TODO: allow list of instances
-    expect_t!(ts,"primitive",TokenKind::SemiColon);
     Ok(node)
 }
\ No newline at end of file
@@ -14,13 +14,13 @@ pub fn parse_module_hdr(ts : &mut TokenStream, node: &mut AstNode) -> Result<(),
     let mut node_h = AstNode::new(AstNodeKind::Header, t.pos);
 
     if t.kind==TokenKind::KwStatic || t.kind==TokenKind::KwAutomatic {
-        node_h.attr.insert("lifetime".to_owned(),t.value);
+        node_h.attr.insert("lifetime".to_owned(),t.value.into());
         ts.flush(1);
         t = ts.next_t(true)?;
     }
     match t.kind {
         TokenKind::Ident => {
-            node.attr.insert("name".to_owned(),t.value);
+            node.attr.insert("name".to_owned(),t.value.into());
             ts.flush(1);
             t = ts.next_t(true)?;
         },
@@ -2,12 +2,16 @@
 // Copyright (c) 2019, clams@mail.com
 
 use crate::error::{SvError};
-use crate::lex::token::{TokenKind};
+use crate::lex::token::{Token,TokenKind};
 use crate::lex::token_stream::TokenStream;
 use crate::ast::astnode::*;
 use crate::ast::common::*;
 use crate::ast::module_body::{parse_timescale};
 use crate::ast::class::{parse_class,parse_func,parse_task};
+use crate::ast::covergroup::parse_covergroup;
+use crate::ast::constraint::parse_constraint;
+use crate::ast::sva::parse_sva_decl;
+use crate::ast::checker::{parse_checker,parse_let};
 
 
 /// 
@@ -17,13 +21,13 @@ pub fn parse_package(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     // Parse package header
     t = ts.next_t(false)?;
     if t.kind==TokenKind::KwStatic || t.kind==TokenKind::KwAutomatic {
-        node.attr.insert("lifetime".to_owned(),t.value);
+        node.attr.insert("lifetime".to_owned(),t.value.into());
         t = ts.next_t(false)?;
     }
     if t.kind!=TokenKind::Ident {
         return Err(SvError::syntax(t, "package header. Expecting identifier"));
     }
-    node.attr.insert("name".to_owned(),t.value);
+    node.attr.insert("name".to_owned(),t.value.into());
     t = ts.next_t(false)?;
     if t.kind!=TokenKind::SemiColon {
         return Err(SvError::syntax(t, "package header. Expecting ;"));
@@ -32,88 +36,105 @@ pub fn parse_package(ts : &mut TokenStream) -> Result<AstNode, SvError> {
     loop {
         t = ts.next_t(true)?;
         // println!("[parse_module_body] Token = {}", t);
-        match t.kind {
-            // Import statement
-            TokenKind::KwImport | TokenKind::KwExport => parse_import(ts,&mut node)?,
-            TokenKind::KwTimeunit | TokenKind::KwTimeprec => parse_timescale(ts,&mut node)?,
-            // Param declaration
-            TokenKind::KwParam |
-            TokenKind::KwLParam => {
-                ts.rewind(1); // put back the token so that it can be read by the parse param function
-                // potential list of param (the parse function extract only one at a time)
-                loop {
-                    let node_param = parse_param_decl(ts,true)?;
-                    node.child.push(node_param);
-                    let nt = ts.next_t(false)?;
-                    match nt.kind {
-                        TokenKind::Comma => {}, // Comma indicate a list -> continue
-                        TokenKind::SemiColon => {break;}, // Semi colon indicate end of statement, stop the loop
-                        _ => return Err(SvError::syntax(t, "param declaration, expecting , or ;"))
-                    }
+        match parse_package_item(ts,&mut node,t) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(&mut node,e)?,
+        }
+    }
+    node.end = ts.last_end;
+    Ok(node)
+}
+
+// Parse one item of a package body: return true when the end of the package is reached
+fn parse_package_item(ts : &mut TokenStream, node : &mut AstNode, t: Token) -> Result<bool, SvError> {
+    match t.kind {
+        // Import statement
+        TokenKind::KwImport | TokenKind::KwExport => parse_import(ts,node)?,
+        TokenKind::KwTimeunit | TokenKind::KwTimeprec => parse_timescale(ts,node)?,
+        // Param declaration
+        TokenKind::KwParam |
+        TokenKind::KwLParam => {
+            ts.rewind(1); // put back the token so that it can be read by the parse param function
+            // potential list of param (the parse function extract only one at a time)
+            loop {
+                let node_param = parse_param_decl(ts,true)?;
+                node.child.push(node_param);
+                let nt = ts.next_t(false)?;
+                match nt.kind {
+                    TokenKind::Comma => {}, // Comma indicate a list -> continue
+                    TokenKind::SemiColon => {break;}, // Semi colon indicate end of statement, stop the loop
+                    _ => return Err(SvError::syntax(t, "param declaration, expecting , or ;"))
                 }
             }
-            TokenKind::KwClass => node.child.push(parse_class(ts)?),
-            // Nettype (might need another function to parse the signal to include strength/charge, delay, ...)
-            TokenKind::KwConst   |
-            TokenKind::KwNetType |
-            TokenKind::KwSupply  =>  parse_signal_decl_list(ts,&mut node)?,
-            // Basetype
-            TokenKind::KwReg         |
-            TokenKind::TypeIntAtom   |
-            TokenKind::TypeIntVector |
-            TokenKind::TypeReal      |
-            TokenKind::TypeString    |
-            TokenKind::TypeCHandle   |
-            TokenKind::TypeEvent     => parse_signal_decl_list(ts,&mut node)?,
-            TokenKind::KwEnum        => {
-                let mut node_e = parse_enum(ts,false)?;
-                parse_ident_list(ts,&mut node_e)?;
-                node.child.push(node_e);
-            }
-            TokenKind::KwStruct |
-            TokenKind::KwUnion  => {
-                let mut node_s = parse_struct(ts)?;
-                parse_ident_list(ts,&mut node_s)?;
-                node.child.push(node_s);
-            }
-            TokenKind::KwTypedef => parse_typedef(ts,&mut node)?,
-            TokenKind::TypeGenvar => {
-                ts.flush_rd();
-                loop {
-                    let nt = ts.next_t(false)?;
-                    match nt.kind {
-                        TokenKind::Ident => {
-                            let mut n = AstNode::new(AstNodeKind::Declaration, t.pos);
-                            n.attr.insert("type".to_owned(), "genvar".to_owned());
-                            n.attr.insert("name".to_owned(),t.value.clone());
-                            node.child.push(n);
-                            loop_args_break_cont!(ts,"genvar declaration",SemiColon);
-                        }
-                        _ =>  return Err(SvError::syntax(t,"virtual interface. Expecting identifier")),
+        }
+        TokenKind::KwClass => node.child.push(parse_class(ts)?),
+        // Nettype (might need another function to parse the signal to include strength/charge, delay, ...)
+        TokenKind::KwConst   |
+        TokenKind::KwNetType |
+        TokenKind::KwSupply  =>  parse_signal_decl_list(ts,node)?,
+        // Basetype
+        TokenKind::KwReg         |
+        TokenKind::TypeIntAtom   |
+        TokenKind::TypeIntVector |
+        TokenKind::TypeReal      |
+        TokenKind::TypeString    |
+        TokenKind::TypeCHandle   |
+        TokenKind::TypeEvent     => parse_signal_decl_list(ts,node)?,
+        TokenKind::KwEnum        => {
+            let mut node_e = parse_enum(ts,false)?;
+            parse_ident_list(ts,&mut node_e)?;
+            node.child.push(node_e);
+        }
+        TokenKind::KwStruct |
+        TokenKind::KwUnion  => {
+            let mut node_s = parse_struct(ts)?;
+            parse_ident_list(ts,&mut node_s)?;
+            node.child.push(node_s);
+        }
+        TokenKind::KwTypedef => parse_typedef(ts,node)?,
+        TokenKind::TypeGenvar => {
+            ts.flush_rd();
+            loop {
+                let nt = ts.next_t(false)?;
+                match nt.kind {
+                    TokenKind::Ident => {
+                        let mut n = AstNode::new(AstNodeKind::Declaration, t.pos);
+                        n.attr.insert("type".to_owned(), "genvar".to_owned());
+                        n.attr.insert("name".to_owned(),t.value.to_string());
+                        node.child.push(n);
+                        loop_args_break_cont!(ts,"genvar declaration",SemiColon);
                     }
+                    _ =>  return Err(SvError::syntax(t,"virtual interface. Expecting identifier")),
                 }
             }
-            // Identifier -> In a package it can only be a signal declaration
-            TokenKind::Ident        => parse_signal_decl_list(ts,&mut node)?,
-            TokenKind::Macro        => parse_macro(ts,&mut node)?,
-            TokenKind::CompDir      => parse_macro(ts,&mut node)?,
-            TokenKind::KwFunction   => parse_func(ts, &mut node, false, false)?,
-            TokenKind::KwTask       => parse_task(ts, &mut node)?,
-            TokenKind::KwCovergroup => parse_covergroup(ts,&mut node)?,
-            // Extra semi-colon
-            TokenKind::SemiColon => {ts.flush(1);}, // TODO: generate a warning
-            // End module -> parsing of body is done
-            TokenKind::KwEndPackage => {
-                ts.flush(1);
-                check_label(ts, &node.attr["name"])?;
-                break;
-            },
-            // Any un-treated token is an error
-            _ => {
-                // println!("{}", node);
-                return Err(SvError::syntax(t, "package"))
-            }
+        }
+        // Identifier -> In a package it can only be a signal declaration
+        TokenKind::Ident        => parse_signal_decl_list(ts,node)?,
+        TokenKind::Macro        => parse_macro(ts,node)?,
+        TokenKind::CompDir      => parse_macro(ts,node)?,
+        TokenKind::KwFunction   => parse_func(ts, node, false, false)?,
+        TokenKind::KwTask       => parse_task(ts, node, false)?,
+        TokenKind::KwConstraint => parse_constraint(ts, node)?,
+        TokenKind::KwCovergroup => parse_covergroup(ts,node)?,
+        TokenKind::KwProperty   => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="sequence" => parse_sva_decl(ts,node)?,
+        TokenKind::Keyword if t.value=="checker" => parse_checker(ts,node)?,
+        TokenKind::Keyword if t.value=="let"     => parse_let(ts,node)?,
+        // Extra semi-colon
+        TokenKind::SemiColon => {ts.flush(1);}, // TODO: generate a warning
+        // End module -> parsing of body is done
+        TokenKind::KwEndPackage => {
+            ts.flush(1);
+            check_label(ts, &node.attr["name"])?;
+            return Ok(true);
+        },
+        // Any un-treated token is an error
+        _ => {
+            // println!("{}", node);
+            return Err(SvError::syntax(t, "package"))
         }
     }
-    Ok(node)
+    Ok(false)
 }
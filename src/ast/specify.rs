@@ -0,0 +1,284 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+
+/// Parse a specify block
+/// Suppose the specify keyword was peeked
+pub fn parse_specify(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Specify, t.pos);
+    loop {
+        match parse_specify_item(ts,&mut n) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(&mut n,e)?,
+        }
+    }
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse one item of a specify block: return true when endspecify is reached
+fn parse_specify_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<bool, SvError> {
+    let t = ts.next_t(true)?;
+    match t.kind {
+        TokenKind::KwSpecParam => parse_specparam(ts,node)?,
+        // Simple or edge-sensitive path
+        TokenKind::ParenLeft => {
+            let mut n = AstNode::new(AstNodeKind::SpecPath, t.pos);
+            parse_spec_path(ts,&mut n)?;
+            node.child.push(n);
+        }
+        // State-dependent path
+        TokenKind::KwIf => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::SpecPath, t.pos);
+            expect_t!(ts,"state-dependent path",TokenKind::ParenLeft);
+            n.child.push(parse_expr(ts,ExprCntxt::Arg,false)?);
+            expect_t!(ts,"state-dependent path",TokenKind::ParenRight);
+            parse_spec_path(ts,&mut n)?;
+            node.child.push(n);
+        }
+        TokenKind::Keyword if t.value == "ifnone" => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::SpecPath, t.pos);
+            n.attr.insert("ifnone".to_owned(), "".to_owned());
+            parse_spec_path(ts,&mut n)?;
+            node.child.push(n);
+        }
+        // Pulse style / cancelled schedule display: list of path outputs
+        TokenKind::Keyword if t.value.starts_with("pulsestyle_") || t.value.ends_with("showcancelled") => {
+            ts.flush(1);
+            let mut n = AstNode::new(AstNodeKind::SpecPath, t.pos);
+            n.attr.insert("kind".to_owned(), t.value.into());
+            loop {
+                ts.next_t(true)?; // Peek identifier to get its position
+                let mut nc = parse_ident_hier(ts)?;
+                nc.attr.insert("path".to_owned(), "dst".to_owned());
+                n.child.push(nc);
+                loop_args_break_cont!(ts,"path output list",SemiColon);
+            }
+            node.child.push(n);
+        }
+        TokenKind::SystemTask => parse_timing_check(ts,node)?,
+        TokenKind::Keyword if t.value == "endspecify" => {
+            ts.flush(1);
+            return Ok(true);
+        }
+        TokenKind::Macro |
+        TokenKind::CompDir => parse_macro(ts,node)?,
+        TokenKind::SemiColon => ts.flush(1),
+        _ => return Err(SvError::syntax(t, "specify block"))
+    }
+    Ok(false)
+}
+
+// Parse a specparam declaration: values can be min:typ:max or (reject,error) for PATHPULSE$
+fn parse_specparam(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Param, t.pos);
+    n.attr.insert("kind".to_owned(), format!("{:?}",t.kind));
+    parse_opt_slice(ts,&mut n,true,false)?;
+    loop {
+        let nt = expect_t!(ts,"specparam",TokenKind::Ident);
+        let mut ni = AstNode::new(AstNodeKind::Identifier, nt.pos);
+        ni.attr.insert("name".to_owned(), nt.value.into());
+        expect_t!(ts,"specparam",TokenKind::OpEq);
+        let nt = ts.next_t(true)?;
+        if nt.kind == TokenKind::ParenLeft {
+            ts.flush(1);
+            let mut nv = AstNode::new(AstNodeKind::Expr, nt.pos);
+            loop {
+                nv.child.push(parse_spec_value(ts)?);
+                loop_args_break_cont!(ts,"specparam",ParenRight);
+            }
+            ni.child.push(nv);
+        } else {
+            ts.rewind(1);
+            ni.child.push(parse_spec_value(ts)?);
+        }
+        n.child.push(ni);
+        loop_args_break_cont!(ts,"specparam",SemiColon);
+    }
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse a path declaration: ([edge] src_list [polarity]=>|*> dst_list) = delays;
+// Source/destination terminals are identifiers with the attribute path set to src/dst
+// Suppose the open parenthesis was peeked
+fn parse_spec_path(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    expect_t!(ts,"path declaration",TokenKind::ParenLeft);
+    let mut t = ts.next_t(true)?;
+    if t.kind == TokenKind::KwEdge {
+        node.attr.insert("edge".to_owned(), t.value.into());
+        ts.flush(1);
+    } else {
+        ts.rewind(1);
+    }
+    // Source list
+    loop {
+        ts.next_t(true)?; // Peek identifier to get its position
+        let mut nc = parse_ident_hier(ts)?;
+        nc.attr.insert("path".to_owned(), "src".to_owned());
+        node.child.push(nc);
+        t = ts.next_t(false)?;
+        if t.kind != TokenKind::Comma {break;}
+    }
+    // Optional polarity: +=> is seen as += followed by >
+    match t.kind {
+        TokenKind::OpPlus | TokenKind::OpMinus => {
+            node.attr.insert("polarity".to_owned(), t.value.into());
+            t = ts.next_t(false)?;
+        }
+        TokenKind::OpCompAss if t.value == "+=" || t.value == "-=" => {
+            node.attr.insert("polarity".to_owned(), t.value[..1].to_owned());
+            expect_t!(ts,"path declaration",TokenKind::OpGT);
+            t.kind = TokenKind::OpFatArrL;
+            t.value = "=>".into();
+        }
+        _ => {}
+    }
+    match t.kind {
+        TokenKind::OpFatArrL | TokenKind::OpStarLT => {node.attr.insert("kind".to_owned(), t.value.into());}
+        _ => return Err(SvError::syntax(t, "path declaration. Expecting => or *>"))
+    }
+    // Destination list, with data source expression for edge-sensitive path
+    t = ts.next_t(true)?;
+    let is_edge = t.kind == TokenKind::ParenLeft;
+    if is_edge {
+        ts.flush(1);
+    } else {
+        ts.rewind(1);
+    }
+    loop {
+        ts.next_t(true)?; // Peek identifier to get its position
+        let mut nc = parse_ident_hier(ts)?;
+        nc.attr.insert("path".to_owned(), "dst".to_owned());
+        node.child.push(nc);
+        t = ts.next_t(false)?;
+        if t.kind != TokenKind::Comma {break;}
+    }
+    if is_edge {
+        if t.kind == TokenKind::OpPlus || t.kind == TokenKind::OpMinus {
+            t = expect_t!(ts,"edge-sensitive path",TokenKind::Colon);
+        }
+        if t.kind != TokenKind::Colon && t.kind != TokenKind::OpRange {
+            return Err(SvError::syntax(t, "edge-sensitive path. Expecting : +: or -:"));
+        }
+        node.child.push(parse_expr(ts,ExprCntxt::Specify,false)?);
+        expect_t!(ts,"edge-sensitive path",TokenKind::ParenRight);
+        t = ts.next_t(false)?;
+    }
+    if t.kind != TokenKind::ParenRight {
+        return Err(SvError::syntax(t, "path declaration. Expecting )"));
+    }
+    // Delay values
+    expect_t!(ts,"path declaration",TokenKind::OpEq);
+    t = ts.next_t(true)?;
+    if t.kind == TokenKind::ParenLeft {
+        ts.flush(1);
+        loop {
+            node.child.push(parse_spec_value(ts)?);
+            loop_args_break_cont!(ts,"path delay",ParenRight);
+        }
+    } else {
+        ts.rewind(1);
+        node.child.push(parse_spec_value(ts)?);
+    }
+    expect_t!(ts,"path declaration",TokenKind::SemiColon);
+    Ok(())
+}
+
+// Parse a timing check: $setup, $hold, $setuphold, $width, $period, ...
+fn parse_timing_check(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::TimingCheck, t.pos);
+    match t.value.as_ref() {
+        "$setup" | "$hold" | "$setuphold" | "$recovery" | "$removal" | "$recrem" |
+        "$skew" | "$timeskew" | "$fullskew" | "$period" | "$width" | "$nochange" => {}
+        _ => return Err(SvError::syntax(t, "specify block. Expecting timing check"))
+    }
+    // Delayed reference/data signals of $setuphold and $recrem (last two arguments)
+    let has_delayed = t.value == "$setuphold" || t.value == "$recrem";
+    n.attr.insert("name".to_owned(), t.value.into());
+    expect_t!(ts,"timing check",TokenKind::ParenLeft);
+    let mut idx = 0;
+    loop {
+        let nt = ts.next_t(true)?;
+        ts.rewind(1);
+        // Empty argument
+        if nt.kind != TokenKind::Comma && nt.kind != TokenKind::ParenRight {
+            let mut na = parse_timing_arg(ts)?;
+            if has_delayed && idx >= 7 {
+                na.attr.insert("delayed".to_owned(), "".to_owned());
+            }
+            n.child.push(na);
+        }
+        idx += 1;
+        loop_args_break_cont!(ts,"timing check",ParenRight);
+    }
+    expect_t!(ts,"timing check",TokenKind::SemiColon);
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse a timing check argument: an event ([edge] signal [&&& condition]) or a limit value
+fn parse_timing_arg(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let t = ts.next_t(true)?;
+    let mut n = AstNode::new(AstNodeKind::Event, t.pos);
+    if t.kind == TokenKind::KwEdge {
+        n.attr.insert("edge".to_owned(), t.value.to_string());
+        ts.flush(1);
+        // Optional edge descriptors: edge [01, 10]
+        let nt = ts.next_t(true)?;
+        if t.value == "edge" && nt.kind == TokenKind::SquareLeft {
+            ts.flush(1);
+            ts.skip_until(TokenKind::SquareRight)?;
+        } else {
+            ts.rewind(1);
+        }
+    } else {
+        ts.rewind(1);
+    }
+    let ne = parse_spec_value(ts)?;
+    let nt = ts.next_t(true)?;
+    if nt.kind == TokenKind::OpTimingAnd {
+        ts.flush(1);
+        n.child.push(ne);
+        n.child.push(parse_expr(ts,ExprCntxt::Specify,false)?);
+    } else {
+        ts.rewind(1);
+        if !n.attr.contains_key("edge") {
+            return Ok(ne);
+        }
+        n.child.push(ne);
+    }
+    Ok(n)
+}
+
+/// Parse a value which can be a min:typ:max expression
+pub fn parse_spec_value(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let ne = parse_expr(ts,ExprCntxt::Specify,false)?;
+    let mut t = ts.next_t(true)?;
+    if t.kind != TokenKind::Colon {
+        ts.rewind(1);
+        return Ok(ne);
+    }
+    let mut n = AstNode::new(AstNodeKind::Expr, ne.pos);
+    n.attr.insert("kind".to_owned(), "mintypmax".to_owned());
+    n.child.push(ne);
+    while t.kind == TokenKind::Colon {
+        ts.flush(1);
+        n.child.push(parse_expr(ts,ExprCntxt::Specify,false)?);
+        t = ts.next_t(true)?;
+    }
+    ts.rewind(1);
+    Ok(n)
+}
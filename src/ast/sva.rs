@@ -0,0 +1,401 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{Token,TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+use crate::ast::module_body::parse_sensitivity;
+
+/// Parse a property or sequence declaration
+/// Suppose the property/sequence keyword was peeked
+pub fn parse_sva_decl(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let is_seq = t.kind != TokenKind::KwProperty;
+    let mut n = AstNode::new(if is_seq {AstNodeKind::SvaSequence} else {AstNodeKind::SvaProperty}, t.pos);
+    let t = expect_t!(ts,"SVA declaration",TokenKind::Ident);
+    n.attr.insert("name".to_owned(),t.value.into());
+    // Optional formal arguments
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::ParenLeft {
+        ts.flush(1);
+        n.child.push(parse_sva_ports(ts)?);
+    } else {
+        ts.rewind(1);
+    }
+    expect_t!(ts,"SVA declaration",TokenKind::SemiColon);
+    // Local variables
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            TokenKind::KwVar         |
+            TokenKind::TypeIntAtom   |
+            TokenKind::TypeIntVector |
+            TokenKind::TypeReal      |
+            TokenKind::TypeString    => parse_signal_decl_list(ts,&mut n)?,
+            // User type: identifier followed by the variable name
+            TokenKind::Ident => {
+                let nt = ts.next_t(true)?;
+                if nt.kind != TokenKind::Ident {
+                    ts.rewind(0);
+                    break;
+                }
+                parse_signal_decl_list(ts,&mut n)?;
+            }
+            _ => {ts.rewind(0); break;}
+        }
+    }
+    parse_sva_spec(ts,&mut n)?;
+    // Optional semi-colon before the end keyword
+    let mut t = ts.next_t(false)?;
+    if t.kind == TokenKind::SemiColon {
+        t = ts.next_t(false)?;
+    }
+    match t.kind {
+        TokenKind::KwEndProperty if !is_seq => {}
+        TokenKind::Keyword if is_seq && t.value=="endsequence" => {}
+        _ => return Err(SvError::syntax(t,if is_seq {"sequence. Expecting endsequence"} else {"property. Expecting endproperty"}))
+    }
+    check_label(ts, &n.attr["name"])?;
+    n.end = ts.last_end;
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse the formal arguments of a property/sequence
+// Suppose the open parenthesis was consumed
+pub fn parse_sva_ports(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let mut node_p = AstNode::new(AstNodeKind::Ports, ts.get_pos());
+    let t = ts.next_t(true)?;
+    if t.kind == TokenKind::ParenRight {
+        ts.flush(1);
+        return Ok(node_p);
+    }
+    ts.rewind(1);
+    loop {
+        let mut t = ts.next_t(true)?;
+        let is_local = t.kind == TokenKind::KwLocal;
+        if is_local {
+            ts.flush(1);
+            t = ts.next_t(true)?;
+        }
+        // Argument types specific to assertions
+        let is_sva_type = match t.kind {
+            TokenKind::KwProperty => true,
+            TokenKind::Keyword => t.value=="untyped" || t.value=="sequence",
+            _ => false
+        };
+        let mut n = if is_sva_type {
+            let mut n = AstNode::new(AstNodeKind::Port, t.pos);
+            n.attr.insert("type".to_owned(), t.value.into());
+            ts.flush(1);
+            let mut ni = AstNode::new(AstNodeKind::Identifier, ts.get_pos());
+            parse_var_decl_name(ts,&mut ni,ExprCntxt::ArgList,false,false)?;
+            ni.end = ts.last_end;
+            n.child.push(ni);
+            n
+        } else {
+            ts.rewind(1);
+            parse_port_decl(ts,false,ExprCntxt::ArgList)?
+        };
+        if is_local {
+            n.attr.insert("local".to_owned(), "".to_owned());
+        }
+        node_p.child.push(n);
+        loop_args_break_cont!(ts,"SVA formal arguments",ParenRight);
+    }
+    Ok(node_p)
+}
+
+/// Parse the specification of a property/sequence: optional clocking event and disable condition
+/// followed by the property/sequence expression
+pub fn parse_sva_spec(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let mut t = ts.next_t(true)?;
+    if t.kind == TokenKind::At {
+        ts.flush(1);
+        node.child.push(parse_sensitivity(ts,false)?);
+        t = ts.next_t(true)?;
+    }
+    if t.kind == TokenKind::KwDisable {
+        ts.flush(1);
+        expect_t!(ts,"disable iff",TokenKind::KwIff);
+        expect_t!(ts,"disable iff",TokenKind::ParenLeft);
+        let mut ne = parse_expr(ts,ExprCntxt::ExprGroup,false)?;
+        ne.attr.insert("kind".to_owned(),"disable iff".to_owned());
+        node.child.push(ne);
+        expect_t!(ts,"disable iff",TokenKind::ParenRight);
+    } else {
+        ts.rewind(1);
+    }
+    node.child.push(parse_sva_expr(ts)?);
+    Ok(())
+}
+
+/// Parse the property/sequence of a concurrent assertion: property ( spec )
+/// Suppose the property/sequence keyword was consumed
+pub fn parse_sva_assert(ts : &mut TokenStream, node: &mut AstNode, t: Token) -> Result<(), SvError> {
+    node.attr.insert("concurrent".to_owned(),t.value.into());
+    expect_t!(ts,"concurrent assertion",TokenKind::ParenLeft);
+    parse_sva_spec(ts,node)?;
+    expect_t!(ts,"concurrent assertion",TokenKind::ParenRight);
+    Ok(())
+}
+
+// Binary sequence/property operators given as keyword
+fn is_sva_binop(t: &Token) -> bool {
+    match t.kind {
+        TokenKind::OpSeqRel | TokenKind::OpEquiv | TokenKind::KwOr | TokenKind::KwIff => true,
+        TokenKind::KwPrimIn => t.value == "and",
+        TokenKind::Keyword  => matches!(t.value.as_ref(),
+            "intersect" | "within" | "throughout" | "implies" |
+            "until" | "s_until" | "until_with" | "s_until_with"),
+        _ => false
+    }
+}
+
+// Unary property operators, optionally followed by a range
+fn is_sva_unop(t: &Token) -> bool {
+    match t.kind {
+        TokenKind::KwAlways => true,
+        // Edge of an event expression (event argument of a sequence, property or checker)
+        TokenKind::KwEdge   => true,
+        TokenKind::KwPrimIn => t.value == "not",
+        TokenKind::Keyword  => matches!(t.value.as_ref(),
+            "s_always" | "eventually" | "s_eventually" | "nexttime" | "s_nexttime"),
+        _ => false
+    }
+}
+
+/// Parse a sequence or property expression
+/// The expression is kept flat: operands, operators, delays and repetitions
+pub fn parse_sva_expr(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let mut node = AstNode::new(AstNodeKind::SvaExpr, ts.get_pos());
+    let mut need_operand = true;
+    loop {
+        let t = ts.next_t(true)?;
+        match t.kind {
+            // Cycle delay: ##n, ##[m:n], ##[*], ##[+]
+            TokenKind::Hash2 => {
+                ts.flush(1);
+                node.child.push(parse_sva_delay(ts,t)?);
+                need_operand = true;
+            }
+            // Clocking event
+            TokenKind::At if need_operand => {
+                ts.flush(1);
+                node.child.push(parse_sensitivity(ts,false)?);
+            }
+            // Repetition: [*n], [=n], [->n], [+]
+            TokenKind::SquareLeft if !need_operand => {
+                ts.flush(1);
+                node.child.push(parse_sva_repeat(ts)?);
+            }
+            _ if !need_operand && is_sva_binop(&t) => {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+                n.end = t.end;
+                n.attr.insert("kind".to_owned(),t.value.into());
+                node.child.push(n);
+                need_operand = true;
+            }
+            _ if need_operand && is_sva_unop(&t) => {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+                n.end = t.end;
+                n.attr.insert("kind".to_owned(),t.value.into());
+                // Optional range
+                let nt = ts.next_t(true)?;
+                if nt.kind == TokenKind::SquareLeft {
+                    ts.flush(1);
+                    n.child.push(parse_sva_range(ts)?);
+                } else {
+                    ts.rewind(1);
+                }
+                node.child.push(n);
+            }
+            // Operators with a sequence argument: strong(seq), weak(seq), first_match(seq, match_item)
+            // and abort properties: accept_on(expr) prop, ...
+            TokenKind::Keyword if need_operand => {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+                n.end = t.end;
+                n.attr.insert("kind".to_owned(),t.value.to_string());
+                expect_t!(ts,"sequence operator",TokenKind::ParenLeft);
+                match t.value.as_ref() {
+                    "strong" | "weak" | "first_match" => {
+                        n.child.push(parse_sva_expr(ts)?);
+                        parse_sva_match_items(ts,&mut n)?;
+                        need_operand = false;
+                    }
+                    "accept_on" | "reject_on" | "sync_accept_on" | "sync_reject_on" => {
+                        n.child.push(parse_expr(ts,ExprCntxt::ExprGroup,false)?);
+                    }
+                    _ => return Err(SvError::syntax(t,"sequence/property expression"))
+                }
+                expect_t!(ts,"sequence operator",TokenKind::ParenRight);
+                node.child.push(n);
+            }
+            // Conditional property: if (expr) property [else property]
+            TokenKind::KwIf if need_operand => {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Branch, t.pos);
+                n.attr.insert("kind".to_owned(),"if".to_owned());
+                expect_t!(ts,"property if",TokenKind::ParenLeft);
+                n.child.push(parse_expr(ts,ExprCntxt::ExprGroup,false)?);
+                expect_t!(ts,"property if",TokenKind::ParenRight);
+                n.child.push(parse_sva_expr(ts)?);
+                let nt = ts.next_t(true)?;
+                if nt.kind == TokenKind::KwElse {
+                    ts.flush(1);
+                    let mut ne = AstNode::new(AstNodeKind::Branch, nt.pos);
+                    ne.attr.insert("kind".to_owned(),"else".to_owned());
+                    ne.child.push(parse_sva_expr(ts)?);
+                    n.child.push(ne);
+                } else {
+                    ts.rewind(1);
+                }
+                node.child.push(n);
+                need_operand = false;
+            }
+            // Sequence/property between parenthesis, with optional match items
+            TokenKind::ParenLeft if need_operand => {
+                ts.flush(1);
+                let mut n = parse_sva_expr(ts)?;
+                parse_sva_match_items(ts,&mut n)?;
+                expect_t!(ts,"sequence expression",TokenKind::ParenRight);
+                node.child.push(n);
+                need_operand = false;
+            }
+            // Boolean expression or sequence/property instance
+            _ if need_operand => {
+                ts.rewind(1);
+                node.child.push(parse_expr(ts,ExprCntxt::Sva,false)?);
+                need_operand = false;
+            }
+            // Boolean operator after a parenthesized expression
+            TokenKind::OpStar     |
+            TokenKind::OpDiv      |
+            TokenKind::OpMod      |
+            TokenKind::OpPlus     |
+            TokenKind::OpMinus    |
+            TokenKind::OpAnd      |
+            TokenKind::OpOr       |
+            TokenKind::OpXor      |
+            TokenKind::OpLogicAnd |
+            TokenKind::OpLogicOr  |
+            TokenKind::OpLT       |
+            TokenKind::OpLTE      |
+            TokenKind::OpGT       |
+            TokenKind::OpGTE      |
+            TokenKind::OpEq2      |
+            TokenKind::OpEq3      |
+            TokenKind::OpDiff     |
+            TokenKind::OpDiff2    => {
+                ts.flush(1);
+                let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+                n.attr.insert("kind".to_owned(),t.value.into());
+                node.child.push(n);
+                need_operand = true;
+            }
+            // End of expression: let the caller check the token
+            _ => {ts.rewind(1); break;}
+        }
+    }
+    Ok(node)
+}
+
+// Parse a cycle delay
+// Suppose the ## was consumed
+fn parse_sva_delay(ts : &mut TokenStream, t: Token) -> Result<AstNode, SvError> {
+    let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+    n.attr.insert("kind".to_owned(),t.value.into());
+    let t = ts.next_t(true)?;
+    match t.kind {
+        TokenKind::Integer => {
+            ts.flush(1);
+            let mut nc = AstNode::new(AstNodeKind::Value, t.pos);
+            nc.end = t.end;
+            nc.attr.insert("value".to_owned(), t.value.into());
+            n.child.push(nc);
+        }
+        TokenKind::Ident => n.child.push(parse_ident_hier(ts)?),
+        TokenKind::ParenLeft => {
+            ts.flush(1);
+            n.child.push(parse_expr(ts,ExprCntxt::ExprGroup,false)?);
+            expect_t!(ts,"cycle delay",TokenKind::ParenRight);
+        }
+        TokenKind::SquareLeft => {
+            ts.flush(1);
+            n.child.push(parse_sva_range(ts)?);
+        }
+        _ => return Err(SvError::syntax(t,"cycle delay. Expecting integer, identifier, ( or ["))
+    }
+    Ok(n)
+}
+
+// Parse a repetition: [*n], [*m:n], [*], [+], [=n], [->n]
+// Suppose the open square bracket was consumed
+fn parse_sva_repeat(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let t = expect_t!(ts,"repetition",TokenKind::OpStar,TokenKind::OpPlus,TokenKind::OpEq,TokenKind::OpImpl);
+    let mut n = AstNode::new(AstNodeKind::Operation, t.pos);
+    n.attr.insert("kind".to_owned(),format!("[{}",t.value));
+    let nt = ts.next_t(true)?;
+    if nt.kind == TokenKind::SquareRight && (t.kind == TokenKind::OpStar || t.kind == TokenKind::OpPlus) {
+        ts.flush(1);
+        return Ok(n);
+    }
+    ts.rewind(1);
+    n.child.push(parse_sva_range(ts)?);
+    Ok(n)
+}
+
+// Parse a range: [n], [m:n], [m:$], [*] or [+]
+// Suppose the open square bracket was consumed
+fn parse_sva_range(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let t = ts.next_t(true)?;
+    let mut n = AstNode::new(AstNodeKind::Slice, t.pos);
+    // Shortcut for ##[*] and ##[+]
+    if t.kind == TokenKind::OpStar || t.kind == TokenKind::OpPlus {
+        ts.flush(1);
+        n.attr.insert("range".to_owned(),t.value.into());
+        expect_t!(ts,"range",TokenKind::SquareRight);
+        return Ok(n);
+    }
+    ts.rewind(1);
+    n.child.push(parse_range_bound(ts,ExprCntxt::BracketMsb)?);
+    let t = expect_t!(ts,"range",TokenKind::Colon,TokenKind::SquareRight);
+    if t.kind == TokenKind::Colon {
+        n.child.push(parse_range_bound(ts,ExprCntxt::BracketLsb)?);
+        expect_t!(ts,"range",TokenKind::SquareRight);
+    }
+    Ok(n)
+}
+
+// Parse optional sequence match items: , v = expr, f(x), ...
+fn parse_sva_match_items(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        let t = ts.next_t(true)?;
+        if t.kind != TokenKind::Comma {
+            ts.rewind(1);
+            break;
+        }
+        ts.flush(1);
+        let t = ts.next_t(true)?;
+        let nt = ts.next_t(true)?;
+        // Assignment of a local variable
+        if t.kind == TokenKind::Ident && (nt.kind == TokenKind::OpEq || nt.kind == TokenKind::OpCompAss) {
+            ts.rewind(0);
+            let mut n = AstNode::new(AstNodeKind::Assign, t.pos);
+            n.child.push(parse_ident_hier(ts)?);
+            let t = ts.next_t(false)?;
+            n.attr.insert("kind".to_owned(),t.value.into());
+            n.child.push(parse_expr(ts,ExprCntxt::ArgList,false)?);
+            node.child.push(n);
+        } else {
+            ts.rewind(0);
+            node.child.push(parse_expr(ts,ExprCntxt::ArgList,false)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use crate::error::{SvError};
+use crate::lex::token::{TokenKind};
+use crate::lex::token_stream::TokenStream;
+use crate::ast::astnode::*;
+use crate::ast::common::*;
+use crate::ast::module_hdr::parse_module_hdr;
+use crate::ast::module_body::parse_initial;
+
+/// Parse a user-defined primitive: header, port declarations, initial statement and table
+/// Suppose the primitive keyword was peeked
+pub fn parse_udp(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::Udp, t.pos);
+    parse_module_hdr(ts,&mut n)?;
+    let mut node_b = AstNode::new(AstNodeKind::Body, t.pos);
+    let res = parse_udp_body(ts,&mut node_b)
+        .and_then(|_| check_label(ts, &n.attr["name"]));
+    n.end = ts.last_end;
+    node_b.end = ts.last_end;
+    // Keep the primitive even if incomplete
+    n.child.push(node_b);
+    node.child.push(n);
+    res
+}
+
+fn parse_udp_body(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    loop {
+        match parse_udp_item(ts,node) {
+            Ok(true) => break,
+            Ok(false) => {}
+            // Syntax error: skip to the next item and continue
+            Err(e) => ts.resync(node,e)?,
+        }
+    }
+    Ok(())
+}
+
+// Parse one item of a primitive body: return true when endprimitive is reached
+fn parse_udp_item(ts : &mut TokenStream, node: &mut AstNode) -> Result<bool, SvError> {
+    let t = ts.next_t(true)?;
+    match t.kind {
+        TokenKind::KwInput | TokenKind::KwOutput => {
+            ts.rewind(1);
+            node.child.push(parse_port_decl(ts,false,ExprCntxt::StmtList)?);
+        }
+        TokenKind::KwReg     => parse_signal_decl_list(ts,node)?,
+        TokenKind::KwInitial => parse_initial(ts,node)?,
+        TokenKind::Keyword if t.value == "table" => parse_udp_table(ts,node)?,
+        TokenKind::Keyword if t.value == "endprimitive" => {
+            ts.flush(1);
+            return Ok(true);
+        }
+        TokenKind::Macro |
+        TokenKind::CompDir => parse_macro(ts,node)?,
+        TokenKind::SemiColon => ts.flush(1),
+        _ => return Err(SvError::syntax(t, "primitive"))
+    }
+    Ok(false)
+}
+
+// Parse the table of a primitive: each row is an entry with the attributes inputs, state (sequential only) and output
+fn parse_udp_table(ts : &mut TokenStream, node: &mut AstNode) -> Result<(), SvError> {
+    let t = ts.next_t(false)?;
+    let mut n = AstNode::new(AstNodeKind::UdpTable, t.pos);
+    loop {
+        let t = ts.next_t(true)?;
+        if t.kind == TokenKind::Keyword && t.value == "endtable" {
+            ts.flush(1);
+            break;
+        }
+        ts.rewind(1);
+        match parse_udp_entry(ts) {
+            Ok(ne) => n.child.push(ne),
+            // Syntax error: skip to the next entry and continue
+            Err(e) => ts.resync(&mut n,e)?,
+        }
+    }
+    node.child.push(n);
+    Ok(())
+}
+
+// Parse one entry of a table: inputs : [state :] output ;
+// Symbols are kept as written (without spaces) since the lexer can merge consecutive symbols (e.g. 01, 1x or bx)
+fn parse_udp_entry(ts : &mut TokenStream) -> Result<AstNode, SvError> {
+    let mut t = ts.next_t(true)?;
+    let mut ne = AstNode::new(AstNodeKind::UdpEntry, t.pos);
+    let mut fields = vec![String::new()];
+    let mut prev = t.clone();
+    loop {
+        match t.kind {
+            TokenKind::SemiColon => break,
+            // Missing semi-colon: report it on the last symbol and keep endtable to close the table
+            TokenKind::Keyword if t.value == "endtable" || t.value == "endprimitive" => {
+                ts.rewind(1);
+                return Err(SvError::syntax(prev, "table entry. Expecting ;"));
+            }
+            TokenKind::Colon => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push_str(&t.value)
+        }
+        ne.end = t.end;
+        ts.flush(1);
+        prev = t;
+        t = ts.next_t(true)?;
+    }
+    if fields.len() < 2 || fields.len() > 3 || fields.iter().any(|x| x.is_empty()) {
+        return Err(SvError::syntax(t, "table entry. Expecting inputs : [state :] output"));
+    }
+    ts.flush(1);
+    ne.attr.insert("output".to_owned(), fields.pop().unwrap());
+    if fields.len() == 2 {
+        ne.attr.insert("state".to_owned(), fields.pop().unwrap());
+    }
+    ne.attr.insert("inputs".to_owned(), fields.pop().unwrap());
+    Ok(ne)
+}
@@ -7,1500 +7,1530 @@ pub fn get_uvm_macro() -> Box<Ast> {
     let mut ast = Ast::new(std::path::PathBuf::from("uvm_macro.svh"));
     let mut body = Vec::with_capacity(1024);
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_enabled".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "VERBOSITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_INFO".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_info".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "MSG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "VERBOSITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__FILE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__LINE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_enabled".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "VERBOSITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_INFO".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_info".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "MSG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "VERBOSITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__FILE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__LINE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_info".to_string(), Some(
         MacroDef {
             ports: [("ID".to_string(),[].to_vec()), ("MSG".to_string(),[].to_vec()), ("VERBOSITY".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_enabled".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_WARNING".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_warning".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "MSG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__FILE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__LINE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_enabled".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_WARNING".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_warning".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "MSG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__FILE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__LINE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_warning".to_string(), Some(
         MacroDef {
             ports: [("ID".to_string(),[].to_vec()), ("MSG".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_enabled".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_ERROR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_error".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "MSG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__FILE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__LINE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_enabled".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_ERROR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_error".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "MSG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__FILE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__LINE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_error".to_string(), Some(
         MacroDef {
             ports: [("ID".to_string(),[].to_vec()), ("MSG".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_enabled".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_FATAL".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_fatal".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "MSG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__FILE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__LINE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_enabled".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_FATAL".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_fatal".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "MSG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__FILE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__LINE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_fatal".to_string(), Some(
         MacroDef {
             ports: [("ID".to_string(),[].to_vec()), ("MSG".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: Ident,value: "SEQUENCER".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "p_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwVirtual,value: "virtual".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeVoid,value: "void".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_set_p_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwSuper,value: "super".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_set_p_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "p_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_fatal".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "DCLPSQ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$sformatf".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "%m %s Error casting p_sequencer, please verify that this sequence/sequence item is intended to execute on this type of sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_full_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: Ident,value: "SEQUENCER".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "p_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwVirtual,value: "virtual".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeVoid,value: "void".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_set_p_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwSuper,value: "super".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_set_p_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "p_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_fatal".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "DCLPSQ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$sformatf".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "%m %s Error casting p_sequencer, please verify that this sequence/sequence item is intended to execute on this type of sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_full_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_declare_p_sequencer".to_string(), Some(
         MacroDef {
             ports: [("SEQUENCER".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_utils".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "create".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeString,value: "string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CompDir,value: "`ifdef".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_OBJECT_DO_NOT_NEED_CONSTRUCTOR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNew,value: "new".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpDiff,value: "!=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "set_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CompDir,value: "`else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq2,value: "==".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNew,value: "new".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNew,value: "new".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CompDir,value: "`endif".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "create".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeString,value: "string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CompDir,value: "`ifdef".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_OBJECT_DO_NOT_NEED_CONSTRUCTOR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNew,value: "new".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpDiff,value: "!=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "set_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CompDir,value: "`else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq2,value: "==".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNew,value: "new".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNew,value: "new".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CompDir,value: "`endif".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_object_create_func".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwConst,value: "const".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwStatic,value: "static".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeString,value: "string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwVirtual,value: "virtual".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeString,value: "string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_type_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwConst,value: "const".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwStatic,value: "static".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeString,value: "string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwVirtual,value: "virtual".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeString,value: "string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_type_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_get_type_name_func".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwTypedef,value: "typedef".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_registry".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Hash,value: "#".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "S".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwStatic,value: "static".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwVirtual,value: "virtual".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_wrapper".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_object_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwTypedef,value: "typedef".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_registry".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Hash,value: "#".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "S".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwStatic,value: "static".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwVirtual,value: "virtual".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_wrapper".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_object_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_object_registry_internal".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec()), ("S".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_internal".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_internal".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_utils_begin".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_create_on".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_create_on".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_create".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_wrapper".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "w_".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "w_".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "create_item".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "w_".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_wrapper".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "w_".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "w_".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "create_item".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "w_".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_create_on".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec()), ("SEQR".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_send_pri".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpMinus,value: "-".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_send_pri".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpMinus,value: "-".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_send".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_sequence_base".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "start_item".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "finish_item".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "start".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwThis,value: "this".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "0".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_sequence_base".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "start_item".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "finish_item".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "start".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwThis,value: "this".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "0".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_send_pri".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec()), ("PRIORITY".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
-    body.push(Token {kind: MacroCall,value: "`uvm_rand_send_pri_with".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpMinus,value: "-".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "CONSTRAINTS".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_rand_send_pri_with".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpMinus,value: "-".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "CONSTRAINTS".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_rand_send_with".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec()), ("CONSTRAINTS".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_rand_send_pri_with".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpMinus,value: "-".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyLeft,value: "{".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyRight,value: "}".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_rand_send_pri_with".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpMinus,value: "-".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyLeft,value: "{".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyRight,value: "}".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_rand_send".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_sequence_base".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "start_item".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "set_item_context".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwThis,value: "this".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq2,value: "==".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNull,value: "null".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpLogicOr,value: "||".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "do_not_randomize".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpLogicAnd,value: "&&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "randomize".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwWith,value: "with".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "CONSTRAINTS".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_warning".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "RNDFLD".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "Randomization failed in uvm_rand_send_with action".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "finish_item".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "start".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__seq".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_sequencer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwThis,value: "this".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "PRIORITY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "0".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_sequence_base".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "start_item".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "set_item_context".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwThis,value: "this".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq2,value: "==".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNull,value: "null".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpLogicOr,value: "||".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "do_not_randomize".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpLogicAnd,value: "&&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "randomize".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwWith,value: "with".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "CONSTRAINTS".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_warning".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "RNDFLD".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "Randomization failed in uvm_rand_send_with action".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "finish_item".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "SEQ_OR_ITEM".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "start".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__seq".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_sequencer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwThis,value: "this".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "PRIORITY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "0".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_rand_send_pri_with".to_string(), Some(
         MacroDef {
             ports: [("SEQ_OR_ITEM".to_string(),[].to_vec()), ("PRIORITY".to_string(),[].to_vec()), ("CONSTRAINTS".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`m_uvm_component_registry_internal".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_component_registry_internal".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_component_utils".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeVoid,value: "void".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_field_automation".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeIntAtom,value: "int".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "what__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeString,value: "string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwTypedef,value: "typedef".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "___local_type____".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: TypeString,value: "string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "string_aa_key".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__current_scopes".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SquareLeft,value: "[".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dollar,value: "$".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SquareRight,value: "]".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "what__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwInside,value: "inside".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyLeft,value: "{".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_SETINT".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_SETSTR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_SETOBJ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyRight,value: "}".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_do_cycle_check".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwThis,value: "this".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__current_scopes".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "m_uvm_cycle_scopes".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwSuper,value: "super".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_field_automation".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "what__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpDiff,value: "!=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNull,value: "null".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$cast".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "tmp_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeVoid,value: "void".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_field_automation".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeIntAtom,value: "int".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "what__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeString,value: "string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwTypedef,value: "typedef".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "___local_type____".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: TypeString,value: "string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "string_aa_key".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__current_scopes".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SquareLeft,value: "[".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dollar,value: "$".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SquareRight,value: "]".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "what__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwInside,value: "inside".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyLeft,value: "{".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_SETINT".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_SETSTR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_SETOBJ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyRight,value: "}".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_do_cycle_check".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwThis,value: "this".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__current_scopes".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "m_uvm_cycle_scopes".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwSuper,value: "super".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_field_automation".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "what__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpDiff,value: "!=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNull,value: "null".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$cast".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "tmp_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_field_utils_begin".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_internal".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_internal".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_get_type_name_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_utils_begin".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
-    ast.defines.insert("`uvm_object_utils_end".to_string(), Some(MacroDef {ports: Vec::new(),body: body.clone()}));
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    ast.defines.insert("`uvm_object_utils_end".to_string(), Some(MacroDef {ports: Vec::new(),body: body.clone(), fname: "builtin".to_string(), pos: Position::at(0, 0)}));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_utils".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwTypedef,value: "typedef".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_component_registry".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Hash,value: "#".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "S".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwStatic,value: "static".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwVirtual,value: "virtual".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_wrapper".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_object_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwTypedef,value: "typedef".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_component_registry".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Hash,value: "#".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "S".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwStatic,value: "static".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwVirtual,value: "virtual".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_wrapper".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_object_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_component_registry_internal".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec()), ("S".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_object_param_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_param_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_object_utils_end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_param_utils".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_param".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_registry_param".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_object_create_func".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_object_param_utils_begin".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwTypedef,value: "typedef".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_registry".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Hash,value: "#".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwStatic,value: "static".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwVirtual,value: "virtual".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwFunction,value: "function".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object_wrapper".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_object_type".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "type_id".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwTypedef,value: "typedef".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_registry".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Hash,value: "#".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwStatic,value: "static".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwVirtual,value: "virtual".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwFunction,value: "function".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object_wrapper".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_object_type".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "type_id".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_object_registry_param".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: MacroCall,value: "`uvm_component_utils".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: MacroCall,value: "`uvm_component_utils".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`uvm_field_utils_begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_component_utils_begin".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndFunction,value: "endfunction".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndFunction,value: "endfunction".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_component_utils_end".to_string(), Some(
         MacroDef {
             ports: Vec::new(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NORECORD".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "recorder".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "record_string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "STR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NORECORD".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "recorder".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "record_string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "STR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`m_uvm_record_string".to_string(), Some(
         MacroDef {
             ports: [("ARG".to_string(),[].to_vec()), ("STR".to_string(),[].to_vec()), ("FLAG".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwCase,value: "case".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "what__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_CHECK_FIELDS".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "do_field_check".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwThis,value: "this".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_COPY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq2,value: "==".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNull,value: "null".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NOCOPY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_COMPARE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq2,value: "==".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwNull,value: "null".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NOCOMPARE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpDiff2,value: "!==".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "scope".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "set_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$swrite".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "stringv".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "lhs = %0s : rhs = %0s".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "local_data__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "comparer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "print_msg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "stringv".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "comparer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "result".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpLogicAnd,value: "&&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "comparer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "show_max".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpLTE,value: "<=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "comparer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "result".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwReturn,value: "return".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_PACK".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NOPACK".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "packer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "pack_field".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$bits".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_UNPACK".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NOPACK".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Casting,value: "T\'".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "packer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "unpack_field_int".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$bits".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_RECORD".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`m_uvm_record_string".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_PRINT".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpBang,value: "!".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NOPRINT".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "printer".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "print_generic".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$bits".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_SETINT".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "scope".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "set_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_is_match".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "scope".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_READONLY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_warning".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "RDONLY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$sformatf".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "Readonly argument match %s is ignored".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_full_scope_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "print_matches".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_info".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "STRMTC".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyLeft,value: "{".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "set_int()".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: ": Matched string ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: " to field ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_full_scope_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyRight,value: "}".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_LOW".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Casting,value: "T\'".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "bitstream".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "status".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_SETSTR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Colon,value: ":".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "scope".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "set_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_is_match".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "scope".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "FLAG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpAnd,value: "&".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_READONLY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_warning".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "RDONLY".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SystemTask,value: "$sformatf".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "Readonly argument match %s is ignored".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_full_scope_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwElse,value: "else".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "print_matches".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_info".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "STRMTC".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyLeft,value: "{".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "set_str()".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: ": Matched string ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "str__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: " to field ".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "get_full_scope_arg".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: CurlyRight,value: "}".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_LOW".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Casting,value: "void\'".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_enum_wrapper".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Hash,value: "#".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "T".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "from_name".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_object".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Scope,value: "::".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "stringv".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ARG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "__m_uvm_status_container".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "status".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: OpEq,value: "=".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEndcase,value: "endcase".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwCase,value: "case".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "what__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_CHECK_FIELDS".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "do_field_check".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwThis,value: "this".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_COPY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq2,value: "==".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNull,value: "null".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NOCOPY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_COMPARE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq2,value: "==".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwNull,value: "null".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NOCOMPARE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpDiff2,value: "!==".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "scope".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "set_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$swrite".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "stringv".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "lhs = %0s : rhs = %0s".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "local_data__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "comparer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "print_msg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "stringv".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "comparer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "result".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpLogicAnd,value: "&&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "comparer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "show_max".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpLTE,value: "<=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "comparer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "result".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwReturn,value: "return".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_PACK".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NOPACK".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "packer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "pack_field".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$bits".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_UNPACK".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NOPACK".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Casting,value: "T\'".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "packer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "unpack_field_int".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$bits".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_RECORD".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`m_uvm_record_string".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_PRINT".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpBang,value: "!".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NOPRINT".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "printer".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "print_generic".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$bits".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_SETINT".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "scope".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "set_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_is_match".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "scope".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_READONLY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_warning".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "RDONLY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$sformatf".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "Readonly argument match %s is ignored".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_full_scope_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "print_matches".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_info".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "STRMTC".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyLeft,value: "{".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "set_int()".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: ": Matched string ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: " to field ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_full_scope_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyRight,value: "}".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_LOW".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Casting,value: "T\'".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "bitstream".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "status".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_SETSTR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Colon,value: ":".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "scope".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "set_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_is_match".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "scope".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "FLAG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpAnd,value: "&".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_READONLY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_warning".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "RDONLY".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SystemTask,value: "$sformatf".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "Readonly argument match %s is ignored".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_full_scope_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwElse,value: "else".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "print_matches".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_info".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "STRMTC".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyLeft,value: "{".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "set_str()".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: ": Matched string ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "str__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: " to field ".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "get_full_scope_arg".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: CurlyRight,value: "}".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_LOW".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Casting,value: "void\'".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_enum_wrapper".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Hash,value: "#".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "T".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "from_name".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_object".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Scope,value: "::".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "stringv".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ARG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "__m_uvm_status_container".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "status".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: OpEq,value: "=".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEndcase,value: "endcase".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_field_enum".to_string(), Some(
         MacroDef {
             ports: [("T".to_string(),[].to_vec()), ("ARG".to_string(),[].to_vec()), ("FLAG".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
-    body.push(Token {kind: KwBegin,value: "begin".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwIf,value: "if".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "RO".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_enabled".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_ERROR".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "RO".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Dot,value: ".".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "uvm_report_error".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenLeft,value: "(".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "ID".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "MSG".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Ident,value: "UVM_NONE".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__FILE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: MacroCall,value: "`__LINE__".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Str,value: "".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Comma,value: ",".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: Integer,value: "1".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: ParenRight,value: ")".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: SemiColon,value: ";".to_string(),pos: Position {line: 1, col: 1}});
-    body.push(Token {kind: KwEnd,value: "end".to_string(),pos: Position {line: 1, col: 1}});
+    body.push(Token {kind: KwBegin,value: "begin".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwIf,value: "if".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "RO".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_enabled".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_ERROR".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "RO".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Dot,value: ".".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "uvm_report_error".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenLeft,value: "(".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "ID".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "MSG".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Ident,value: "UVM_NONE".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__FILE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: MacroCall,value: "`__LINE__".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Str,value: "".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Comma,value: ",".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: Integer,value: "1".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: ParenRight,value: ")".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: SemiColon,value: ";".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
+    body.push(Token {kind: KwEnd,value: "end".into(),pos: Position::at(0, 0), end: Position::at(0, 0), exp: None});
     ast.defines.insert("`uvm_error_context".to_string(), Some(
         MacroDef {
             ports: [("ID".to_string(),[].to_vec()), ("MSG".to_string(),[].to_vec()), ("RO".to_string(),[].to_vec())].to_vec(),
-            body: body.clone()}
+            body: body.clone(),
+            fname: "builtin".to_string(), pos: Position::at(0, 0)}
     ));
     body.clear();
 
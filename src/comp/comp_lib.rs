@@ -2,16 +2,18 @@
 // Copyright (c) 2019, clams@mail.com
 
 use std::collections::{HashMap};
+use std::path::PathBuf;
 
 use crate::ast::Ast;
 use crate::ast::astnode::{AstNode,AstNodeKind};
 
 use crate::comp::comp_obj::{ObjDef, ObjDefParam};
 use crate::comp::prototype::*;
-use crate::comp::def_type::{DefType,TypeVIntf,TypePrimary,TypeUser,TYPE_INT,TYPE_STR};
+use crate::comp::def_type::{DefType,TypeIntVector,TypeVIntf,TypePrimary,TypeUser,TYPE_INT,TYPE_STR};
 use crate::comp::lib_uvm::get_uvm_lib;
 use crate::error::{SvError,SvErrorKind};
 use crate::reporter::{REPORTER, MsgID};
+use crate::lex::source::path_display;
 
 type LinkCntxt = (AstNodeKind,String);
 
@@ -21,6 +23,24 @@ pub struct CompLib {
     pub objects: HashMap<String, ObjDef>,
     pub binds  : HashMap<String, Vec<String> >,
     cntxt : Vec<LinkCntxt>,
+    // Compilation unit: name of the current $unit scope and mode (single or one per file)
+    unit : String,
+    single_unit : bool,
+    // Net type used for implicit net declaration (set by `default_nettype, none to forbid them)
+    nettype : String,
+    // Design elements (module/interface/program/checker/primitive) of each library
+    pub cells  : HashMap<String, HashMap<String,ObjDef>>,
+    // Libraries in search order (library map declaration order)
+    pub libs   : Vec<String>,
+    pub configs: HashMap<String, DefConfig>,
+    // Configuration used to bind instances
+    pub config : Option<String>,
+    // Library of the current file, file defining each cell (lib.cell) and flag for library search (-v/-y)
+    lib : String,
+    cell_src : HashMap<String, PathBuf>,
+    lib_search : bool,
+    // Instance paths of each cell (lib.cell) under the design of the configuration
+    inst_paths : HashMap<String, Vec<String>>,
 }
 
 // Structure containing local information for a block:
@@ -51,9 +71,10 @@ impl LocalInfo {
 impl CompLib {
 
     // Create a library containing definition of all object compiled
-    // Try to fix any missing reference, analyze hierarchical access, ...
-    pub fn new(name: String, ast_list: &Vec<Ast>, ast_inc: &HashMap<String,Box<Ast>>) -> CompLib {
-        let mut lib = CompLib {name, objects:HashMap::new(), binds:HashMap::new(), cntxt:Vec::new()};
+    pub fn new(name: String, ast_list: &[Ast], ast_inc: &HashMap<String,Box<Ast>>, single_unit: bool) -> CompLib {
+        let mut lib = CompLib {name, objects:HashMap::new(), binds:HashMap::new(), cntxt:Vec::new(), unit: String::new(), single_unit, nettype: "wire".to_owned(),
+            cells: HashMap::new(), libs: Vec::new(), configs: HashMap::new(), config: None,
+            lib: String::new(), cell_src: HashMap::new(), lib_search: false, inst_paths: HashMap::new()};
         // let mut missing_scope : HashSet<String> = HashSet::new();
 
         // Create a top object for type/localparam definition without scope
@@ -62,24 +83,130 @@ impl CompLib {
 
         // Extract object definition from all ASTs
         for ast in ast_list {
-            rpt_set_fname!(&ast.filename);
-            // rpt_s!(MsgID::DbgStatus,"Compiling AST");
-            ObjDef::from_ast(&ast, ast_inc, &mut lib);
-            // ObjDef::from_ast(&ast, &ast_inc, &mut lib.objects);
+            lib.add_ast(ast, ast_inc);
+        }
+        lib
+    }
+
+    // Extract object definition from an AST
+    pub fn add_ast(&mut self, ast: &Ast, ast_inc: &HashMap<String,Box<Ast>>) {
+        rpt_set_fname!(&ast.filename);
+        self.set_unit(&ast.filename);
+        self.lib = ast.lib.clone();
+        // rpt_s!(MsgID::DbgStatus,"Compiling AST");
+        ObjDef::from_ast(ast, ast_inc, self);
+    }
+
+    // Extract object definition from an AST found in a library file/directory (-v/-y):
+    // design elements already defined are silently ignored
+    pub fn add_lib_ast(&mut self, ast: &Ast, ast_inc: &HashMap<String,Box<Ast>>) {
+        self.lib_search = true;
+        self.add_ast(ast, ast_inc);
+        self.lib_search = false;
+    }
+
+    // Add a design element to the current library, reporting any redefinition coming from another file
+    pub fn add_cell(&mut self, d: DefModule, fname: &PathBuf, node: &AstNode) {
+        let key = format!("{}.{}", self.lib, d.name);
+        let cells = self.cells.entry(self.lib.clone()).or_default();
+        if cells.contains_key(&d.name) {
+            if !self.lib_search && self.cell_src.get(&key) != Some(fname) {
+                rpt!(MsgID::ErrInvalid, node, &format!("{} already defined in library {} ({})", d.name, self.lib, path_display(&self.cell_src[&key])));
+            }
+            return;
+        }
+        // Top object keeps the first definition: cells with the same name in other libraries are bound by the library search
+        self.objects.entry(d.name.clone()).or_insert_with(|| ObjDef::Module(d.clone()));
+        cells.insert(d.name.clone(),ObjDef::Module(d));
+        self.cell_src.insert(key, fname.clone());
+    }
+
+    // Select the compilation unit ($unit) of a file, creating it if needed
+    fn set_unit(&mut self, fname: &PathBuf) {
+        self.unit = self.unit_name(fname);
+        if !self.objects.contains_key(&self.unit) {
+            self.objects.insert(self.unit.clone(),ObjDef::Package(DefPackage::new(self.unit.clone())));
+        }
+    }
+
+    // Name of the compilation unit ($unit) of a file
+    pub fn unit_name(&self, fname: &PathBuf) -> String {
+        if self.single_unit {"$unit".to_owned()} else {format!("$unit:{}",path_display(fname))}
+    }
+
+    // Definitions of the current compilation unit: declarations outside of any module/package/class
+    // None when no compilation unit was selected
+    pub fn unit_defs(&mut self) -> Option<&mut HashMap<String,ObjDef>> {
+        match self.objects.get_mut(&self.unit) {
+            Some(ObjDef::Package(d)) => Some(&mut d.defs),
+            _ => None
+        }
+    }
+
+    // Add a definition to the current compilation unit
+    pub fn add_unit_def(&mut self, name: String, d: ObjDef) {
+        if let Some(defs) = self.unit_defs() {
+            defs.insert(name,d);
         }
+    }
+
+    // List of module instantiated in the ASTs without definition in the library
+    pub fn unresolved_modules(&self, ast_list: &[Ast]) -> Vec<String> {
+        fn collect(node: &AstNode, v: &mut Vec<String>) {
+            for nc in &node.child {
+                if nc.kind == AstNodeKind::Instances {
+                    if let Some(t) = nc.attr.get("type") {v.push(t.clone());}
+                } else {
+                    collect(nc,v);
+                }
+            }
+        }
+        let mut v = Vec::new();
+        for ast in ast_list {
+            collect(&ast.tree,&mut v);
+        }
+        v.retain(|t| !matches!(self.objects.get(t), Some(ObjDef::Module(_))));
+        v.sort();
+        v.dedup();
+        v
+    }
 
+    // Try to fix any missing reference, analyze hierarchical access, ...
+    pub fn check_all(&mut self, ast_list: &[Ast], ast_inc: &HashMap<String,Box<Ast>>) {
         // Reduce all bind path to a single type
-        lib.solve_bind();
+        self.solve_bind();
+        // Configuration: the one selected or the only one defined
+        if self.config.is_none() && self.configs.len()==1 {
+            self.config = self.configs.keys().next().cloned();
+        }
+        if let Some(c) = &self.config {
+            if !self.configs.contains_key(c) {
+                rpt_s!(MsgID::ErrNotFound, &format!("Configuration {} not found", c));
+                self.config = None;
+            }
+        }
+        self.set_inst_paths();
 
         // Second pass : check types and signals are defined, module instance are correct ...
+        // In single compilation unit, imports done in $unit are visible in the following files
+        let mut imports = Vec::new();
+        self.nettype = "wire".to_owned();
         for ast in ast_list {
             rpt_set_fname!(&ast.filename);
             // rpt_s!(MsgID::DbgStatus,"Linking AST");
-            let mut li = LocalInfo{imports: Vec::new(),defs: Vec::new(), obj: None};
-            lib.check_ast(&ast.tree, ast_inc, &mut li, true);
+            self.set_unit(&ast.filename);
+            self.lib = ast.lib.clone();
+            // `default_nettype applies until the end of the compilation unit
+            if !self.single_unit {
+                self.nettype = "wire".to_owned();
+            }
+            if !self.single_unit || imports.is_empty() {
+                imports = vec![self.unit.clone()];
+            }
+            let mut li = LocalInfo{imports,defs: Vec::new(), obj: None};
+            self.check_ast(&ast.tree, ast_inc, &mut li, true);
+            imports = li.imports;
         }
-
-        lib
     }
 
     pub fn check_ast(&mut self, node: &AstNode, ast_inc: & HashMap<String,Box<Ast>>, li: &mut LocalInfo, new_cntxt: bool) {
@@ -99,7 +226,7 @@ impl CompLib {
                     }
                 }
                 if let Some(k) = rt {
-                    let mb = DefMember{name: node.attr["name"].clone(),kind: k, unpacked : Vec::new(), is_const: false, access: Access::Public};
+                    let mb = DefMember{name: node.attr["name"].clone(),kind: k, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
                     li.add_def(node.attr["name"].clone(),ObjDef::Member(mb));
                 }
 
@@ -110,6 +237,8 @@ impl CompLib {
                 // Blocks
                 AstNodeKind::Interface |
                 AstNodeKind::Module    |
+                AstNodeKind::Program   |
+                AstNodeKind::Udp       |
                 AstNodeKind::Package   |
                 AstNodeKind::Class     => {
                     // TODO: scope might be best describe as a vector of string ...
@@ -117,13 +246,20 @@ impl CompLib {
                     if let Some((_,v)) = self.cntxt.get(0) {
                         scope = Some(v.clone());
                     }
-                    li.obj = match self.find_def(&nc.attr["name"],scope.as_ref(),li,false,false,false) {
-                        Ok(x) => Some(x.0.clone()),
-                        _ => None
+                    // Design element: take the definition from the library of the file
+                    li.obj = match self.cells.get(&self.lib).and_then(|c| c.get(&nc.attr["name"])) {
+                        Some(d) if nc.kind!=AstNodeKind::Package && nc.kind!=AstNodeKind::Class => Some(d.clone()),
+                        _ => match self.find_def(&nc.attr["name"],scope.as_ref(),li,false,false,false) {
+                            Ok(x) => Some(x.0.clone()),
+                            _ => None
+                        }
                     };
                     self.cntxt.push((nc.kind.clone(), nc.attr["name"].clone()));
                     // println!("[Linking] {} {}", nc.kind, nc.attr["name"]);
+                    // Imports inside a design element are local to it: only $unit imports are kept
+                    let nb_imports = li.imports.len();
                     self.check_ast(&nc, &ast_inc, li,true);
+                    li.imports.truncate(nb_imports);
                     self.cntxt.pop();
                 }
                 AstNodeKind::Process   |
@@ -190,7 +326,7 @@ impl CompLib {
                                         let mb = DefMember{
                                             name: x.attr["name"].clone(),
                                             kind: t,
-                                            unpacked : Vec::new(), is_const: false, access: Access::Public};
+                                            unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
 
                                         li.add_def(mb.name.clone(),ObjDef::Member(mb));
                                     }
@@ -223,6 +359,12 @@ impl CompLib {
                             _ => if i!="uvm_macros.svh" {rpt_s!(MsgID::ErrFile,i);}
                         }
                     }
+                    else if let Some(nt) = nc.attr.get("nettype") {
+                        self.nettype = nt.clone();
+                    }
+                    else if nc.attr["name"]=="`line" {
+                        rpt_line_fname!(PathBuf::from(&nc.attr["filename"]));
+                    }
                 }             
                 // Update local info
                 AstNodeKind::Import => {
@@ -230,12 +372,15 @@ impl CompLib {
                     if nc.attr.contains_key("dpi") {
                         if nc.attr["kind"]=="import" {
                             if nc.child.len() == 1 {
+                                self.check_dpi_import(nc,li);
                                 let m = DefMethod::from(&nc.child[0]);
                                 li.add_def(m.name.clone(),ObjDef::Method(m));
                             } else {
                                 rpt!(MsgID::DbgSkip,nc,"DPI import");
                                 // println!("[Linking] {:?} | Skipping DPI import : {:?}", self.cntxt, nc);
                             }
+                        } else {
+                            self.check_dpi_export(nc,li);
                         }
                     }
                     // Import package
@@ -280,7 +425,7 @@ impl CompLib {
                     let t = DefType::from(nc);
                     let m = DefMember{
                         name: self.cntxt.last().unwrap().1.clone(),
-                        kind : t, is_const: false, unpacked: Vec::new(), access: Access::Local};
+                        kind : t, is_const: false, unpacked: Vec::new(), access: Access::Local, rand: RandKind::None};
                     if let DefType::User(k) = &m.kind {
                         if self.find_def(&k.name,k.scope.as_ref(),li,false,false,false).is_err() {
                             rpt!(MsgID::ErrNotFound, nc, &format!("type {}",k.name));
@@ -300,7 +445,8 @@ impl CompLib {
                                     kind : t.clone(),
                                     is_const : false,
                                     unpacked : Vec::new(),
-                                    access   : Access::Public // TODO
+                                    access   : Access::Public, // TODO
+                                    rand     : RandKind::None
                                 };
                                 li.add_def(m.name.clone(),ObjDef::Member(m));
                             }
@@ -316,7 +462,7 @@ impl CompLib {
                             // Add Enum value if any
                             DefType::Enum(te) => {
                                 // println!("[Linking] Typedef enum {:?}", te);
-                                for tev in te {
+                                for tev in &te.values {
                                     li.add_def(tev.clone(),ObjDef::EnumValue(nc.attr["name"].clone()));
                                 }
                                 li.add_def(nc.attr["name"].clone(),ObjDef::Type(d,Vec::new()));
@@ -363,7 +509,8 @@ impl CompLib {
                                     kind: d.clone(),
                                     unpacked : Vec::new(),
                                     is_const: false,
-                                    access: Access::Public
+                                    access: Access::Public,
+                                    rand  : RandKind::None
                                 };
                                 li.add_def(m.name.clone(),ObjDef::Member(m));
                             }
@@ -403,6 +550,8 @@ impl CompLib {
                     }
                 }
                 AstNodeKind::Task |
+                AstNodeKind::Function if nc.child.iter().any(|x| x.kind==AstNodeKind::Scope) => self.check_oob(nc,ast_inc,li),
+                AstNodeKind::Task |
                 AstNodeKind::Function => {
                     // let m = DefMethod::from(nc);
                     // li.add_def(m.name.clone(),ObjDef::Method(m));
@@ -415,25 +564,71 @@ impl CompLib {
                 }
                 // Interface defintion
                 AstNodeKind::Modport => {
-                    let d : DefModport = nc.child.iter().map(|x| x.attr["name"].clone()).collect();
+                    let d = DefModport::from(nc);
+                    self.check_modport(nc,li);
                     li.add_def(nc.attr["name"].clone(),ObjDef::Modport(d));
-                    // TODO: add check
                 }
-                // Handle Non-Ansi port declaration
+                // Clocking block: only one default/global clocking per scope
                 AstNodeKind::Clocking => {
-                    // TODO: update once parsing actually extract all part of the clocking block
-                    let d : DefClocking = nc.child.iter()
-                                            .filter(|x| x.kind==AstNodeKind::Identifier)
-                                            .map(|x| x.attr["name"].clone())
-                                            .collect();
-                    // println!("[{:?}] Clocking {:?} : {:?} \n\t{:#?}", self.name, nc.attr["name"], d,n);
-                    li.add_def(nc.attr["name"].clone(),ObjDef::Clocking(d));
-                    // TODO: add check
+                    let d = DefClocking::from(nc);
+                    self.check_clocking(nc,&d,ast_inc,li);
+                    if let Some(k) = d.scope_key() {
+                        if li.defs.last().is_some_and(|x| x.contains_key(k)) {
+                            rpt!(MsgID::ErrInvalid, nc, &format!("Multiple {} clocking in the same scope.", if d.is_default {"default"} else {"global"}));
+                        }
+                        li.add_def(k.to_owned(),ObjDef::Clocking(d.clone()));
+                    }
+                    if !d.name.is_empty() && d.event.is_some() {
+                        li.add_def(d.name.clone(),ObjDef::Clocking(d));
+                    }
+                }
+                // Sequence/property declaration: check the expression with the formal arguments and local variables
+                AstNodeKind::SvaProperty |
+                AstNodeKind::SvaSequence => {
+                    let d = DefMethod::from(nc);
+                    li.add_def(d.name.clone(),ObjDef::Method(d));
+                    self.check_ast(nc, ast_inc, li,true);
+                }
+                // Let declaration: check the expression with the formal arguments
+                AstNodeKind::Let => {
+                    let d = DefMethod::from(nc);
+                    li.add_def(d.name.clone(),ObjDef::Method(d));
+                    self.check_ast(nc, ast_inc, li,true);
+                }
+                // Checker declaration: check the body with the formal arguments
+                AstNodeKind::Checker => {
+                    self.cntxt.push((nc.kind.clone(), nc.attr["name"].clone()));
+                    self.check_ast(nc, ast_inc, li,true);
+                    self.cntxt.pop();
+                }
+                AstNodeKind::SvaExpr => self.search_sva(nc,li),
+                AstNodeKind::UdpTable  => self.check_udp_table(nc,li),
+                AstNodeKind::Config    => self.check_config(nc),
+                AstNodeKind::Primitive => self.check_primitive(nc,li),
+                AstNodeKind::Specify => self.check_ast(nc, ast_inc, li,true),
+                AstNodeKind::SpecPath => self.check_spec_path(nc,li),
+                AstNodeKind::TimingCheck => {
+                    for ncc in &nc.child {
+                        if ncc.attr.contains_key("delayed") {
+                            // Delayed signals are implicitly declared in the module (IEEE 1800 31.9), not local to the specify block
+                            let local = li.defs.pop();
+                            if ncc.kind==AstNodeKind::Identifier && ncc.child.is_empty() {
+                                if self.find_ident_def(ncc,li,false).is_err() {
+                                    self.add_net(&ncc.attr["name"],li);
+                                }
+                            } else {
+                                self.search_ident(ncc,li);
+                            }
+                            li.defs.extend(local);
+                        }
+                        else if ncc.kind==AstNodeKind::Identifier {self.check_ident(ncc,li);}
+                        else {self.search_ident(ncc,li);}
+                    }
                 }
                 AstNodeKind::Covergroup => {
-                    let mut d = DefCovergroup::new(nc.attr["name"].clone());
+                    let d = DefCovergroup::from(nc);
+                    self.check_covergroup(nc,&d,ast_inc,li);
                     li.add_def(nc.attr["name"].clone(),ObjDef::Covergroup(d));
-                    // TODO: add check
                 }
                 //-----------------------------
                 // Check
@@ -465,7 +660,23 @@ impl CompLib {
                 // - Check every variable has been declared
                 // - Check type compatibility
                 AstNodeKind::Assign => {
-                    self.search_ident(&nc,&li);
+                    // Continuous assignment: the left-hand side can be an implicit net
+                    if nc.attr.get("kind").map(|k| k.as_str())==Some("assign") && nc.child.len()>1 {
+                        let lhs = nc.child.len() - 2;
+                        for (i,ncc) in nc.child.iter().enumerate() {
+                            if i==lhs {
+                                self.check_implicit_net(ncc,li);
+                                self.check_write(ncc,"assign",li);
+                            }
+                            else if ncc.kind==AstNodeKind::Identifier {self.check_ident(ncc,li);}
+                            else {self.search_ident(ncc,li);}
+                        }
+                    } else {
+                        self.search_ident(&nc,&li);
+                        if let Some(ncc) = nc.child.first() {
+                            self.check_write(ncc,nc.attr.get("kind").map_or("",|k| k.as_str()),li);
+                        }
+                    }
                 },
                 AstNodeKind::Assert      |
                 AstNodeKind::Concat      |
@@ -479,17 +690,22 @@ impl CompLib {
                 AstNodeKind::SystemTask  |
                 AstNodeKind::Type |
                 AstNodeKind::Wait        => {
+                    self.check_cycle_delay(nc,li);
                     self.search_ident(&nc,&li);
                 },
                 // TODO :
                 AstNodeKind::Define     => {}
-                AstNodeKind::Constraint => {}
+                AstNodeKind::Constraint if nc.has_scope() => self.check_oob(nc,ast_inc,li),
+                AstNodeKind::Constraint => self.check_constraint(nc,ast_inc,li),
                 // Whitelist
+                AstNodeKind::Scope  |
                 AstNodeKind::Value  |
                 AstNodeKind::Timescale  => {}
                 AstNodeKind::Bind  => {
                     // println!("[Linking] {:?} | Binding ignored {:?} ({} childs) : {:?}", self.cntxt, nc.kind, nc.child.len(), nc.attr);
                 }
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip,nc,"Root (comp_lib)")
             }
         }
@@ -498,6 +714,391 @@ impl CompLib {
         }
     }
 
+    // Check a covergroup: sampled expressions, conditions and cross items must be declared,
+    // bins selection must refer to existing bins and options must be known
+    fn check_covergroup(&mut self, node: &AstNode, d: &DefCovergroup, ast_inc: & HashMap<String,Box<Ast>>, li: &mut LocalInfo) {
+        li.defs.push(HashMap::new());
+        for nc in &node.child {
+            match nc.kind {
+                // Arguments of the covergroup and of the sample method
+                AstNodeKind::Ports => self.check_ast(nc, ast_inc, li,false),
+                AstNodeKind::Sensitivity => self.search_ident(nc,li),
+                AstNodeKind::CoverOption => self.check_cover_option(nc,li),
+                AstNodeKind::Coverpoint => {
+                    for ncc in &nc.child {
+                        match ncc.kind {
+                            AstNodeKind::CoverBins   => self.check_cover_bins(ncc,d,li),
+                            AstNodeKind::CoverOption => self.check_cover_option(ncc,li),
+                            AstNodeKind::Error => {}
+                            // Sampled expression or iff condition
+                            _ => self.check_cover_expr(ncc,li),
+                        }
+                    }
+                }
+                AstNodeKind::CoverCross => {
+                    for ncc in &nc.child {
+                        match ncc.kind {
+                            // Cross item: either a coverpoint or a variable (implicit coverpoint)
+                            AstNodeKind::Identifier if !ncc.attr.contains_key("kind") => {
+                                if !d.coverpoints.contains_key(&ncc.attr["name"]) && self.find_ident_def(ncc,li,false).is_err() {
+                                    let cross = nc.attr.get("name").map(|n| format!("cross {}",n)).unwrap_or_else(|| "cross".to_owned());
+                                    rpt!(MsgID::ErrNotFound, ncc, &format!("coverpoint {} in {} of covergroup {}",ncc.attr["name"],cross,d.name));
+                                }
+                            }
+                            AstNodeKind::CoverBins   => self.check_cover_bins(ncc,d,li),
+                            AstNodeKind::CoverOption => self.check_cover_option(ncc,li),
+                            // TODO: check functions used in bins selection
+                            AstNodeKind::Function => {}
+                            AstNodeKind::Error => {}
+                            _ => self.check_cover_expr(ncc,li),
+                        }
+                    }
+                }
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
+                _ => rpt!(MsgID::DbgSkip,nc,"Covergroup")
+            }
+        }
+        li.defs.pop();
+    }
+
+    // Check all identifiers of a coverage expression are declared
+    fn check_cover_expr(&self, node: &AstNode, li: &LocalInfo) {
+        if node.kind == AstNodeKind::Identifier {
+            self.check_ident(node,li);
+        } else {
+            self.search_ident(node,li);
+        }
+    }
+
+    // Check a bins of a coverpoint or a bins selection of a cross
+    fn check_cover_bins(&self, node: &AstNode, d: &DefCovergroup, li: &mut LocalInfo) {
+        for nc in &node.child {
+            match nc.kind {
+                // binsof(coverpoint.bins): the bins must be defined in the coverpoint
+                AstNodeKind::Identifier if nc.attr.contains_key("binsof") => {
+                    match d.coverpoints.get(&nc.attr["name"]) {
+                        Some(bins) => {
+                            if let Some(b) = nc.child.first() {
+                                if !bins.contains(&b.attr["name"]) {
+                                    rpt!(MsgID::ErrNotFound, b, &format!("bins {} in coverpoint {} of covergroup {}",b.attr["name"],nc.attr["name"],d.name));
+                                }
+                            }
+                        }
+                        None => self.check_ident(nc,li),
+                    }
+                }
+                AstNodeKind::Identifier if d.coverpoints.contains_key(&nc.attr["name"]) => {}
+                // The with clause can use the implicit variable item
+                _ if nc.attr.get("kind").map(|k| k.as_str())==Some("with") => {
+                    li.defs.push(HashMap::new());
+                    let m = DefMember{name: "item".to_owned(), kind: TYPE_INT, unpacked: Vec::new(), is_const: false, access: Access::Local, rand: RandKind::None};
+                    li.add_def(m.name.clone(),ObjDef::Member(m));
+                    self.check_cover_expr(nc,li);
+                    li.defs.pop();
+                }
+                _ => self.check_cover_expr(nc,li),
+            }
+        }
+    }
+
+    // Check the name of a coverage option is valid and its value is declared
+    fn check_cover_option(&self, node: &AstNode, li: &LocalInfo) {
+        let valid = if node.attr["kind"]=="option" {
+            ["name", "weight", "goal", "comment", "at_least", "auto_bin_max", "cross_num_print_missing",
+             "detect_overlap", "per_instance", "get_inst_coverage", "cross_retain_auto_bins"].contains(&node.attr["name"].as_str())
+        } else {
+            ["weight", "goal", "comment", "strobe", "merge_instances", "distribute_first", "real_interval"].contains(&node.attr["name"].as_str())
+        };
+        if !valid {
+            rpt!(MsgID::ErrNotFound, node, &format!("covergroup option {}.{}",node.attr["kind"],node.attr["name"]));
+        }
+        for nc in &node.child {
+            self.check_cover_expr(nc,li);
+        }
+    }
+
+    // Check an out-of-block method or constraint (class_name::name) with the members of its class
+    fn check_oob(&mut self, node: &AstNode, ast_inc: & HashMap<String,Box<Ast>>, li: &mut LocalInfo) {
+        // The scope follows the return type of a function
+        let ns = match node.child.iter().find(|x| x.kind==AstNodeKind::Scope) {
+            Some(n) => n,
+            None => return
+        };
+        let cname = ns.attr["name"].clone();
+        // Class declared in the package being checked
+        let pkg = match self.cntxt.first() {
+            Some((AstNodeKind::Package,v)) => Some(v.clone()),
+            _ => None
+        };
+        let cd = match self.find_def(&cname,None,li,false,false,false).or_else(|_| self.find_def(&cname,pkg.as_ref(),li,false,false,false)) {
+            Ok((ObjDef::Class(cd),_)) => cd.clone(),
+            _ => {
+                rpt!(MsgID::ErrNotFound, ns, &format!("class {}",cname));
+                return;
+            }
+        };
+        li.defs.push(cd.params.clone());
+        li.defs.push(cd.defs.clone());
+        let obj = li.obj.replace(ObjDef::Class(cd));
+        self.cntxt.push((AstNodeKind::Class, cname));
+        if node.kind == AstNodeKind::Constraint {
+            self.check_constraint(node,ast_inc,li);
+        } else {
+            self.cntxt.push((node.kind.clone(), node.attr["name"].clone()));
+            self.check_ast(node, ast_inc, li,true);
+            self.cntxt.pop();
+        }
+        self.cntxt.pop();
+        li.obj = obj;
+        li.defs.truncate(li.defs.len()-2);
+    }
+
+    // Check a constraint block or item: all variables must be declared,
+    // variables ordered by solve before or used in disable soft must be random
+    fn check_constraint(&mut self, node: &AstNode, ast_inc: & HashMap<String,Box<Ast>>, li: &mut LocalInfo) {
+        match node.attr.get("kind").map(|k| k.as_str()) {
+            Some("solve") => {
+                for nc in &node.child {
+                    self.check_rand_var(nc,li,"solve before",false);
+                }
+            }
+            Some("disable soft") => {
+                for nc in &node.child {
+                    self.check_rand_var(nc,li,"disable soft",true);
+                }
+            }
+            _ => self.check_ast(node, ast_inc, li,false)
+        }
+    }
+
+    // Check a variable is a rand class member (randc only when allowed), including inherited members
+    fn check_rand_var(&self, node: &AstNode, li: &LocalInfo, cntxt: &str, allow_randc: bool) {
+        let o = match self.find_ident_def(node,li,true) {
+            Ok(d) => d,
+            Err(e) => {
+                rpt!(MsgID::ErrNotFound, node, &format!("identifier {}: {}",node.attr["name"], e));
+                return;
+            }
+        };
+        match o {
+            ObjDef::Member(m) if m.rand==RandKind::Rand => {}
+            ObjDef::Member(m) if m.rand==RandKind::RandC => {
+                if !allow_randc {
+                    rpt!(MsgID::ErrInvalid, node, &format!("randc variable {} in {}: only rand variables are allowed.",node.attr["name"],cntxt));
+                }
+            }
+            _ => rpt!(MsgID::ErrInvalid, node, &format!("Variable {} in {} is not a random variable (rand/randc).",node.attr["name"],cntxt)),
+        }
+    }
+
+    // Check a specify path: source must be an input/inout port and destination an output/inout port
+    fn check_spec_path(&self, node: &AstNode, li: &LocalInfo) {
+        let ports = if let Some(ObjDef::Module(d)) = &li.obj {Some(&d.ports)} else {None};
+        for nc in &node.child {
+            let path = match nc.attr.get("path") {
+                Some(p) if ports.is_some() => p,
+                // Condition, data source or delay value
+                _ => {
+                    if nc.kind==AstNodeKind::Identifier {self.check_ident(nc,li);}
+                    else {self.search_ident(nc,li);}
+                    continue;
+                }
+            };
+            let (dir_ok, cntxt) = if path=="src" {(PortDir::Input,"source")} else {(PortDir::Output,"destination")};
+            match ports.unwrap().get(&nc.attr["name"]) {
+                Some(ObjDef::Port(p)) => {
+                    if p.dir != dir_ok && p.dir != PortDir::Inout {
+                        rpt!(MsgID::ErrInvalid, nc, &format!("Specify path {} {} must be an {}/inout port (found {}).",
+                            cntxt, nc.attr["name"], format!("{:?}",dir_ok).to_lowercase(), format!("{:?}",p.dir).to_lowercase()));
+                    }
+                }
+                _ => {
+                    if self.find_ident_def(nc,li,false).is_ok() {
+                        rpt!(MsgID::ErrInvalid, nc, &format!("Specify path {} {} is not a port of the module.",cntxt, nc.attr["name"]));
+                    } else {
+                        self.check_ident(nc,li);
+                    }
+                }
+            }
+        }
+    }
+
+    // Check the table of a user-defined primitive: symbols of each entry and conflicting entries
+    fn check_udp_table(&self, node: &AstNode, li: &LocalInfo) {
+        let d = if let Some(ObjDef::Module(d)) = &li.obj {d} else {return};
+        let is_reg = |t: &DefType| if let DefType::IntVector(x) = t {x.name=="reg"} else {false};
+        let mut nb_in = 0;
+        let mut is_seq = false;
+        for o in d.ports.values() {
+            if let ObjDef::Port(p) = o {
+                match p.dir {
+                    PortDir::Input  => nb_in += 1,
+                    // Sequential primitive: output declared as reg
+                    PortDir::Output => {
+                        is_seq |= is_reg(&p.kind);
+                        if let Some(ObjDef::Member(m)) = d.defs.get(&p.name) {
+                            is_seq |= is_reg(&m.kind);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let mut entries : Vec<(&AstNode,UdpEntry)> = Vec::new();
+        for nc in &node.child {
+            if nc.kind != AstNodeKind::UdpEntry {continue;}
+            let e = match UdpEntry::new(nc,nb_in,is_seq) {
+                Ok(e) => e,
+                Err(m) => {rpt!(MsgID::ErrInvalid, nc, &m); continue;}
+            };
+            // Report only the first conflict of an entry
+            if let Some((np,_)) = entries.iter().find(|(_,x)| x.conflicts(&e)) {
+                rpt!(MsgID::ErrInvalid, nc, &format!("Table entry conflicts with the entry at line {}.", np.pos.line));
+            }
+            entries.push((nc,e));
+        }
+    }
+
+    // Check the libraries and cells referenced by a configuration
+    fn check_config(&self, node: &AstNode) {
+        for nc in &node.child {
+            let mut libs : Vec<&str> = nc.attr.get("liblist").map(|l| l.split_whitespace().collect()).unwrap_or_default();
+            libs.extend(nc.attr.get("lib").into_iter().chain(nc.attr.get("use_lib")).map(|x| x.as_str()));
+            for l in libs {
+                if !self.libs.iter().any(|x| x==l) {
+                    rpt!(MsgID::ErrNotFound, nc, &format!("library {}", l));
+                }
+            }
+            // Design cell and cell bound by a use clause must exist
+            let (lib,cell) = match (nc.attr.get("cell"),nc.attr.get("use")) {
+                (Some(c),_) if nc.attr["kind"]=="design" => (nc.attr.get("lib"),c),
+                (_,Some(c)) if !nc.attr.contains_key("config") => (nc.attr.get("use_lib"),c),
+                (_,Some(c)) => {
+                    if !self.configs.contains_key(c) {rpt!(MsgID::ErrNotFound, nc, &format!("configuration {}", c));}
+                    continue;
+                }
+                _ => continue
+            };
+            let liblist = match lib {
+                Some(l) => vec![l.clone()],
+                None => self.libs.clone()
+            };
+            if liblist.iter().all(|l| self.libs.contains(l)) && self.search_cell(&liblist,cell).is_none() {
+                rpt!(MsgID::ErrNotFound, nc, &format!("cell {}", cell));
+            }
+        }
+    }
+
+    // Check a DPI import: only types with a C equivalent, no ref argument and no pure task
+    fn check_dpi_import(&self, node: &AstNode, li: &LocalInfo) {
+        let m = &node.child[0];
+        if m.kind==AstNodeKind::Task && node.attr.get("property").map(|x| x.as_ref())==Some("pure") {
+            rpt!(MsgID::ErrInvalid, node, &format!("DPI task {} cannot be pure", m.attr["name"]));
+        }
+        // Return value: small values only (scalar, no packed dimension)
+        if let Some(t) = m.child.iter().find(|x| x.kind==AstNodeKind::Type) {
+            let scalar = !t.child.iter().any(|x| x.kind==AstNodeKind::Slice);
+            match self.is_dpi_type(t,li) {
+                None => rpt!(MsgID::ErrNotFound, t, &format!("type {}", t.attr["type"])),
+                Some(ok) if !(scalar && ok) => rpt!(MsgID::ErrInvalid, t, &format!("Illegal return type {} for DPI function {}", t.attr.get("type").unwrap_or(&"".to_owned()), m.attr["name"])),
+                _ => {}
+            }
+        }
+        let mut dir = "input";
+        for np in m.child.iter().filter(|x| x.kind==AstNodeKind::Ports).flat_map(|x| x.child.iter()) {
+            // Direction is inherited from the previous argument
+            if let Some(d) = np.attr.get("dir") {dir = d;}
+            let is_dpi = self.is_dpi_type(np,li);
+            if is_dpi.is_none() {
+                rpt!(MsgID::ErrNotFound, np, &format!("type {}", np.attr["type"]));
+            }
+            for ni in np.child.iter().filter(|x| x.kind==AstNodeKind::Identifier) {
+                if dir=="ref" {
+                    rpt!(MsgID::ErrInvalid, ni, &format!("DPI argument {} cannot be passed by ref", ni.attr["name"]));
+                }
+                // Unpacked dimensions: fixed size or open array only (no queue/associative array)
+                let bad_dim = ni.child.iter().filter(|x| x.kind==AstNodeKind::Slice).any(|x| x.child.iter().any(|d| d.kind==AstNodeKind::Type || d.attr.get("value").map(|v| v=="$" || v=="*")==Some(true)));
+                if bad_dim || is_dpi==Some(false) {
+                    rpt!(MsgID::ErrInvalid, ni, &format!("Illegal type {} for DPI argument {}", np.attr.get("type").unwrap_or(&"".to_owned()), ni.attr["name"]));
+                }
+            }
+        }
+    }
+
+    // Check a type can be passed to a DPI function: integral, real, string, chandle or user type which is not a class.
+    // Return None when the type is not defined.
+    fn is_dpi_type(&self, node: &AstNode, li: &LocalInfo) -> Option<bool> {
+        let t = match node.attr.get("type") {
+            Some(t) => t,
+            None => return Some(true)
+        };
+        match t.as_ref() {
+            "void" | "byte" | "shortint" | "int" | "longint" | "integer" | "time" | "struct" | "enum" |
+            "real" | "shortreal" | "realtime" | "chandle" | "string" | "bit" | "logic" | "reg" => Some(true),
+            "event" | "virtual" | "mailbox" | "semaphore" | "process" => Some(false),
+            _ => {
+                let scope = if node.has_scope() {Some(&node.child[0].attr["name"])} else {None};
+                // Type declared in the package being checked
+                let pkg = match self.cntxt.first() {
+                    Some((AstNodeKind::Package,v)) if scope.is_none() => Some(v),
+                    _ => None
+                };
+                match self.find_def(t,scope,li,false,true,false).or_else(|e| pkg.map_or(Err(e), |p| self.find_def(t,Some(p),li,false,true,false))) {
+                    Ok((ObjDef::Class(_),_)) => Some(false),
+                    Ok(_) => Some(true),
+                    Err(_) => None
+                }
+            }
+        }
+    }
+
+    // Check a DPI export refers to a function/task of the same scope
+    fn check_dpi_export(&self, node: &AstNode, li: &LocalInfo) {
+        let name = &node.attr["name"];
+        let d = match &li.obj {
+            Some(o) if !self.cntxt.is_empty() => o.get_def(name),
+            _ => self.objects.get(&self.unit).and_then(|o| o.get_def(name))
+        };
+        match d {
+            Some(ObjDef::Method(m)) if m.is_task == (node.attr["method"]=="task") => {}
+            Some(ObjDef::Method(_)) => rpt!(MsgID::ErrInvalid, node, &format!("DPI export of {}: not a {}", name, node.attr["method"])),
+            _ => rpt!(MsgID::ErrNotFound, node, &format!("{} {} for DPI export", node.attr["method"], name)),
+        }
+    }
+
+    // Check a gate instantiation: number of terminals and signals connected (implicit net allowed)
+    fn check_primitive(&self, node: &AstNode, li: &mut LocalInfo) {
+        let (nb_min, nb_max) = match node.attr["type"].as_ref() {
+            "bufif0" | "bufif1" | "notif0" | "notif1" |
+            "nmos" | "pmos" | "rnmos" | "rpmos" |
+            "tranif0" | "tranif1" | "rtranif0" | "rtranif1" => (3,3),
+            "cmos" | "rcmos"       => (4,4),
+            "tran" | "rtran"       => (2,2),
+            "pullup" | "pulldown"  => (1,1),
+            // n-input/n-output gates
+            _ => (2,usize::MAX)
+        };
+        for nc in &node.child {
+            if nc.kind != AstNodeKind::Instance {
+                self.search_ident(nc,li);
+                continue;
+            }
+            let nb = nc.child.iter().filter(|x| x.kind==AstNodeKind::Port).count();
+            if nb < nb_min || nb > nb_max {
+                let exp = if nb_min==nb_max {format!("{}",nb_min)} else {format!("at least {}",nb_min)};
+                rpt!(MsgID::ErrInvalid, nc, &format!("Gate {} expects {} terminals (found {}).", node.attr["type"], exp, nb));
+            }
+            for ncc in &nc.child {
+                match ncc.kind {
+                    AstNodeKind::Port  => ncc.child.iter().for_each(|x| self.check_implicit_net(x,li)),
+                    AstNodeKind::Slice => self.search_ident(ncc,li),
+                    _ => {}
+                }
+            }
+        }
+    }
+
     // Search for identifier in all children
     pub fn add_enum_def(&self, node: &AstNode, li: &mut LocalInfo) {
         let enum_type = DefType::from(node);
@@ -514,7 +1115,8 @@ impl CompLib {
                         kind     : enum_type.clone(),
                         unpacked : Vec::new(),
                         is_const : false,
-                        access   : Access::Public
+                        access   : Access::Public,
+                        rand     : RandKind::None
                     };
                     // println!("[{:?}] Adding enum : {:?}", self.name,m);
                     li.add_def(m.name.clone(),ObjDef::Member(m));
@@ -530,11 +1132,82 @@ impl CompLib {
         for n in &node.child {
             match n.kind {
                 AstNodeKind::Identifier => self.check_ident(&n,li),
+                AstNodeKind::SvaExpr => self.search_sva(n,li),
+                AstNodeKind::Wait => {
+                    self.check_cycle_delay(n,li);
+                    self.search_ident(n,li);
+                }
                 _ => if n.child.len()>0 {self.search_ident(&n,li)},
             }
         }
     }
 
+    // Cycle delay (##N statement or intra-assignment delay): relative to the default clocking
+    fn check_cycle_delay(&self, node: &AstNode, li: &LocalInfo) {
+        if node.attr.get("kind").map(|k| k.as_str())==Some("##") && self.find_def(&"!default_clocking".to_owned(),None,li,false,true,false).is_err() {
+            rpt!(MsgID::ErrInvalid, node, "Cycle delay ## used without default clocking.");
+        }
+    }
+
+    // Search for identifier and sequence/property instance in a sequence/property expression
+    pub fn search_sva(&self, node: &AstNode, li: &LocalInfo) {
+        for n in &node.child {
+            match n.kind {
+                AstNodeKind::Identifier => self.check_ident(n,li),
+                AstNodeKind::MethodCall => {
+                    match self.find_ident_def(n,li,true) {
+                        Ok(d)  => self.check_call(n,Some(d),li),
+                        Err(e) => rpt!(MsgID::ErrNotFound, n, &format!("sequence/property {}(). {}",n.attr["name"],e)),
+                    }
+                    // Check arguments only: members (e.g. triggered) are not checked
+                    for nc in n.child.iter().filter(|x| x.kind==AstNodeKind::Ports) {
+                        self.search_sva(nc,li);
+                    }
+                }
+                _ => if !n.child.is_empty() {self.search_sva(n,li)},
+            }
+        }
+    }
+
+
+    // Check identifier connected to a port or driven by a continuous assignment:
+    // when undeclared it is an implicit net, forbidden with `default_nettype none
+    // Declare a scalar net in the current scope
+    fn add_net(&self, name: &str, li: &mut LocalInfo) {
+        let m = DefMember{
+            name     : name.to_owned(),
+            kind     : DefType::IntVector(TypeIntVector {name: "logic".to_owned(), packed: None, signed: false}),
+            unpacked : Vec::new(),
+            is_const : false,
+            access   : Access::Public,
+            rand     : RandKind::None
+        };
+        li.add_def(m.name.clone(),ObjDef::Member(m));
+    }
+
+    pub fn check_implicit_net(&self, node: &AstNode, li: &mut LocalInfo) {
+        match node.kind {
+            AstNodeKind::Identifier if node.child.is_empty() => {
+                if self.find_ident_def(node,li,false).is_ok() {
+                    return;
+                }
+                let name = &node.attr["name"];
+                if self.nettype == "none" {
+                    rpt!(MsgID::ErrNotFound, node, &format!("identifier {}: implicit net not allowed with `default_nettype none",name));
+                    return;
+                }
+                rpt!(MsgID::WarnImplicitNet, node, &format!("{} {}",self.nettype,name));
+                self.add_net(name,li);
+            }
+            AstNodeKind::Identifier => self.check_ident(node,li),
+            AstNodeKind::Concat => {
+                for nc in &node.child {
+                    self.check_implicit_net(nc,li);
+                }
+            }
+            _ => self.search_ident(node,li),
+        }
+    }
 
     // TODO: evaluate a cache version of find_def
     // Find a definition from a string
@@ -542,6 +1215,8 @@ impl CompLib {
     pub fn find_def<'a>(&'a self, name: &String, scope: Option<&String>, li: &'a LocalInfo, check_base: bool, check_obj: bool, check_bind: bool) ->  Result<ObjDefParam<'a>,SvError> {
         // if name == "T_CMP" {println!("[find_def] {:?} | searching for {} : scope = {:?}, check_base={}, check_obj={}",self.cntxt,name,scope,check_base,check_obj);}
         if let Some(scope_name) = scope {
+            // $unit refers to the compilation unit of the file being checked
+            let scope_name = if scope_name=="$unit" {&self.unit} else {scope_name};
             match &self.objects.get(scope_name) {
                 Some(ObjDef::Package(di)) => {
                     if di.defs.contains_key(name) {
@@ -770,12 +1445,187 @@ impl CompLib {
 
         // Get type definition before analysing childs
         match self.get_type_def(o,li) {
-            Ok((ot,dim)) => self.check_childs(node,ot,dim,li),
+            Ok((ot,dim)) => {
+                if let ObjDef::Clocking(d) = &ot {
+                    self.check_clocking_access(node,d);
+                    return;
+                }
+                // Access through a modport: only the items of the modport are visible
+                if let Some(mp) = self.get_modport(o.unwrap(),&ot) {
+                    if !self.check_modport_access(node,mp,li) {
+                        return;
+                    }
+                }
+                self.check_childs(node,ot,dim,li)
+            }
             Err(e) => rpt!(MsgID::ErrNotFound, node, &e)
         }
 
     }
 
+    // Check the items of a modport declaration are defined in the interface
+    // Exported methods are provided by the module connected to the modport
+    fn check_modport(&self, node: &AstNode, li: &LocalInfo) {
+        for nc in &node.child {
+            let kind = match nc.kind {
+                // Port expression: check the signals used
+                AstNodeKind::Port if !nc.child.is_empty() => {
+                    let ne = &nc.child[0];
+                    if ne.kind==AstNodeKind::Identifier {self.check_ident(ne,li);}
+                    else {self.search_ident(ne,li);}
+                    continue;
+                }
+                AstNodeKind::Port => "signal",
+                AstNodeKind::Clocking => "clocking block",
+                AstNodeKind::Import if nc.attr["kind"]=="import" => "method",
+                _ => continue
+            };
+            if self.find_def(&nc.attr["name"],None,li,false,true,false).is_err() {
+                rpt!(MsgID::ErrNotFound, nc, &format!("{} {} in modport {}",kind,nc.attr["name"],node.attr["name"]));
+            }
+        }
+    }
+
+    // Return the modport definition when the object is an interface port with a modport
+    fn get_modport<'a>(&self, o: &ObjDef, ot: &'a ObjDef) -> Option<&'a DefModport> {
+        if let (ObjDef::Port(p),ObjDef::Module(od)) = (o,ot) {
+            if let PortDir::Modport(mp) = &p.dir {
+                if let Some(ObjDef::Modport(d)) = od.defs.get(mp) {
+                    return Some(d);
+                }
+            }
+        }
+        None
+    }
+
+    // Check an access through a modport: the member must be an item of the modport
+    // Return true when the access can be checked against the interface definition
+    fn check_modport_access(&self, node: &AstNode, mp: &DefModport, li: &LocalInfo) -> bool {
+        let nc = match node.child.iter().find(|x| x.kind==AstNodeKind::Identifier || x.kind==AstNodeKind::MethodCall) {
+            Some(nc) => nc,
+            None => return true
+        };
+        match mp.items.get(&nc.attr["name"]) {
+            None => {
+                rpt!(MsgID::ErrNotFound, nc, &format!("member {} in modport {}",nc.attr["name"],mp.name));
+                false
+            }
+            // Port expression: the name does not exist in the interface
+            Some(ModportItem::Port(_,Some(_))) => false,
+            // Method with a prototype: check the call against it
+            Some(ModportItem::Import(Some(m))) |
+            Some(ModportItem::Export(Some(m))) if nc.kind==AstNodeKind::MethodCall => {
+                self.check_call(nc,Some(&ObjDef::Method(m.clone())),li);
+                false
+            }
+            // Exported method: defined in the module connected to the modport
+            Some(ModportItem::Export(_)) => false,
+            _ => true
+        }
+    }
+
+    // Check the left-hand side of an assignment: inputs of a modport or of a clocking block cannot be written
+    // and clocking block signals can only be driven with a nonblocking assignment
+    fn check_write(&self, node: &AstNode, kind: &str, li: &LocalInfo) {
+        match node.kind {
+            AstNodeKind::Identifier if !node.child.is_empty() => {
+                let o = match self.find_ident_def(node,li,false) {
+                    Ok(o) => o,
+                    Err(_) => return
+                };
+                let ot = match self.get_type_def(Some(o),li) {
+                    Ok((ot,_)) => ot,
+                    Err(_) => return
+                };
+                let nc = match node.child.iter().find(|x| x.kind==AstNodeKind::Identifier) {
+                    Some(nc) => nc,
+                    None => return
+                };
+                // Clocking block signal: cb.sig
+                if let ObjDef::Clocking(d) = &ot {
+                    self.check_clocking_write(nc,d,kind);
+                    return;
+                }
+                if let Some(mp) = self.get_modport(o,&ot) {
+                    if let Some(ModportItem::Port(PortDir::Input,_)) = mp.items.get(&nc.attr["name"]) {
+                        rpt!(MsgID::ErrInvalid, nc, &format!("Cannot assign {}: input of modport {}.",nc.attr["name"],mp.name));
+                    }
+                }
+                // Clocking block of an interface: intf.cb.sig
+                if let Some(ObjDef::Clocking(d)) = self.find_def_in_obj(&ot,&nc.attr["name"],li) {
+                    if let Some(ncc) = nc.child.iter().find(|x| x.kind==AstNodeKind::Identifier) {
+                        self.check_clocking_write(ncc,d,kind);
+                    }
+                }
+            }
+            AstNodeKind::Concat => {
+                for nc in &node.child {
+                    self.check_write(nc,kind,li);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Check a clocking block declaration: signals must be declared in the scope of the clocking block
+    // A default clocking without event must refer to a clocking block of the same scope
+    fn check_clocking(&mut self, node: &AstNode, d: &DefClocking, ast_inc: & HashMap<String,Box<Ast>>, li: &mut LocalInfo) {
+        if d.event.is_none() {
+            match self.find_def(&d.name,None,li,false,true,false) {
+                Ok((ObjDef::Clocking(_),_)) => {}
+                _ => rpt!(MsgID::ErrNotFound, node, &format!("clocking block {}",d.name))
+            }
+            return;
+        }
+        let name = if d.name.is_empty() {"default"} else {&d.name};
+        li.defs.push(HashMap::new());
+        for nc in &node.child {
+            match nc.kind {
+                AstNodeKind::Sensitivity => self.search_ident(nc,li),
+                // Signal with a hierarchical expression: check the expression
+                AstNodeKind::Port if !nc.child.is_empty() => {
+                    let ne = &nc.child[0];
+                    if ne.kind==AstNodeKind::Identifier {self.check_ident(ne,li);}
+                    else {self.search_ident(ne,li);}
+                }
+                AstNodeKind::Port if self.find_def(&nc.attr["name"],None,li,false,true,false).is_err() => {
+                    rpt!(MsgID::ErrNotFound, nc, &format!("signal {} in clocking block {}",nc.attr["name"],name));
+                }
+                AstNodeKind::SvaProperty |
+                AstNodeKind::SvaSequence |
+                AstNodeKind::Let => {
+                    let m = DefMethod::from(nc);
+                    li.add_def(m.name.clone(),ObjDef::Method(m));
+                    self.check_ast(nc, ast_inc, li,true);
+                }
+                _ => {}
+            }
+        }
+        li.defs.pop();
+    }
+
+    // Check an access to a clocking block: cb.sig must be a signal or a declaration of the clocking block
+    fn check_clocking_access(&self, node: &AstNode, d: &DefClocking) {
+        if let Some(nc) = node.child.iter().find(|x| x.kind==AstNodeKind::Identifier) {
+            if !d.signals.contains_key(&nc.attr["name"]) && !d.defs.contains_key(&nc.attr["name"]) {
+                rpt!(MsgID::ErrNotFound, nc, &format!("signal {} in clocking block {}",nc.attr["name"],d.name));
+            }
+        }
+    }
+
+    // Check a write to a clocking block signal: only outputs, with a nonblocking assignment
+    fn check_clocking_write(&self, node: &AstNode, d: &DefClocking, kind: &str) {
+        match d.signals.get(&node.attr["name"]) {
+            Some(v) if v.dir==PortDir::Input => {
+                rpt!(MsgID::ErrInvalid, node, &format!("Cannot assign {}: input of clocking block {}.",node.attr["name"],d.name));
+            }
+            Some(_) if kind!="<=" => {
+                rpt!(MsgID::ErrInvalid, node, &format!("Clocking block signal {}.{} must be driven with a nonblocking assignment.",d.name,node.attr["name"]));
+            }
+            _ => {}
+        }
+    }
+
     pub fn check_childs(&self, node: &AstNode, ot: ObjDef, mut dim: Vec<SvArrayKind>, li: &LocalInfo) {
         for nc in &node.child {
             match nc.kind {
@@ -798,6 +1648,9 @@ impl CompLib {
                         rpt!(MsgID::ErrNotFound, nc, &format!("member {}",nc.attr["name"]));
                         // println!("[Linking] {:?} | Identifier {} not found in {} ({}{:?})", self.cntxt, nc.attr["name"],node.attr["name"],ot.get_typename(),dim);
                     }
+                    else if let Some(ObjDef::Clocking(d)) = cd {
+                        self.check_clocking_access(nc,d);
+                    }
                     else if nc.child.len() > 0 {
                         // let _ctd = self.get_type_def(cd,li);
                         // println!("[Linking] {:?} | Identifier {:?} has childs {:?} : \n{:#?}", self.cntxt, nc.attr["name"],nc.child, ctd);
@@ -837,6 +1690,8 @@ impl CompLib {
                     }
                 }
                 AstNodeKind::Ports => {}
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip, nc, &format!("child of {:?}", node.attr["name"]))
             }
         }
@@ -875,6 +1730,11 @@ impl CompLib {
             }
             ObjDef::Type(DefType::Enum(_),_) => if let ObjDef::Class(od) = &self.objects["enum"]  {od.defs.get(name)} else {None},
             ObjDef::Type(DefType::IntVector(_),_) => {None}
+            // Sequence methods
+            ObjDef::Method(_) if name=="triggered" || name=="matched" => if let ObjDef::Class(od) = &self.objects["event"] {od.defs.get("triggered")} else {None},
+            // Coverpoints and crosses provide the same coverage methods as the covergroup
+            ObjDef::Covergroup(d) if d.defs.contains_key(name) => d.defs.get(name),
+            ObjDef::Covergroup(d) if d.coverpoints.contains_key(name) || d.crosses.contains_key(name) => self.objects.get("covergroup"),
             ObjDef::Covergroup(_) => if let ObjDef::Class(od) = &self.objects["covergroup"]  {od.defs.get(name)} else {None},
             _ => {
                 // println!("[Linking] {:?} | Searching for {} in {:?}",self.cntxt, name, o);
@@ -1014,8 +1874,22 @@ impl CompLib {
         }
         // Instance module should appear as a top object
         // println!("[Linking] {:?} | Checking instance in {}", self.cntxt, node);
-        match self.objects.get(&node.attr["type"]) {
-            Some(ObjDef::Module(d)) => {
+        // Checker can also be declared locally or in a package
+        let d_local;
+        let def = match self.bind_inst(node) {
+            Ok(d) => Some(d),
+            // Cell defined only in libraries outside of the search list
+            Err(l) if self.cells.values().any(|c| c.contains_key(&node.attr["type"])) => {
+                rpt!(MsgID::ErrNotFound, node, &format!("module {} definition in library list {}",node.attr["type"],l.join(" ")));
+                return;
+            }
+            _ => match self.find_def(&node.attr["type"],None,li,false,true,false) {
+                Ok((ObjDef::Module(d),_)) => {d_local = d.clone(); Some(&d_local)}
+                _ => None
+            }
+        };
+        match def {
+            Some(d) => {
                 // println!("[Linking] Instance type {:?}\n\tDefinition = {:?}", node.attr["type"],d);
                 for n in &node.child {
                     let mut params : Vec<DefPort> = d.params.values().cloned().map(|x| if let ObjDef::Port(pd) = x {pd} else {unreachable!()}).collect();
@@ -1023,6 +1897,8 @@ impl CompLib {
                     match n.kind {
                         AstNodeKind::Instance => {
                             let mut ports : Vec<ObjDef> = d.ports.values().cloned().collect();
+                            // Un-named ports are connected in declaration order
+                            ports.sort_by_key(|x| if let ObjDef::Port(p) = x {p.idx} else {0});
                             for nc in &n.child {
                                 match nc.kind {
                                     AstNodeKind::Port => {
@@ -1056,9 +1932,11 @@ impl CompLib {
                                                 }
                                             }
                                         }
-                                        // Check identifiers use in binding are OK
+                                        // Check identifiers use in binding are OK, undeclared one being implicit net
                                         // TODO: check type / direction as well
-                                        self.search_ident(&nc,li);
+                                        for ncc in &nc.child {
+                                            self.check_implicit_net(ncc,li);
+                                        }
                                     }
                                     AstNodeKind::Slice => {}
                                     _ => rpt!(MsgID::DbgSkip, nc, "Instance port")
@@ -1131,6 +2009,94 @@ impl CompLib {
         }
     }
 
+    // Bind an instance to a design element: instance rule of the configuration, cell rule, default liblist
+    // and without configuration the library of the parent followed by all libraries in declaration order.
+    // Return the library list searched when no definition is found
+    fn bind_inst(&self, node: &AstNode) -> Result<&DefModule, Vec<String>> {
+        let inst = node.child.iter().find(|n| n.kind==AstNodeKind::Instance).map(|n| n.attr["name"].clone()).unwrap_or_default();
+        let paths = match self.cntxt.first() {
+            Some((_,n)) => self.inst_paths.get(&format!("{}.{}",self.lib,n)).cloned().unwrap_or_default(),
+            None => Vec::new()
+        };
+        self.resolve_cell(&self.lib, &paths, &inst, &node.attr["type"]).map(|(_,d)| d)
+    }
+
+    // Resolve the cell of instance inst in a parent of library parent_lib, instantiated at the given paths
+    fn resolve_cell(&self, parent_lib: &str, paths: &[String], inst: &str, cell: &str) -> Result<(String,&DefModule), Vec<String>> {
+        let cfg = self.config.as_ref().and_then(|c| self.configs.get(c));
+        let mut liblist = match cfg {
+            Some(c) if !c.liblist.is_empty() => c.liblist.clone(),
+            _ => {
+                let mut l = vec![parent_lib.to_owned()];
+                l.extend(self.libs.iter().filter(|x| *x!=parent_lib).cloned());
+                l
+            }
+        };
+        let mut name = cell.to_owned();
+        if let Some(cfg) = cfg {
+            // Instance rule first, then cell rule (optionally restricted to the library found by default)
+            let rule = paths.iter().find_map(|p| cfg.insts.get(&format!("{}.{}",p,inst)))
+                .or_else(|| {
+                    let lib_dflt = self.search_cell(&liblist, cell).map(|x| x.0);
+                    cfg.cells.iter().find(|(l,c,_)| c==cell && (l.is_none() || *l==lib_dflt)).map(|x| &x.2)
+                });
+            match rule {
+                Some(CfgRule::Liblist(l)) => liblist = l.clone(),
+                Some(CfgRule::Use(Some(l),c)) => {
+                    liblist = vec![l.clone()];
+                    name = c.clone();
+                }
+                Some(CfgRule::Use(None,c)) => name = c.clone(),
+                None => {}
+            }
+        }
+        self.search_cell(&liblist,&name).ok_or(liblist)
+    }
+
+    // Search a cell in a list of libraries, returning the library where it was found and its definition
+    fn search_cell(&self, liblist: &[String], cell: &str) -> Option<(String,&DefModule)> {
+        liblist.iter().find_map(|l| match self.cells.get(l).and_then(|c| c.get(cell)) {
+            Some(ObjDef::Module(d)) => Some((l.clone(),d)),
+            _ => None
+        })
+    }
+
+    // Compute the instance paths of each cell (lib.cell) under the design of the active configuration
+    // Only instances declared directly in a module body are followed
+    fn set_inst_paths(&mut self) {
+        self.inst_paths.clear();
+        let cfg = match self.config.as_ref().and_then(|c| self.configs.get(c)) {
+            Some(c) => c,
+            None => return
+        };
+        let mut paths = HashMap::new();
+        for (l,c) in &cfg.design {
+            let liblist = match l {
+                Some(l) => vec![l.clone()],
+                None => self.libs.clone()
+            };
+            if let Some((l,d)) = self.search_cell(&liblist,c) {
+                self.walk_inst(&l, d, c.clone(), &mut paths);
+            }
+        }
+        self.inst_paths = paths;
+    }
+
+    fn walk_inst(&self, lib: &str, d: &DefModule, path: String, paths: &mut HashMap<String,Vec<String>>) {
+        let v = paths.entry(format!("{}.{}",lib,d.name)).or_default();
+        // Stop on recursive instantiation
+        if v.contains(&path) || path.matches('.').count() > 64 {return;}
+        v.push(path.clone());
+        let p = [path.clone()];
+        for (inst,o) in &d.defs {
+            if let ObjDef::Instance(t) = o {
+                if let Ok((l,dc)) = self.resolve_cell(lib, &p, inst, t) {
+                    self.walk_inst(&l, dc, format!("{}.{}",path,inst), paths);
+                }
+            }
+        }
+    }
+
     // Analyze a function/task call
     pub fn check_call(&self, node: &AstNode, obj: Option<&ObjDef>, _li: &LocalInfo) {
         // if node.attr.get("name")==Some(&"from_name".to_string()) {println!("[Linking] {:?} | Checking call in {:#?}", self.cntxt, node);}
@@ -1170,7 +2136,7 @@ impl CompLib {
                     // Check if remaining ports are optional or not
                     let ma :Vec<_> = ports.iter().filter(|p| p.default.is_none()).collect();
                     if !ma.is_empty() {
-                        rpt!(MsgID::ErrArgMiss, node, &format!("{:?}", ma.iter().fold(String::new(), |acc, x| format!("{}{},", acc,x))));
+                        rpt!(MsgID::ErrArgMiss, node, &ma.iter().map(|x| x.name.clone()).collect::<Vec<_>>().join(", "));
                     }
                 }
             }
@@ -1245,3 +2211,121 @@ impl CompLib {
         // }
     }
 }
+
+// Symbol of a user-defined primitive table: level (bit mask of 0/1/x) or edge (bit mask of transitions)
+#[derive(Debug, Clone)]
+enum UdpSym {Level(u8), Edge(u16)}
+
+fn udp_level(c: char) -> Option<u8> {
+    match c {
+        '0' => Some(1),
+        '1' => Some(2),
+        'x' | 'X' => Some(4),
+        'b' | 'B' => Some(3),
+        '?' => Some(7),
+        _ => None
+    }
+}
+
+// Transitions from any value in v to any other value in w
+fn udp_edge(v: u8, w: u8) -> u16 {
+    let mut m = 0;
+    for i in 0..3 {
+        for j in 0..3 {
+            if i != j && v & (1<<i) != 0 && w & (1<<j) != 0 {
+                m |= 1 << (i*3+j);
+            }
+        }
+    }
+    m
+}
+
+// Entry of a user-defined primitive table
+#[derive(Debug, Clone)]
+struct UdpEntry {
+    inputs : Vec<UdpSym>,
+    // Current state (all values for a combinational primitive)
+    state  : u8,
+    // Output, 0 for no change (-)
+    output : u8,
+}
+
+impl UdpEntry {
+    // Decode an entry and check it against the primitive definition
+    fn new(node: &AstNode, nb_in: usize, is_seq: bool) -> Result<UdpEntry, String> {
+        let mut inputs = Vec::new();
+        let mut it = node.attr["inputs"].chars();
+        while let Some(c) = it.next() {
+            let s = match c {
+                '(' => {
+                    let e : String = it.by_ref().take_while(|x| *x != ')').collect();
+                    let l : Vec<u8> = e.chars().filter_map(udp_level).collect();
+                    let m = if l.len()==2 && e.chars().count()==2 {udp_edge(l[0],l[1])} else {0};
+                    if m == 0 {
+                        return Err(format!("Invalid edge ({}) in table entry.", e));
+                    }
+                    UdpSym::Edge(m)
+                }
+                'r' | 'R' => UdpSym::Edge(udp_edge(1,2)),
+                'f' | 'F' => UdpSym::Edge(udp_edge(2,1)),
+                'p' | 'P' => UdpSym::Edge(udp_edge(1,6) | udp_edge(4,2)),
+                'n' | 'N' => UdpSym::Edge(udp_edge(2,5) | udp_edge(4,1)),
+                '*' => UdpSym::Edge(udp_edge(7,7)),
+                _ => match udp_level(c) {
+                    Some(m) => UdpSym::Level(m),
+                    None => return Err(format!("Invalid symbol {} in table entry inputs.", c))
+                }
+            };
+            inputs.push(s);
+        }
+        if inputs.len() != nb_in {
+            return Err(format!("Table entry has {} input symbols, expecting {}.", inputs.len(), nb_in));
+        }
+        let nb_edge = inputs.iter().filter(|x| matches!(x, UdpSym::Edge(_))).count();
+        if nb_edge > 0 && !is_seq {
+            return Err("Edge symbol in table entry of a combinational primitive.".to_owned());
+        }
+        if nb_edge > 1 {
+            return Err("Table entry has more than one edge symbol.".to_owned());
+        }
+        let state = match node.attr.get("state") {
+            Some(s) if is_seq => {
+                let mut c = s.chars();
+                match (c.next().and_then(udp_level), c.next()) {
+                    (Some(m), None) => m,
+                    _ => return Err(format!("Invalid current state {} in table entry.", s))
+                }
+            }
+            Some(_) => return Err("Current state in table entry of a combinational primitive.".to_owned()),
+            None if is_seq => return Err("Missing current state in table entry of a sequential primitive.".to_owned()),
+            None => 7
+        };
+        let output = match node.attr["output"].as_ref() {
+            "0" => 1,
+            "1" => 2,
+            "x" | "X" => 4,
+            "-" if is_seq => 0,
+            o => return Err(format!("Invalid output {} in table entry.", o))
+        };
+        Ok(UdpEntry {inputs, state, output})
+    }
+
+    // Two entries conflict when they match the same inputs/state with a different output.
+    // Level and edge entries never conflict: level entries have priority.
+    fn conflicts(&self, e: &UdpEntry) -> bool {
+        let overlap = self.inputs.iter().zip(e.inputs.iter()).all(|x|
+            match x {
+                (UdpSym::Level(a), UdpSym::Level(b)) => a & b != 0,
+                (UdpSym::Edge(a), UdpSym::Edge(b))   => a & b != 0,
+                _ => false
+            });
+        let s = self.state & e.state;
+        if !overlap || s == 0 {
+            return false;
+        }
+        // No change (-) means the output is the current state
+        let o1 = if self.output==0 {s} else {self.output};
+        let o2 = if e.output==0 {s} else {e.output};
+        o1 != o2
+    }
+}
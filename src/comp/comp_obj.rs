@@ -54,48 +54,17 @@ impl ObjDef {
                 },
                 AstNodeKind::MacroCall => {},
                 AstNodeKind::Interface |
+                AstNodeKind::Program   |
+                AstNodeKind::Checker   |
+                AstNodeKind::Udp       |
                 AstNodeKind::Module => {
-                    let mut prev_dir = PortDir::Input; // Default port direction to input
-                    let mut idx_port = -1;
-                    let mut idx_param = -1;
-                    let mut d = DefModule::new(node.attr["name"].clone());
-                    // println!("[Compiling] Module {}", node.attr["name"]);
-                    for node_m in &node.child {
-                        // println!(" - {:?}", node_m.kind);
-                        match node_m.kind {
-                            AstNodeKind::Header => {
-                                for n in &node_m.child {
-                                    // println!("[ObjDef] {} | Header: {:?}",d.name, n);
-                                    match n.kind {
-                                        AstNodeKind::Port => {
-                                            let p = DefPort::new(n,&mut prev_dir,&mut idx_port);
-                                            for nc in &n.child {
-                                                if nc.kind==AstNodeKind::Identifier {
-                                                    let mut pc = p.clone();
-                                                    pc.updt(&mut idx_port,nc);
-                                                    d.ports.insert(nc.attr["name"].clone(),ObjDef::Port(pc));
-                                                }
-                                            }
-                                        }
-                                        AstNodeKind::Param => {
-                                            let p = DefPort::new(n, &mut PortDir::Param, &mut idx_param);
-                                            for nc in &n.child {
-                                                if nc.kind==AstNodeKind::Identifier {
-                                                    let mut pc = p.clone();
-                                                    pc.updt(&mut idx_port,nc);
-                                                    d.params.insert(nc.attr["name"].clone(),ObjDef::Port(pc));
-                                                }
-                                            }
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            _ => d.parse_body(&node_m,ast_inc, &mut lib.binds)
-                        }
-                    }
+                    let d = DefModule::from_ast(node, ast_inc, &mut lib.binds);
                     // println!("[ObjDef] {:?}", d);
-                    lib.objects.insert(d.name.clone(),ObjDef::Module(d));
+                    lib.add_cell(d, &ast.filename, node);
+                }
+                AstNodeKind::Config => {
+                    let d = DefConfig::from_ast(node);
+                    lib.configs.insert(d.name.clone(),d);
                 }
                 AstNodeKind::Package   => {
                     let mut d = DefPackage::new(node.attr["name"].clone());
@@ -121,7 +90,7 @@ impl ObjDef {
                         // println!("[Compiling] Top define {:#?}", node.attr);
                     }
                 }
-                // Handle special case of type/localparams/define done out of context
+                // Type/localparams/function done out of context belong to the compilation unit ($unit)
                 AstNodeKind::Param => {
                     let m = DefMember::new(node);
                     // if m.name != "" {self.defs.insert(m.name.clone(),ObjDef::Member(m.clone()));}
@@ -129,30 +98,48 @@ impl ObjDef {
                         if nc.kind==AstNodeKind::Identifier {
                             let mut mc = m.clone();
                             mc.updt(nc);
-                            lib.objects.insert(nc.attr["name"].clone(),ObjDef::Member(mc));
+                            lib.add_unit_def(nc.attr["name"].clone(),ObjDef::Member(mc));
                         }
                     }
                 }
                 AstNodeKind::Typedef => {
                     if let Some(c) = node.child.get(0) {
                         let d = DefType::from(c);
+                        // Forward declaration of a class: keep it with the class definitions
+                        if c.attr.get("type")==Some(&"class".to_string()) {
+                            lib.objects.insert(node.attr["name"].clone(),ObjDef::Type(d,Vec::new()));
+                            continue;
+                        }
                         // Add Enum value if any
                         if let DefType::Enum(te) = &d {
                             // println!("[CompLib] Typedef enum {:?}", te);
-                            for tev in te {
-                                lib.objects.insert(tev.clone(),ObjDef::EnumValue(node.attr["name"].clone()));
+                            for tev in &te.values {
+                                lib.add_unit_def(tev.clone(),ObjDef::EnumValue(node.attr["name"].clone()));
                             }
                         }
                         // Add typedef definition
-                        lib.objects.insert(node.attr["name"].clone(),ObjDef::Type(d,Vec::new()));
+                        lib.add_unit_def(node.attr["name"].clone(),ObjDef::Type(d,Vec::new()));
                     }
                 }
-                AstNodeKind::Function => {
+                AstNodeKind::Task     |
+                AstNodeKind::Function |
+                AstNodeKind::Let      => {
                     let m = DefMethod::from(node);
-                    lib.objects.insert(m.name.clone(),ObjDef::Method(m));
+                    lib.add_unit_def(m.name.clone(),ObjDef::Method(m));
+                }
+                // DPI import in the compilation unit
+                AstNodeKind::Import => {
+                    if node.attr.get("kind").map(|x| x.as_ref())==Some("import") && node.attr.contains_key("dpi") {
+                        if let Some(nc) = node.child.first() {
+                            let m = DefMethod::from(nc);
+                            lib.add_unit_def(m.name.clone(),ObjDef::Method(m));
+                        }
+                    }
                 }
-                // Temporay Whitelist
-                AstNodeKind::Import => {}
+                // Out-of-block constraint: checked with its class
+                AstNodeKind::Constraint => {}
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip,node,"Root (comp_obj)")
             }
         }
@@ -194,6 +181,50 @@ impl ObjDef {
 }
 
 impl DefModule {
+    // Create a module definition (module/interface/program/checker/primitive) from its header and body
+    pub fn from_ast(node: &AstNode, ast_inc: & HashMap<String,Box<Ast>>, binds  : &mut HashMap<String, Vec<String> >) -> DefModule {
+        let mut prev_dir = PortDir::Input; // Default port direction to input
+        let mut idx_port = -1;
+        let mut idx_param = -1;
+        let mut d = DefModule::new(node.attr["name"].clone());
+        // println!("[Compiling] Module {}", node.attr["name"]);
+        for node_m in &node.child {
+            // println!(" - {:?}", node_m.kind);
+            match node_m.kind {
+                AstNodeKind::Header => {
+                    for n in &node_m.child {
+                        // println!("[ObjDef] {} | Header: {:?}",d.name, n);
+                        match n.kind {
+                            AstNodeKind::Port => {
+                                let p = DefPort::new(n,&mut prev_dir,&mut idx_port);
+                                for nc in &n.child {
+                                    if nc.kind==AstNodeKind::Identifier {
+                                        let mut pc = p.clone();
+                                        pc.updt(&mut idx_port,nc);
+                                        d.ports.insert(nc.attr["name"].clone(),ObjDef::Port(pc));
+                                    }
+                                }
+                            }
+                            AstNodeKind::Param => {
+                                let p = DefPort::new(n, &mut PortDir::Param, &mut idx_param);
+                                for nc in &n.child {
+                                    if nc.kind==AstNodeKind::Identifier {
+                                        let mut pc = p.clone();
+                                        pc.updt(&mut idx_port,nc);
+                                        d.params.insert(nc.attr["name"].clone(),ObjDef::Port(pc));
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => d.parse_body(&node_m,ast_inc, binds)
+            }
+        }
+        d
+    }
+
     // Collect signals declaration, instance, type and function definition
     pub fn parse_body(&mut self, node: &AstNode, ast_inc: & HashMap<String,Box<Ast>>, binds  : &mut HashMap<String, Vec<String> >) {
         let mut prev_dir = PortDir::Input; // Default port direction to input
@@ -239,18 +270,18 @@ impl DefModule {
                 }
                 // Handle Non-Ansi port declaration
                 AstNodeKind::Modport => {
-                    let d : DefModport = n.child.iter().map(|x| x.attr["name"].clone()).collect();
+                    let d = DefModport::from(n);
                     self.defs.insert(n.attr["name"].clone(),ObjDef::Modport(d));
                 }
-                // Handle Non-Ansi port declaration
+                // Clocking block: default/global clocking are also registered with a special key
                 AstNodeKind::Clocking => {
-                    // TODO: update once parsing actually extract all part of the clocking block
-                    let d : DefClocking = n.child.iter()
-                                            .filter(|x| x.kind==AstNodeKind::Identifier)
-                                            .map(|x| x.attr["name"].clone())
-                                            .collect();
-                    // println!("[{:?}] Clocking {:?} : {:?} \n\t{:#?}", self.name, n.attr["name"], d,n);
-                    self.defs.insert(n.attr["name"].clone(),ObjDef::Clocking(d));
+                    let d = DefClocking::from(n);
+                    if let Some(k) = d.scope_key() {
+                        self.defs.insert(k.to_owned(),ObjDef::Clocking(d.clone()));
+                    }
+                    if !d.name.is_empty() && d.event.is_some() {
+                        self.defs.insert(d.name.clone(),ObjDef::Clocking(d));
+                    }
                 }
                 //
                 AstNodeKind::Typedef => {
@@ -259,7 +290,7 @@ impl DefModule {
                         // Add Enum value if any
                         if let DefType::Enum(te) = &d {
                             // println!("[CompLib] Typedef enum {:?}", te);
-                            for tev in te {
+                            for tev in &te.values {
                                 self.defs.insert(tev.clone(),ObjDef::EnumValue(n.attr["name"].clone()));
                             }
                         }
@@ -282,7 +313,8 @@ impl DefModule {
                                     kind: enum_type.clone(),
                                     unpacked : Vec::new(),
                                     is_const: false,
-                                    access: Access::Public
+                                    access: Access::Public,
+                                    rand  : RandKind::None
                                 };
                                 // println!("[{:?}] Adding enum : {:?}", self.name,m);
                                 self.defs.insert(m.name.clone(),ObjDef::Member(m));
@@ -302,7 +334,8 @@ impl DefModule {
                                     kind: d.clone(),
                                     unpacked : unpacked.clone(),
                                     is_const: false,
-                                    access: Access::Public
+                                    access: Access::Public,
+                                    rand  : RandKind::None
                                 };
                                 // println!("[{:?}] Adding enum : {:?}", self.name,m);
                                 self.defs.insert(m.name.clone(),ObjDef::Member(m));
@@ -382,10 +415,21 @@ impl DefModule {
                 }
                 // TODO
                 AstNodeKind::Covergroup => {
-                    let d = DefCovergroup::new(n.attr["name"].clone());
+                    let d = DefCovergroup::from(n);
                     self.defs.insert(n.attr["name"].clone(),ObjDef::Covergroup(d));
                 }
-                AstNodeKind::SvaProperty => {}
+                // Sequence, property and let are instantiated like a function call
+                AstNodeKind::SvaProperty |
+                AstNodeKind::SvaSequence |
+                AstNodeKind::Let         => {
+                    let d = DefMethod::from(n);
+                    self.defs.insert(d.name.clone(),ObjDef::Method(d));
+                }
+                // Checker are instantiated like a module
+                AstNodeKind::Checker => {
+                    let d = DefModule::from_ast(n, ast_inc, binds);
+                    self.defs.insert(d.name.clone(),ObjDef::Module(d));
+                }
                 AstNodeKind::Bind => {
                     self.parse_bind(n, binds);
                     // println!("[DefModule] {} | Bind Skipping",self.name);
@@ -399,6 +443,8 @@ impl DefModule {
                 AstNodeKind::Assign    |
                 AstNodeKind::Assert    |
                 AstNodeKind::SystemTask |
+                AstNodeKind::Specify   |
+                AstNodeKind::UdpTable  |
                 AstNodeKind::Process   => {}
                 //
                 AstNodeKind::Class => {
@@ -406,6 +452,8 @@ impl DefModule {
                     d.parse_body(&n,ast_inc);
                     self.defs.insert(d.name.clone(),ObjDef::Class(d));
                 }
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip,n,"Module top (comp_obj)")
             }
         }
@@ -497,7 +545,7 @@ impl DefPackage {
                         // Add Enum value if any
                         if let DefType::Enum(te) = &d {
                             // println!("[CompLib] Typedef enum {:?}", te);
-                            for tev in te {
+                            for tev in &te.values {
                                 self.defs.insert(tev.clone(),ObjDef::EnumValue(n.attr["name"].clone()));
                             }
                         }
@@ -520,7 +568,8 @@ impl DefPackage {
                                     kind     : enum_type.clone(),
                                     unpacked : Vec::new(),
                                     is_const : false,
-                                    access   : Access::Public
+                                    access   : Access::Public,
+                                    rand     : RandKind::None
                                 };
                                 // println!("[{:?}] Adding enum : {:?}", self.name,m);
                                 self.defs.insert(m.name.clone(),ObjDef::Member(m));
@@ -599,13 +648,26 @@ impl DefPackage {
                 // To be removed and replaced by default once eveything is working as intended
                 AstNodeKind::MacroCall => {}
                 AstNodeKind::Timescale => {}
-                AstNodeKind::SvaProperty => {}
+                AstNodeKind::SvaProperty |
+                AstNodeKind::SvaSequence |
+                AstNodeKind::Let         => {
+                    let d = DefMethod::from(n);
+                    self.defs.insert(d.name.clone(),ObjDef::Method(d));
+                }
+                AstNodeKind::Checker => {
+                    let d = DefModule::from_ast(n, ast_inc, &mut HashMap::new());
+                    self.defs.insert(d.name.clone(),ObjDef::Module(d));
+                }
                 //
                 AstNodeKind::Class => {
                     let mut d = DefClass::new(n.attr["name"].clone());
                     d.parse_body(&n,ast_inc);
                     self.defs.insert(d.name.clone(),ObjDef::Class(d));
                 }
+                // Out-of-block constraint: checked with its class
+                AstNodeKind::Constraint => {}
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip,n,"Package Top (comp_obj)")
             }
         }
@@ -657,7 +719,7 @@ impl DefClass {
                         // Add Enum value if any
                         if let DefType::Enum(te) = &d {
                             // println!("[CompLib] Typedef enum {:?}", te);
-                            for tev in te {
+                            for tev in &te.values {
                                 self.defs.insert(tev.clone(),ObjDef::EnumValue(n.attr["name"].clone()));
                             }
                         }
@@ -680,7 +742,8 @@ impl DefClass {
                                     kind     : enum_type.clone(),
                                     unpacked : Vec::new(),
                                     is_const : false,
-                                    access   : Access::Public
+                                    access   : Access::Public,
+                                    rand     : RandKind::None
                                 };
                                 // println!("[{:?}] Adding enum : {:?}", self.name,m);
                                 self.defs.insert(m.name.clone(),ObjDef::Member(m));
@@ -718,7 +781,8 @@ impl DefClass {
                                     kind : t.clone(),
                                     is_const : false,
                                     unpacked : Vec::new(),
-                                    access   : Access::Public // TODO
+                                    access   : Access::Public, // TODO
+                                    rand     : RandKind::None
                                 };
                                 self.defs.insert(m.name.clone(),ObjDef::Member(m));
                             }
@@ -758,7 +822,10 @@ impl DefClass {
                 }
                 // TODO
                 AstNodeKind::Constraint => {}
-                AstNodeKind::Covergroup => {}
+                AstNodeKind::Covergroup => {
+                    let d = DefCovergroup::from(n);
+                    self.defs.insert(n.attr["name"].clone(),ObjDef::Covergroup(d));
+                }
                 AstNodeKind::SvaProperty => {}
                 // Temporary: Whitelist node we can safely skip
                 // To be removed and replaced by default once eveything is working as intended
@@ -771,6 +838,8 @@ impl DefClass {
                     d.parse_body(&n,ast_inc);
                     self.defs.insert(d.name.clone(),ObjDef::Class(d));
                 }
+                // Syntax error: already reported by the parser
+                AstNodeKind::Error => {}
                 _ => rpt!(MsgID::DbgSkip,n,"Class top (comp_obj)")
             }
         }
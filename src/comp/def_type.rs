@@ -16,7 +16,7 @@ pub enum DefType {
     IntAtom(TypeIntAtom),
     Primary(TypePrimary),
     Struct(TypeStruct),
-    Enum(Vec<String>),
+    Enum(TypeEnum),
     VIntf(TypeVIntf),
     User(TypeUser),
     None
@@ -83,6 +83,13 @@ pub struct TypeStruct {
     pub members : Vec<ObjDef>,
 }
 
+// Enumerate: base type (int by default) and values
+#[derive(Debug, Clone)]
+pub struct TypeEnum {
+    pub base   : Box<DefType>,
+    pub values : Vec<String>,
+}
+
 
 // TODO: KeyVal is used to stored param default value: it should not be a string, but something to handle parameterized param
 #[derive(Debug, Clone)]
@@ -154,16 +161,36 @@ impl From<&AstNode> for TypeUser {
     }
 }
 
+// Packed dimension of a declaration: only bounds with a value or a name are kept in the text
+fn packed_dim(node: &AstNode) -> Option<String> {
+    node.child.iter().find(|x| x.kind==AstNodeKind::Slice).map(|s| {
+        s.child.iter()
+            .map(|x| x.attr.get("value").or_else(|| x.attr.get("name")).map_or("_",|v| v.as_ref()))
+            .collect::<Vec<&str>>()
+            .join(":")
+    })
+}
+
 impl From<&AstNode> for DefType {
     fn from(node: &AstNode) -> Self {
         // println!("[DefType] {:?}", node.kind);
         match node.kind {
-            AstNodeKind::Enum =>
-                DefType::Enum(node.child.iter()
+            AstNodeKind::Enum => {
+                let base = if node.attr.contains_key("type") {
+                    let mut nb = node.clone();
+                    nb.kind = AstNodeKind::Type;
+                    DefType::from(&nb)
+                } else {
+                    TYPE_INT
+                };
+                DefType::Enum(TypeEnum {
+                    base : Box::new(base),
+                    values : node.child.iter()
                                 .filter(|x| x.kind==AstNodeKind::EnumIdent)
                                 .map(|x| x.attr["name"].clone())
                                 .collect()
-                ),
+                })
+            }
             AstNodeKind::Struct | AstNodeKind::Union => {
                 let mut mv = Vec::new(); //Vec<ObjDef>
                 for nc in &node.child {
@@ -180,7 +207,7 @@ impl From<&AstNode> for DefType {
                     }
                 }
                 DefType::Struct(TypeStruct {
-                    is_packed : node.child.iter().find(|x| x.kind==AstNodeKind::Slice).is_some(),
+                    is_packed : node.attr.contains_key("packed"),
                     members : mv
                 })
             }
@@ -197,23 +224,24 @@ impl From<&AstNode> for DefType {
                             "bit" | "logic" | "reg" =>
                                 DefType::IntVector(TypeIntVector {
                                     name   : t.to_owned(),
-                                    packed : node.attr.get("packed").map_or(None,|x| Some(x.clone())),
+                                    packed : packed_dim(node),
                                     signed : node.attr.get("signing").map_or(false,|x| x=="signed"),
                                 }),
                             // Default type is logic
                             "" =>
                                 DefType::IntVector(TypeIntVector {
                                     name   : "logic".to_owned(),
-                                    packed : node.attr.get("packed").map_or(None,|x| Some(x.clone())),
+                                    packed : packed_dim(node),
                                     signed : node.attr.get("signing").map_or(false,|x| x=="signed"),
                                 }),
                             "genvar" => DefType::IntVector(TypeIntVector {name : "genvar".to_owned(), packed : Some("31:0".to_string()), signed : true,}),
                             // Integer Atomic type
-                            "byte"     => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Byte    , signed : node.is_signed()}),
-                            "shortint" => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Shortint, signed : node.is_signed()}),
-                            "int"      => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Int     , signed : node.is_signed()}),
-                            "longint"  => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Longint , signed : node.is_signed()}),
-                            "integer"  => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Integer , signed : node.is_signed()}),
+                            // Signed by default, except time
+                            "byte"     => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Byte    , signed : !node.is_unsigned()}),
+                            "shortint" => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Shortint, signed : !node.is_unsigned()}),
+                            "int"      => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Int     , signed : !node.is_unsigned()}),
+                            "longint"  => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Longint , signed : !node.is_unsigned()}),
+                            "integer"  => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Integer , signed : !node.is_unsigned()}),
                             "time"     => DefType::IntAtom(TypeIntAtom {name:IntAtomName::Time    , signed : node.is_signed()}),
                             // Primary type
                             "shortreal" => DefType::Primary(TypePrimary::Shortreal),
@@ -232,7 +260,7 @@ impl From<&AstNode> for DefType {
                     }
                     _ => DefType::IntVector(TypeIntVector {
                             name   : "logic".to_owned(),
-                            packed : node.attr.get("unpacked").map_or(None,|x| Some(x.clone())),
+                            packed : packed_dim(node),
                             signed : node.attr.get("signing").map_or(false,|x| x=="signed"),
                         })
                 }
@@ -152,7 +152,7 @@ pub fn get_uvm_lib() -> ObjDef {
     m = DefMethod::new("clone".to_owned(),false);
     m.ret = Some(DefType::User(TypeUser::new("uvm_object".to_owned())));
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    let mb = DefMember{ name: "__m_uvm_status_container".to_owned(), kind: DefType::User(TypeUser::new("uvm_status_container".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    let mb = DefMember{ name: "__m_uvm_status_container".to_owned(), kind: DefType::User(TypeUser::new("uvm_status_container".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     m = DefMethod::new("__m_uvm_field_automation".to_owned(),false);
     m.ports.push(DefPort{name:"tmp_data__".to_owned(), dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_object".to_owned())), idx: 0, unpacked: Vec::new(), default: None});
@@ -163,11 +163,11 @@ pub fn get_uvm_lib() -> ObjDef {
     //
     o = DefClass::new("uvm_component".to_owned());
     o.base = Some(TypeUser::new("uvm_object".to_owned())); // Not directly but no need to complexify yet
-    let mut mb = DefMember{ name: "m_name".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    let mut mb = DefMember{ name: "m_name".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "type_name".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "type_name".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "m_current_phase".to_owned(), kind: DefType::User(TypeUser::new("uvm_phase".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "m_current_phase".to_owned(), kind: DefType::User(TypeUser::new("uvm_phase".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     m = DefMethod::new("new".to_owned(),false);
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
@@ -207,7 +207,7 @@ pub fn get_uvm_lib() -> ObjDef {
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
     o = DefClass::new("uvm_top".to_owned()); // should be uvm root and uvm top a member of uvm_pkg
-    mb = DefMember{ name: "enable_print_topology".to_owned(), kind: DefType::IntVector(TypeIntVector {name: "bit".to_owned(),packed: None, signed: false}), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "enable_print_topology".to_owned(), kind: DefType::IntVector(TypeIntVector {name: "bit".to_owned(),packed: None, signed: false}), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     m = DefMethod::new("print_topology".to_owned(),false);
     m.ports.push(DefPort{name:"printer".to_owned()  ,dir:PortDir::Input, kind: DefType::User(TypeUser::new("uvm_printer".to_owned())), idx: 0, unpacked: Vec::new(), default: Some("null".to_owned())});
@@ -256,11 +256,11 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
     m.ports.push(DefPort{name:"parent".to_owned(), dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_component".to_owned())), idx: 1, unpacked: Vec::new(), default: None});
     o.defs.insert("new".to_owned(),ObjDef::Method(m));
-    mb = DefMember{ name: "req".to_owned(), kind: DefType::User(TypeUser::new("REQ".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "req".to_owned(), kind: DefType::User(TypeUser::new("REQ".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "rsp".to_owned(), kind: DefType::User(TypeUser::new("RSP".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "rsp".to_owned(), kind: DefType::User(TypeUser::new("RSP".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "seq_item_port".to_owned(), kind: DefType::User(TypeUser::new("uvm_seq_item_pull_port".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "seq_item_port".to_owned(), kind: DefType::User(TypeUser::new("uvm_seq_item_pull_port".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -289,9 +289,9 @@ pub fn get_uvm_lib() -> ObjDef {
     m = DefMethod::new("new".to_owned(),false);
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: Some("uvm_sequence".to_string())});
     o.defs.insert("new".to_owned(),ObjDef::Method(m));
-    mb = DefMember{ name: "req".to_owned(), kind: DefType::User(TypeUser::new("REQ".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "req".to_owned(), kind: DefType::User(TypeUser::new("REQ".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "rsp".to_owned(), kind: DefType::User(TypeUser::new("RSP".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "rsp".to_owned(), kind: DefType::User(TypeUser::new("RSP".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -316,7 +316,7 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"item".to_owned()  , dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_sequence_item".to_owned())), idx: 0, unpacked: Vec::new(), default: None});
     m.ports.push(DefPort{name:"set_priority".to_owned()  , dir:PortDir::Input, kind:TYPE_INT, idx: 1, unpacked: Vec::new(), default: Some("-1".to_string())});
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    mb = DefMember{ name: "do_not_randomize".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "do_not_randomize".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -332,11 +332,11 @@ pub fn get_uvm_lib() -> ObjDef {
     m = DefMethod::new("set_sequence_id".to_owned(),false);
     m.ports.push(DefPort{name:"id".to_owned()  , dir:PortDir::Input, kind:TYPE_INT, idx: 0, unpacked: Vec::new(), default: None});
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    mb = DefMember{ name: "m_parent_sequence".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "m_parent_sequence".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "m_sequencer".to_owned(), kind: DefType::User(TypeUser::new("uvm_sequencer".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "m_sequencer".to_owned(), kind: DefType::User(TypeUser::new("uvm_sequencer".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "p_sequencer".to_owned(), kind: DefType::User(TypeUser::new("uvm_sequencer".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "p_sequencer".to_owned(), kind: DefType::User(TypeUser::new("uvm_sequencer".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     m = DefMethod::new("get_response".to_owned(),false);
     m.ports.push(DefPort{name:"response".to_owned()      , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
@@ -367,7 +367,7 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
     m.ports.push(DefPort{name:"parent".to_owned(), dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_component".to_owned())), idx: 1, unpacked: Vec::new(), default: None});
     o.defs.insert("new".to_owned(),ObjDef::Method(m));
-    mb = DefMember{ name: "is_active".to_owned(), kind: DefType::User(TypeUser::new("uvm_active_passive_enum".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "is_active".to_owned(), kind: DefType::User(TypeUser::new("uvm_active_passive_enum".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -406,11 +406,11 @@ pub fn get_uvm_lib() -> ObjDef {
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
     o = DefClass::new("uvm_comparer".to_owned());
-    mb = DefMember{ name: "miscompares".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "miscompares".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "show_max".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "show_max".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "result".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "result".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -481,9 +481,9 @@ pub fn get_uvm_lib() -> ObjDef {
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
     o = DefClass::new("uvm_printer".to_owned());
-    mb = DefMember{ name: "knobs".to_owned(), kind: DefType::User(TypeUser::new("uvm_printer_knobs".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "knobs".to_owned(), kind: DefType::User(TypeUser::new("uvm_printer_knobs".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "m_string".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "m_string".to_owned(), kind: TYPE_STR, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -582,7 +582,7 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"regs".to_owned()     , dir:PortDir::Ref,   kind:DefType::User(TypeUser::new("uvm_reg".to_owned())), idx: 0, unpacked: vec![SvArrayKind::Queue], default: None});
     m.ports.push(DefPort{name:"hier".to_owned()     , dir:PortDir::Ref,   kind:DefType::User(TypeUser::new("uvm_hier_e".to_owned())), idx: 1, unpacked: vec![], default: Some("UVM_HIER".to_owned())});
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    mb = DefMember{ name: "default_map".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_map".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "default_map".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_map".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     m = DefMethod::new("get_reg_by_name".to_owned(),false);
     m.ret = Some(DefType::User(TypeUser::new("uvm_reg".to_owned())));
@@ -607,11 +607,11 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
     m.ports.push(DefPort{name:"parent".to_owned(), dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_component".to_owned())), idx: 1, unpacked: Vec::new(), default: None});
     o.defs.insert("new".to_owned(),ObjDef::Method(m));
-    mb = DefMember{ name: "reg_ap".to_owned(), kind: DefType::User(TypeUser::new("uvm_analysis_port".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "reg_ap".to_owned(), kind: DefType::User(TypeUser::new("uvm_analysis_port".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "map".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_map".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "map".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_map".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "adapter".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_adapter".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "adapter".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_adapter".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -644,12 +644,12 @@ pub fn get_uvm_lib() -> ObjDef {
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
     let mut s = TypeStruct{is_packed: false, members: Vec::new()};
-    s.members.push(ObjDef::Member(DefMember{ name: "kind".to_owned(), kind: DefType::User(TypeUser::new("uvm_access_e".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public}));
-    s.members.push(ObjDef::Member(DefMember{ name: "addr".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_addr_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public}));
-    s.members.push(ObjDef::Member(DefMember{ name: "data".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_data_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public}));
-    s.members.push(ObjDef::Member(DefMember{ name: "n_bits".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public}));
-    s.members.push(ObjDef::Member(DefMember{ name: "byte_en".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_byte_en_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public}));
-    s.members.push(ObjDef::Member(DefMember{ name: "status".to_owned(), kind: DefType::User(TypeUser::new("uvm_status_e".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public}));
+    s.members.push(ObjDef::Member(DefMember{ name: "kind".to_owned(), kind: DefType::User(TypeUser::new("uvm_access_e".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
+    s.members.push(ObjDef::Member(DefMember{ name: "addr".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_addr_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
+    s.members.push(ObjDef::Member(DefMember{ name: "data".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_data_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
+    s.members.push(ObjDef::Member(DefMember{ name: "n_bits".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
+    s.members.push(ObjDef::Member(DefMember{ name: "byte_en".to_owned(), kind: DefType::User(TypeUser::new("uvm_reg_byte_en_t".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
+    s.members.push(ObjDef::Member(DefMember{ name: "status".to_owned(), kind: DefType::User(TypeUser::new("uvm_status_e".to_owned())), unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None}));
     p.defs.insert("uvm_reg_bus_op".to_owned(),ObjDef::Type(DefType::Struct(s),Vec::new()));
     //
     o = DefClass::new("uvm_reg_data_t".to_owned());
@@ -690,9 +690,9 @@ pub fn get_uvm_lib() -> ObjDef {
     m = DefMethod::new("new".to_owned(),false);
     m.ports.push(DefPort{name:"name".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: Some("".to_owned())});
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    mb = DefMember{ name: "supports_byte_enable".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "supports_byte_enable".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "provides_responses".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public};
+    mb = DefMember{ name: "provides_responses".to_owned(), kind: TYPE_INT, unpacked : Vec::new(), is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
 
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
@@ -792,25 +792,25 @@ pub fn get_uvm_lib() -> ObjDef {
     m.ports.push(DefPort{name:"field".to_owned()  , dir:PortDir::Input, kind:TYPE_STR, idx: 0, unpacked: Vec::new(), default: None});
     m.ports.push(DefPort{name:"obj".to_owned()  , dir:PortDir::Input, kind:DefType::User(TypeUser::new("uvm_object".to_owned())), idx: 1, unpacked: Vec::new(), default: None});
     o.defs.insert(m.name.clone(),ObjDef::Method(m));
-    mb = DefMember{ name: "m_uvm_cycle_scopes".to_owned(), kind: DefType::User(TypeUser::new("uvm_object".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "m_uvm_cycle_scopes".to_owned(), kind: DefType::User(TypeUser::new("uvm_object".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "scope".to_owned(), kind: DefType::User(TypeUser::new("uvm_scope_stack".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "scope".to_owned(), kind: DefType::User(TypeUser::new("uvm_scope_stack".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "stringv".to_owned(), kind: TYPE_STR, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "stringv".to_owned(), kind: TYPE_STR, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "comparer".to_owned(), kind: DefType::User(TypeUser::new("uvm_comparer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "comparer".to_owned(), kind: DefType::User(TypeUser::new("uvm_comparer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "packer".to_owned(), kind: DefType::User(TypeUser::new("uvm_packer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "packer".to_owned(), kind: DefType::User(TypeUser::new("uvm_packer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "recorder".to_owned(), kind: DefType::User(TypeUser::new("uvm_recorder".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "recorder".to_owned(), kind: DefType::User(TypeUser::new("uvm_recorder".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "printer".to_owned(), kind: DefType::User(TypeUser::new("uvm_printer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "printer".to_owned(), kind: DefType::User(TypeUser::new("uvm_printer".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "bitstream".to_owned(), kind: DefType::User(TypeUser::new("uvm_bitstream_t".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "bitstream".to_owned(), kind: DefType::User(TypeUser::new("uvm_bitstream_t".to_owned())), unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "print_matches".to_owned(), kind: TYPE_INT, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "print_matches".to_owned(), kind: TYPE_INT, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
-    mb = DefMember{ name: "status".to_owned(), kind: TYPE_INT, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public};
+    mb = DefMember{ name: "status".to_owned(), kind: TYPE_INT, unpacked : vec![SvArrayKind::Queue], is_const: false, access: Access::Public, rand: RandKind::None};
     o.defs.insert(mb.name.clone(),ObjDef::Member(mb));
     p.defs.insert(o.name.clone(),ObjDef::Class(o));
     //
@@ -1,9 +1,9 @@
 // This file is part of sv_check and subject to the terms of MIT Licence
 // Copyright (c) 2019, clams@mail.com
 
-mod prototype;
-mod comp_obj;
-mod def_type;
+pub mod prototype;
+pub mod comp_obj;
+pub mod def_type;
 mod lib_uvm;
 mod lib_std;
 pub mod comp_lib;
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use std::fmt;
 
 use crate::ast::astnode::{AstNode,AstNodeKind};
-use crate::comp::def_type::{DefType,TypeUser};
+use crate::comp::def_type::{DefType,TypePrimary,TypeUser};
 use crate::comp::comp_obj::*;
 use crate::reporter::{REPORTER, MsgID};
 
@@ -155,8 +155,15 @@ impl DefPort {
 
 pub fn parse_dim(node : &AstNode,) -> SvArrayKind {
     // rpt!(MsgID::InfoStatus, node, &format!("{}", node));
+    // Size of constant dimensions, 0 when unknown
+    let val = |n: &AstNode| n.attr.get("value").and_then(|v| v.parse::<i64>().ok());
     if node.child.len() == 0 {SvArrayKind::Dynamic}
-    else if node.child.len() > 1 {SvArrayKind::Fixed(0)}
+    else if node.child.len() > 1 {
+        match (val(&node.child[0]),val(&node.child[1])) {
+            (Some(msb),Some(lsb)) => SvArrayKind::Fixed((msb-lsb).unsigned_abs() as u32 + 1),
+            _ => SvArrayKind::Fixed(0)
+        }
+    }
     // else if node.attr.contains_key("range") {self.unpacked.push(SvArrayKind::Fixed(0));}
     else {
         match node.child[0].kind {
@@ -165,10 +172,7 @@ pub fn parse_dim(node : &AstNode,) -> SvArrayKind {
                 // TODO: determine if the identifier is a user-type or a constant (Default to constant for the moment)
                 SvArrayKind::Fixed(0)
             }
-            AstNodeKind::Value => {
-                // TODO: try to parse the value as int
-                SvArrayKind::Fixed(0)
-            }
+            AstNodeKind::Value => SvArrayKind::Fixed(val(&node.child[0]).map_or(0,|v| v as u32)),
             AstNodeKind::Expr => {
                 if node.child[0].attr.get("value") == Some(&"$".to_string()) {
                     SvArrayKind::Queue
@@ -376,12 +380,112 @@ impl fmt::Display for DefMacro {
 
 
 // ------------------
-// Modport definition : temporary, might need more information than just name (like direction/task)
-pub type DefModport = Vec<String>;
+// Modport definition
+#[derive(Debug, Clone)]
+pub enum ModportItem {
+    // Direction and optional port expression .name(expr)
+    Port(PortDir, Option<AstNode>),
+    Clocking,
+    // Imported/exported method with optional prototype
+    Import(Option<DefMethod>),
+    Export(Option<DefMethod>),
+}
+
+#[derive(Debug, Clone)]
+pub struct DefModport {
+    pub name  : String,
+    pub items : HashMap<String,ModportItem>,
+}
+
+impl From<&AstNode> for DefModport {
+    fn from(node: &AstNode) -> Self {
+        let mut d = DefModport {name: node.attr["name"].clone(), items: HashMap::new()};
+        for nc in &node.child {
+            let name = match nc.attr.get("name") {
+                Some(n) => n.clone(),
+                None => continue
+            };
+            let item = match nc.kind {
+                AstNodeKind::Port => ModportItem::Port(str_to_dir(&nc.attr["dir"]), nc.child.first().cloned()),
+                AstNodeKind::Clocking => ModportItem::Clocking,
+                AstNodeKind::Import => {
+                    let m = nc.child.first().map(DefMethod::from);
+                    if nc.attr["kind"]=="export" {ModportItem::Export(m)} else {ModportItem::Import(m)}
+                }
+                _ => continue
+            };
+            d.items.insert(name,item);
+        }
+        d
+    }
+}
 
 // ------------------
-// Clocking definition : temporary, might need more information than just name (like direction)
-pub type DefClocking = Vec<String>;
+// Clocking block definition
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DefClockvar {
+    pub dir         : PortDir,
+    pub input_skew  : Option<String>,
+    pub output_skew : Option<String>,
+    // Hierarchical expression (name = expr)
+    pub expr        : Option<AstNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DefClocking {
+    pub name        : String,
+    pub is_default  : bool,
+    pub is_global   : bool,
+    // Clocking event: none for a default clocking referring to another clocking block
+    pub event       : Option<AstNode>,
+    pub input_skew  : String,
+    pub output_skew : String,
+    pub signals     : HashMap<String,DefClockvar>,
+    // Property/sequence/let declared in the clocking block
+    pub defs        : HashMap<String,ObjDef>,
+}
+
+impl From<&AstNode> for DefClocking {
+    fn from(node: &AstNode) -> Self {
+        let scope = node.attr.get("scope").map(|x| x.as_ref());
+        let mut d = DefClocking {
+            name        : node.attr.get("name").cloned().unwrap_or_default(),
+            is_default  : scope == Some("default"),
+            is_global   : scope == Some("global"),
+            event       : node.child.iter().find(|x| x.kind==AstNodeKind::Sensitivity).cloned(),
+            // Default skews when not specified: sample in the preponed region, drive without delay
+            input_skew  : node.attr.get("input_skew").cloned().unwrap_or_else(|| "#1step".to_owned()),
+            output_skew : node.attr.get("output_skew").cloned().unwrap_or_else(|| "#0".to_owned()),
+            signals     : HashMap::new(),
+            defs        : HashMap::new(),
+        };
+        for nc in node.child.iter().filter(|x| x.kind==AstNodeKind::SvaProperty || x.kind==AstNodeKind::SvaSequence || x.kind==AstNodeKind::Let) {
+            let m = DefMethod::from(nc);
+            d.defs.insert(m.name.clone(),ObjDef::Method(m));
+        }
+        for nc in node.child.iter().filter(|x| x.kind==AstNodeKind::Port) {
+            let dir = str_to_dir(&nc.attr["dir"]);
+            let v = DefClockvar {
+                input_skew  : if dir==PortDir::Output {None} else {Some(nc.attr.get("input_skew").unwrap_or(&d.input_skew).clone())},
+                output_skew : if dir==PortDir::Input  {None} else {Some(nc.attr.get("output_skew").unwrap_or(&d.output_skew).clone())},
+                expr        : nc.child.first().cloned(),
+                dir,
+            };
+            d.signals.insert(nc.attr["name"].clone(),v);
+        }
+        d
+    }
+}
+
+impl DefClocking {
+    // Key used to register a default/global clocking in its scope
+    pub fn scope_key(&self) -> Option<&'static str> {
+        if self.is_default {Some("!default_clocking")}
+        else if self.is_global {Some("!global_clocking")}
+        else {None}
+    }
+}
 
 // ------------------
 // Package definition
@@ -419,11 +523,17 @@ impl DefBlock {
 }
 
 // ------------------
-// Package definition
+// Covergroup definition
 #[derive(Debug, Clone)]
 pub struct DefCovergroup {
     pub name       : String,
     pub defs: HashMap<String,ObjDef>,
+    pub ports      : Vec<DefPort>,
+    // Coverpoints and crosses with the name of their bins
+    pub coverpoints: HashMap<String,Vec<String>>,
+    pub crosses    : HashMap<String,Vec<String>>,
+    // Options (option.x and type_option.x) with their value
+    pub options    : HashMap<String,String>,
 }
 
 impl DefCovergroup {
@@ -431,7 +541,79 @@ impl DefCovergroup {
         DefCovergroup {
             name,
             defs: HashMap::new(),
+            ports: Vec::new(),
+            coverpoints: HashMap::new(),
+            crosses: HashMap::new(),
+            options: HashMap::new(),
+        }
+    }
+}
+
+impl From<&AstNode> for DefCovergroup {
+    fn from(node: &AstNode) -> Self {
+        let mut d = DefCovergroup::new(node.attr["name"].clone());
+        let mut prev_dir = PortDir::Input; // Default port direction to input
+        let mut prev_idx = -1;
+        for nc in &node.child {
+            match nc.kind {
+                // Sample method with arguments overrides the default one
+                AstNodeKind::Ports if nc.attr.contains_key("name") => {
+                    let mut m = DefMethod::new("sample".to_owned(),false);
+                    m.ret = Some(DefType::Primary(TypePrimary::Void));
+                    let mut dir = PortDir::Input;
+                    let mut idx = -1;
+                    for np in &nc.child {
+                        let p = DefPort::new(np,&mut dir, &mut idx);
+                        for npc in &np.child {
+                            if npc.kind==AstNodeKind::Identifier {
+                                let mut pc = p.clone();
+                                pc.updt(&mut idx,npc);
+                                m.ports.push(pc);
+                            }
+                        }
+                    }
+                    d.defs.insert(m.name.clone(),ObjDef::Method(m));
+                }
+                AstNodeKind::Ports => {
+                    for np in &nc.child {
+                        let p = DefPort::new(np,&mut prev_dir, &mut prev_idx);
+                        for npc in &np.child {
+                            if npc.kind==AstNodeKind::Identifier {
+                                let mut pc = p.clone();
+                                pc.updt(&mut prev_idx,npc);
+                                d.ports.push(pc);
+                            }
+                        }
+                    }
+                }
+                AstNodeKind::Coverpoint |
+                AstNodeKind::CoverCross => {
+                    // Unnamed coverpoint on an expression cannot be referenced
+                    if let Some(name) = nc.attr.get("name") {
+                        let bins = nc.child.iter()
+                                    .filter(|x| x.kind==AstNodeKind::CoverBins)
+                                    .map(|x| x.attr["name"].clone())
+                                    .collect();
+                        if nc.kind==AstNodeKind::Coverpoint {
+                            d.coverpoints.insert(name.clone(),bins);
+                        } else {
+                            d.crosses.insert(name.clone(),bins);
+                        }
+                    }
+                }
+                AstNodeKind::CoverOption => {
+                    // Only keep simple values
+                    let v = match nc.child.first() {
+                        Some(x) if x.kind==AstNodeKind::Value => x.attr["value"].clone(),
+                        Some(x) if x.kind==AstNodeKind::Identifier => x.attr["name"].clone(),
+                        _ => "".to_owned()
+                    };
+                    d.options.insert(format!("{}.{}",nc.attr["kind"],nc.attr["name"]),v);
+                }
+                _ => {}
+            }
         }
+        d
     }
 }
 
@@ -456,6 +638,53 @@ impl DefModule {
     }
 }
 
+// ------------------
+// Configuration definition
+
+// Expansion clause of an instance/cell rule: library list or explicit binding [lib.]cell
+#[derive(Debug, Clone)]
+pub enum CfgRule {
+    Liblist(Vec<String>),
+    Use(Option<String>,String),
+}
+
+impl CfgRule {
+    pub fn new(node: &AstNode) -> CfgRule {
+        match node.attr.get("use") {
+            Some(c) => CfgRule::Use(node.attr.get("use_lib").cloned(),c.clone()),
+            None => CfgRule::Liblist(node.attr.get("liblist").map(|l| l.split_whitespace().map(|x| x.to_owned()).collect()).unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefConfig {
+    pub name    : String,
+    // Top cells with optional library
+    pub design  : Vec<(Option<String>,String)>,
+    pub liblist : Vec<String>,
+    // Rules indexed by the full instance path
+    pub insts   : HashMap<String,CfgRule>,
+    // Cell rules with optional library
+    pub cells   : Vec<(Option<String>,String,CfgRule)>,
+}
+
+impl DefConfig {
+    pub fn from_ast(node: &AstNode) -> DefConfig {
+        let mut d = DefConfig {name: node.attr["name"].clone(), design: Vec::new(), liblist: Vec::new(), insts: HashMap::new(), cells: Vec::new()};
+        for nc in &node.child {
+            match nc.attr.get("kind").map(|x| x.as_ref()) {
+                Some("design")   => d.design.push((nc.attr.get("lib").cloned(),nc.attr["cell"].clone())),
+                Some("default")  => if let CfgRule::Liblist(l) = CfgRule::new(nc) {d.liblist = l},
+                Some("instance") => {d.insts.insert(nc.attr["path"].clone(),CfgRule::new(nc));}
+                Some("cell")     => d.cells.push((nc.attr.get("lib").cloned(),nc.attr["cell"].clone(),CfgRule::new(nc))),
+                _ => {}
+            }
+        }
+        d
+    }
+}
+
 // ------------------
 // Class definition
 
@@ -463,6 +692,9 @@ impl DefModule {
 #[derive(Debug, Clone)]
 pub enum Access {Public, Protected, Local}
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum RandKind {None, Rand, RandC}
+
 // Parameter
 #[derive(Debug, Clone)]
 pub struct DefMember {
@@ -471,6 +703,7 @@ pub struct DefMember {
     pub is_const : bool,
     pub unpacked : Vec<SvArrayKind>,
     pub access   : Access,
+    pub rand     : RandKind,
 }
 
 impl DefMember {
@@ -482,7 +715,12 @@ impl DefMember {
             kind     : DefType::from(node),
             is_const : node.kind==AstNodeKind::Param, // TODO
             unpacked : Vec::new(),
-            access   : Access::Public // TODO
+            access   : Access::Public, // TODO
+            rand     : match node.attr.get("rand").map(|r| r.as_str()) {
+                Some("rand")  => RandKind::Rand,
+                Some("randc") => RandKind::RandC,
+                _ => RandKind::None
+            }
         }
     }
 
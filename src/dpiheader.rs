@@ -0,0 +1,372 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use std::{
+    collections::{HashMap,HashSet},
+    io::{self,Write},
+};
+
+use crate::ast::{Ast, astnode::{AstNode,AstNodeKind}};
+use crate::comp::{
+    comp_lib::CompLib,
+    comp_obj::ObjDef,
+    def_type::{DefType,IntAtomName,TypePrimary,TypeStruct,TypeUser},
+    prototype::SvArrayKind,
+};
+use crate::reporter::{REPORTER, MsgID};
+
+/// Write a C header with the prototypes of all DPI-C imported and exported functions/tasks,
+/// using the types of svdpi.h (similar to the dpiheader option of simulators).
+/// User types are resolved through the library: unpacked structures are declared as C structures.
+pub fn write_dpi_header(ast_list: &[Ast], ast_inc: &HashMap<String,Box<Ast>>, lib: &CompLib, w: &mut dyn Write) -> io::Result<()> {
+    let mut h = DpiHeader::new(lib, ast_inc);
+    for ast in ast_list {
+        rpt_set_fname!(&ast.filename);
+        h.unit = lib.unit_name(&ast.filename);
+        h.imports.clear();
+        h.collect(&ast.tree);
+    }
+    writeln!(w, "/* DPI-C prototypes generated by sv_check */")?;
+    writeln!(w, "#ifndef SV_CHECK_DPI_H")?;
+    writeln!(w, "#define SV_CHECK_DPI_H\n")?;
+    writeln!(w, "#include \"svdpi.h\"\n")?;
+    writeln!(w, "#ifdef __cplusplus\nextern \"C\" {{\n#endif\n")?;
+    for s in &h.structs {
+        writeln!(w, "{}\n", s)?;
+    }
+    for (title,protos) in [("Imported",h.dpi_imports),("Exported",h.dpi_exports)] {
+        if protos.is_empty() {continue;}
+        writeln!(w, "/* {} functions/tasks */", title)?;
+        // Same C function can be declared in several scopes
+        let mut done = HashSet::new();
+        for p in protos {
+            if done.insert(p.clone()) {
+                writeln!(w, "extern {};", p)?;
+            }
+        }
+        writeln!(w)?;
+    }
+    writeln!(w, "#ifdef __cplusplus\n}}\n#endif\n")?;
+    writeln!(w, "#endif")?;
+    Ok(())
+}
+
+// Error while mapping a type to C: message ID and text
+type CTypeError = (MsgID,String);
+
+struct DpiHeader<'a> {
+    lib         : &'a CompLib,
+    ast_inc     : &'a HashMap<String,Box<Ast>>,
+    // Compilation unit, enclosing design elements and imported packages (package, name or *)
+    unit        : String,
+    scopes      : Vec<String>,
+    imports     : Vec<(String,String)>,
+    inc_done    : HashSet<String>,
+    dpi_imports : Vec<String>,
+    dpi_exports : Vec<String>,
+    // C declaration of unpacked structures, in dependency order
+    structs     : Vec<String>,
+    struct_done : HashSet<String>,
+}
+
+impl<'a> DpiHeader<'a> {
+
+    fn new(lib: &'a CompLib, ast_inc: &'a HashMap<String,Box<Ast>>) -> DpiHeader<'a> {
+        DpiHeader {lib, ast_inc, unit: String::new(), scopes: Vec::new(), imports: Vec::new(), inc_done: HashSet::new(),
+            dpi_imports: Vec::new(), dpi_exports: Vec::new(), structs: Vec::new(), struct_done: HashSet::new()}
+    }
+
+    // Collect the C prototypes of DPI imports and exports of a scope
+    fn collect(&mut self, node: &AstNode) {
+        for nc in &node.child {
+            match nc.kind {
+                AstNodeKind::Import if nc.attr.contains_key("dpi") => {
+                    if nc.attr["kind"]=="import" {
+                        if let Some(m) = nc.child.first() {
+                            if let Some(p) = self.c_prototype(m, nc.attr.get("c_name")) {
+                                self.dpi_imports.push(p);
+                            }
+                        }
+                    }
+                    // Export: prototype from the function/task defined in the same scope
+                    else if let Some(m) = node.child.iter().find(|x| (x.kind==AstNodeKind::Function || x.kind==AstNodeKind::Task) && x.attr.get("name")==nc.attr.get("name")) {
+                        if let Some(p) = self.c_prototype(m, nc.attr.get("c_name")) {
+                            self.dpi_exports.push(p);
+                        }
+                    }
+                }
+                // Package import: used to resolve user types
+                AstNodeKind::Import => {
+                    for ncc in nc.child.iter().filter(|x| x.attr.contains_key("pkg_name")) {
+                        self.imports.push((ncc.attr["pkg_name"].clone(), ncc.attr.get("name").cloned().unwrap_or_default()));
+                    }
+                }
+                AstNodeKind::Directive => {
+                    if let Some(i) = nc.attr.get("include") {
+                        if let Some(a) = self.ast_inc.get(i) {
+                            if self.inc_done.insert(i.clone()) {
+                                rpt_push_fname!(&a.filename);
+                                self.collect(&a.tree);
+                                rpt_pop_fname!();
+                            }
+                        }
+                    }
+                }
+                // No DPI declaration inside a method
+                AstNodeKind::Function | AstNodeKind::Task => {}
+                // Design element: new scope for type resolution, imports are local to it
+                AstNodeKind::Module  | AstNodeKind::Interface | AstNodeKind::Program |
+                AstNodeKind::Package | AstNodeKind::Class     | AstNodeKind::Checker if nc.attr.contains_key("name") => {
+                    let nb_imports = self.imports.len();
+                    self.scopes.push(nc.attr["name"].clone());
+                    self.collect(nc);
+                    self.scopes.pop();
+                    self.imports.truncate(nb_imports);
+                }
+                _ => self.collect(nc)
+            }
+        }
+    }
+
+    // C prototype of a function/task: tasks return an int (disable status)
+    // Return None when a type cannot be mapped (error reported)
+    fn c_prototype(&mut self, node: &AstNode, c_name: Option<&String>) -> Option<String> {
+        let name = c_name.unwrap_or(&node.attr["name"]);
+        let nt = node.child.iter().find(|x| x.kind==AstNodeKind::Type);
+        let ret = match nt {
+            _ if node.kind==AstNodeKind::Task => Ok("int".to_owned()),
+            Some(t) => self.c_type(t, "", None),
+            None => Ok("svLogic".to_owned()),
+        };
+        let mut ok = self.report(nt.unwrap_or(node), ret.as_ref().err());
+        let mut args = Vec::new();
+        let mut dir = "input".to_owned();
+        for np in node.child.iter().filter(|x| x.kind==AstNodeKind::Ports).flat_map(|x| x.child.iter()) {
+            // Direction is inherited from the previous argument
+            if let Some(d) = np.attr.get("dir") {dir = d.clone();}
+            for ni in np.child.iter().filter(|x| x.kind==AstNodeKind::Identifier) {
+                // Unpacked dimension: open array [] or fixed size
+                let array = ni.child.iter().find(|x| x.kind==AstNodeKind::Slice).map(|x| x.child.is_empty());
+                match self.c_type(np, &dir, array) {
+                    Ok(t) => args.push(format!("{} {}", t, ni.attr["name"])),
+                    Err(e) => ok &= self.report(ni, Some(&e)),
+                }
+            }
+        }
+        if !ok {
+            return None;
+        }
+        if args.is_empty() {
+            args.push("void".to_owned());
+        }
+        Some(format!("{} {}({})", ret.unwrap_or_default(), name, args.join(", ")))
+    }
+
+    fn report(&self, node: &AstNode, e: Option<&CTypeError>) -> bool {
+        match e {
+            Some((id,msg)) => {rpt!(id.clone(), node, msg); false}
+            None => true
+        }
+    }
+
+    // C type of an argument (or of the return value when dir is empty)
+    fn c_type(&mut self, node: &AstNode, dir: &str, array: Option<bool>) -> Result<String,CTypeError> {
+        let is_input = dir == "input";
+        // Open array: passed with a handle
+        if array == Some(true) {
+            return Ok(if is_input {"const svOpenArrayHandle".to_owned()} else {"svOpenArrayHandle".to_owned()});
+        }
+        let (t,dims,name) = self.resolve(DefType::from(node))?;
+        // Array defined by a typedef: same as a fixed-size array
+        let array = if array.is_none() && !dims.is_empty() {Some(false)} else {array};
+        let (base, by_ptr) = match &t {
+            // Unpacked structure: declared in the header and passed by pointer
+            DefType::Struct(s) if !s.is_packed => {
+                let name = name.ok_or((MsgID::ErrInvalid, "DPI header: anonymous unpacked structure must be declared with a typedef".to_owned()))?;
+                if dir.is_empty() {
+                    return Err((MsgID::ErrInvalid, format!("DPI header: unpacked structure {} cannot be returned", name)));
+                }
+                self.c_struct(&name, s)?;
+                (name, true)
+            }
+            _ => self.c_base(&t)?
+        };
+        let cst = if is_input {"const "} else {""};
+        // Fixed-size unpacked array: pointer on the first element
+        // Vectors and structures are always passed by pointer
+        Ok(if array.is_some() || (by_ptr && !dir.is_empty()) {
+            format!("{}{}*", cst, base)
+        } else if is_input || dir.is_empty() {
+            base
+        } else {
+            format!("{}*", base)
+        })
+    }
+
+    // C type of an SV type and whether it is passed by pointer (packed vectors)
+    fn c_base(&self, t: &DefType) -> Result<(String,bool),CTypeError> {
+        let base = match t {
+            DefType::IntVector(x) if x.name=="bit" => if x.packed.is_some() {"svBitVecVal"} else {"svBit"},
+            DefType::IntVector(x) if x.name=="genvar" => "int",
+            DefType::IntVector(x) => if x.packed.is_some() {"svLogicVecVal"} else {"svLogic"},
+            DefType::IntAtom(x) => match x.name {
+                IntAtomName::Byte     => if x.signed {"char"} else {"unsigned char"},
+                IntAtomName::Shortint => if x.signed {"short"} else {"unsigned short"},
+                IntAtomName::Int      => if x.signed {"int"} else {"unsigned int"},
+                IntAtomName::Longint  => if x.signed {"long long"} else {"unsigned long long"},
+                IntAtomName::Integer |
+                IntAtomName::Time     => "svLogicVecVal",
+            },
+            DefType::Primary(TypePrimary::Real)      |
+            DefType::Primary(TypePrimary::Realtime)  => "double",
+            DefType::Primary(TypePrimary::Shortreal) => "float",
+            DefType::Primary(TypePrimary::Str)       => "const char*",
+            DefType::Primary(TypePrimary::CHandle)   => "void*",
+            DefType::Primary(TypePrimary::Void)      => "void",
+            // Enum: same as its base type
+            DefType::Enum(e) => return self.c_base(&self.resolve(*e.base.clone())?.0),
+            // Packed structure/union: vector of 2-state or 4-state values
+            DefType::Struct(_) => if self.is_2state(t)? {"svBitVecVal"} else {"svLogicVecVal"},
+            _ => return Err((MsgID::ErrInvalid, format!("DPI header: no C equivalent for type {}", t)))
+        };
+        Ok((base.to_owned(), base=="svBitVecVal" || base=="svLogicVecVal"))
+    }
+
+    // Check if a packed type only contains 2-state values
+    fn is_2state(&self, t: &DefType) -> Result<bool,CTypeError> {
+        Ok(match t {
+            DefType::IntVector(x) => x.name=="bit",
+            DefType::IntAtom(x) => !matches!(x.name, IntAtomName::Integer | IntAtomName::Time),
+            DefType::Enum(e) => self.is_2state(&self.resolve(*e.base.clone())?.0)?,
+            DefType::Struct(s) => {
+                for m in &s.members {
+                    if let ObjDef::Member(m) = m {
+                        if !self.is_2state(&self.resolve(m.kind.clone())?.0)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+                true
+            }
+            _ => false
+        })
+    }
+
+    // Declare an unpacked structure as a C structure (once)
+    fn c_struct(&mut self, name: &str, s: &TypeStruct) -> Result<(),CTypeError> {
+        if !self.struct_done.insert(name.to_owned()) {
+            return Ok(());
+        }
+        let mut fields = Vec::new();
+        for m in &s.members {
+            let m = match m {
+                ObjDef::Member(m) => m,
+                _ => continue
+            };
+            let (t,mut dims,tname) = self.resolve(m.kind.clone())?;
+            dims.extend(m.unpacked.iter().cloned());
+            let mut field = match &t {
+                DefType::Struct(s) if !s.is_packed => {
+                    let tname = tname.ok_or((MsgID::ErrInvalid, format!("DPI header: anonymous unpacked structure in member {} of {}", m.name, name)))?;
+                    self.c_struct(&tname, s)?;
+                    format!("{} {}", tname, m.name)
+                }
+                _ => {
+                    let (base,is_vector) = self.c_base(&t)?;
+                    if is_vector {
+                        let w = packed_width(&t).ok_or((MsgID::ErrInvalid, format!("DPI header: unknown width for member {} of {}", m.name, name)))?;
+                        format!("{} {}[SV_PACKED_DATA_NELEMS({})]", base, m.name, w)
+                    } else {
+                        format!("{} {}", base, m.name)
+                    }
+                }
+            };
+            for d in dims {
+                match d {
+                    SvArrayKind::Fixed(n) if n>0 => field.push_str(&format!("[{}]", n)),
+                    _ => return Err((MsgID::ErrInvalid, format!("DPI header: member {} of {} must have a constant size", m.name, name)))
+                }
+            }
+            fields.push(format!("    {};", field));
+        }
+        self.structs.push(format!("typedef struct {{\n{}\n}} {};", fields.join("\n"), name));
+        Ok(())
+    }
+
+    // Follow typedefs through the library: type, unpacked dimensions and name of the last typedef
+    fn resolve(&self, t: DefType) -> Result<(DefType,Vec<SvArrayKind>,Option<String>),CTypeError> {
+        let mut t = t;
+        let mut dims = Vec::new();
+        let mut name = None;
+        // Limit the depth in case of recursive definition
+        for _ in 0..16 {
+            let u = match &t {
+                DefType::User(u) => u,
+                _ => return Ok((t,dims,name))
+            };
+            match self.find_type(u) {
+                Some(ObjDef::Type(d,dd)) => {
+                    name = Some(u.name.clone());
+                    dims.extend(dd.iter().cloned());
+                    t = d.clone();
+                }
+                Some(_) => return Err((MsgID::ErrInvalid, format!("DPI header: type {} has no C equivalent", u.name))),
+                None => return Err((MsgID::ErrNotFound, format!("type {}", u.name)))
+            }
+        }
+        Err((MsgID::ErrInvalid, format!("DPI header: recursive definition of type {}", name.unwrap_or_default())))
+    }
+
+    // Find a type definition: explicit scope, enclosing design elements, imported packages then compilation unit
+    fn find_type(&self, u: &TypeUser) -> Option<&'a ObjDef> {
+        let lib = self.lib;
+        if let Some(s) = &u.scope {
+            let s = if s=="$unit" {&self.unit} else {s};
+            return lib.objects.get(s).and_then(|o| o.get_def(&u.name));
+        }
+        let mut objs : Vec<&ObjDef> = Vec::new();
+        for s in &self.scopes {
+            // Class defined in a package
+            if let Some(o) = objs.last().and_then(|p| p.get_def(s)).or_else(|| lib.objects.get(s)) {
+                objs.push(o);
+            }
+        }
+        if let Some(d) = objs.iter().rev().find_map(|o| o.get_def(&u.name)) {
+            return Some(d);
+        }
+        for (p,n) in self.imports.iter().rev() {
+            if n=="*" || n==&u.name {
+                if let Some(d) = lib.objects.get(p).and_then(|o| o.get_def(&u.name)) {
+                    return Some(d);
+                }
+            }
+        }
+        lib.objects.get(&self.unit).and_then(|o| o.get_def(&u.name))
+    }
+}
+
+// Width of a packed type: only known for constant dimensions
+fn packed_width(t: &DefType) -> Option<u32> {
+    match t {
+        DefType::IntVector(x) => {
+            match &x.packed {
+                None => Some(1),
+                Some(p) => {
+                    let mut b = p.split(':').map(|v| v.parse::<i64>().ok());
+                    match (b.next()?, b.next()?) {
+                        (Some(msb),Some(lsb)) => Some((msb-lsb).unsigned_abs() as u32 + 1),
+                        _ => None
+                    }
+                }
+            }
+        }
+        DefType::IntAtom(x) => match x.name {
+            IntAtomName::Byte => Some(8),
+            IntAtomName::Shortint => Some(16),
+            IntAtomName::Int | IntAtomName::Integer => Some(32),
+            IntAtomName::Longint | IntAtomName::Time => Some(64),
+        },
+        DefType::Enum(e) => packed_width(&e.base),
+        _ => None
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright (c) 2019, clams@mail.com
 
 use crate::lex::position::Position;
-use crate::lex::token::{Token, TokenKind};
+use crate::lex::token::{Token, TokenKind, TokenValue};
 use std::fmt;
 
 #[allow(dead_code)]
@@ -11,6 +11,7 @@ pub enum SvErrorKind {
     Null,
     Io,
     Include,
+    IncCycle,
     Eof,
     Token,
     Syntax,
@@ -19,7 +20,7 @@ pub enum SvErrorKind {
     // NotSupported,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SvError {
     pub kind: SvErrorKind,
     pub token: Token,
@@ -49,7 +50,7 @@ impl SvError {
         SvError {kind:SvErrorKind::Scope, token: Token::new(TokenKind::EOF, "".to_owned(), Position::new()), txt: txt.to_string()}
     }
 
-    pub fn token(pos: Position, s: String) -> SvError {
+    pub fn token<V: Into<TokenValue>>(pos: Position, s: V) -> SvError {
         SvError {
             kind:SvErrorKind::Syntax,
             token: Token::new(TokenKind::Unknown, s, pos),
@@ -69,6 +70,7 @@ impl fmt::Display for SvError {
             SvErrorKind::Null         => write!(f, " | End of file reached."),
             SvErrorKind::Io           => write!(f, " {}", self.txt),
             SvErrorKind::Include      => write!(f, ":{} | File {} not found", self.token.pos, self.token.value),
+            SvErrorKind::IncCycle     => write!(f, ":{} | Recursive include of {} : {}", self.token.pos, self.token.value, self.txt),
             SvErrorKind::Eof          => write!(f, ":{} | Unexpected end of file !", self.token.pos),
             SvErrorKind::Token        => write!(f, ":{} | Unable to parse token \"{}\" !",self.token.pos, self.token.value),
             SvErrorKind::Syntax       => write!(f, ":{} | Unexpected '{}' ({}) in {} !",self.token.pos, self.token.value, self.token.kind, self.txt),
@@ -5,16 +5,27 @@ use std::fmt;
 
 /// Structure holding source code to parse with function to read char by char
 ///  and keeping information on current position in line/column.
-#[derive(Debug, Copy, Clone)]
+/// Column are 1-based (0 meaning start of line, before any character)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Position {
     pub line    : u32,
     pub col     : u32,
+    /// Byte offset of the character in the file
+    pub byte    : u32,
+    /// Column counted in UTF-8 bytes
+    pub col_u8  : u32,
+    /// Column counted in UTF-16 code units (as used by the LSP)
+    pub col_u16 : u32,
 }
 
 impl Position {
     /// Create a new pposition initialize to 0,0
     pub fn new() -> Self {
-        Position {line:1, col: 0}
+        Position {line:1, col: 0, byte: 0, col_u8: 0, col_u16: 0}
+    }
+    /// Position from a line/column, for code not read from a file
+    pub fn at(line: u32, col: u32) -> Self {
+        Position {line, col, byte: 0, col_u8: col, col_u16: col}
     }
     /// Increment the current position
     pub fn incr(&mut self, c: char) {
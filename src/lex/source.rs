@@ -1,9 +1,10 @@
 // This file is part of sv_check and subject to the terms of MIT Licence
 // Copyright (c) 2019, clams@mail.com
 
-use crate::lex::position::Position;
+use crate::lex::{position::Position, token::TokenValue};
 
-use std::{fs,path,io, mem, str, iter};
+use std::{fs,path,io};
+use std::rc::Rc;
 
 #[cfg(not(target_os = "windows"))]
 pub fn path_display<P: AsRef<path::Path>>(p: P) -> String {
@@ -27,12 +28,19 @@ pub fn path_display<P: AsRef<path::Path>>(p: P) -> String {
 pub struct Source {
     /// filename used to initialize the code
     pub filename : path::PathBuf,
-    /// String representing the source code to analyze
-    _code : String,
-    /// Current position in the code
+    /// String representing the source code to analyze (shared with the reporter)
+    code : Rc<str>,
+    /// Byte index of the next character to read
+    idx : usize,
+    /// Current position in the code (last character read)
     pub pos : Position,
-    // // Character iterator
-    chars : iter::Peekable<str::Chars<'static>>
+    /// Position of the character before the last one read
+    pub prev_pos : Position,
+    /// UTF-8/UTF-16 column of the next character
+    next_u8 : u32,
+    next_u16 : u32,
+    /// Filename set by a `line directive
+    line_fname : Option<path::PathBuf>,
 }
 
 impl Source {
@@ -40,24 +48,75 @@ impl Source {
     /// Create a Source struct from a file.
     /// Return an io error if unable to open the file
     pub fn from_file(filename: path::PathBuf) -> Result<Source,io::Error>  {
-        let _code = fs::read_to_string(&filename)?;
-        let chars = unsafe { mem::transmute(_code.chars().peekable()) };
+        let code = fs::read_to_string(&filename)?;
+        Ok(Source::from_string(filename, code))
+    }
+
+    /// Create a Source struct from a string (e.g. value of a command line define, unsaved editor buffer)
+    pub fn from_string(filename: path::PathBuf, code: String) -> Source  {
         let pos = Position::new();
-        Ok(Source {filename, _code, pos, chars})
+        Source {filename, code: code.into(), idx: 0, pos, prev_pos: pos, next_u8: 1, next_u16: 1, line_fname: None}
     }
 
     pub fn get_char(&mut self) -> Option<char> {
-        let c = self.chars.next()?;
+        let c = self.code[self.idx..].chars().next()?;
+        self.prev_pos = self.pos;
         self.pos.incr(c);
+        self.pos.byte = self.idx as u32;
+        self.idx += c.len_utf8();
+        if c == '\n' {
+            self.pos.col_u8 = 0;
+            self.pos.col_u16 = 0;
+            self.next_u8 = 1;
+            self.next_u16 = 1;
+        } else {
+            self.pos.col_u8 = self.next_u8;
+            self.pos.col_u16 = self.next_u16;
+            self.next_u8 += c.len_utf8() as u32;
+            self.next_u16 += c.len_utf16() as u32;
+        }
         // println!("Parse_ident: char={} at {:?}", c,self.pos );
         Some(c)
     }
 
-    pub fn peek_char(&mut self) -> Option<&char> {
-        self.chars.peek()
+    pub fn peek_char(&self) -> Option<char> {
+        self.code[self.idx..].chars().next()
+    }
+
+    /// Byte index of the next character to read
+    pub fn get_idx(&self) -> usize {
+        self.idx
+    }
+
+    /// Size of the code in bytes
+    pub fn size(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Shared handle on the whole code
+    pub fn code(&self) -> Rc<str> {
+        Rc::clone(&self.code)
+    }
+
+    /// Part of the code between two byte index
+    pub fn slice(&self, start: usize, end: usize) -> &str {
+        &self.code[start..end]
     }
 
+    /// Token value referencing the code between two byte index
+    pub fn value(&self, start: usize, end: usize) -> TokenValue {
+        TokenValue::src(&self.code, start, end)
+    }
+
+    // Name of the file as seen by the user: takes into account `line directive
     pub fn get_filename(&self) -> String {
-        path_display(&self.filename)
+        path_display(self.line_fname.as_ref().unwrap_or(&self.filename))
+    }
+
+    /// Remap position following a `line directive located on line dir_line:
+    /// the line following the directive becomes line `line` of file fname
+    pub fn set_line(&mut self, line: u32, dir_line: u32, fname: path::PathBuf) {
+        self.pos.line = (line + self.pos.line).saturating_sub(dir_line + 1);
+        self.line_fname = Some(fname);
     }
 }
@@ -4,6 +4,7 @@
 use crate::lex::position::Position;
 
 use std::fmt;
+use std::rc::Rc;
 
 /// The kind of a Token
 #[derive(PartialEq, Clone, Debug)]
@@ -90,19 +91,131 @@ pub enum TokenKind {
     Dollar, LineCont, EOF, Unknown
 }
 
+/// Text of a token: a range of the source code, a fixed string (operators, keywords)
+/// or an owned string for text built by the tokenizer (string literal, macro concatenation, ...)
+#[derive(Clone)]
+pub enum TokenValue {
+    Src(Rc<str>, u32, u32),
+    Fixed(&'static str),
+    Owned(String),
+}
+
+impl TokenValue {
+    /// Range of a source code
+    pub fn src(code: &Rc<str>, start: usize, end: usize) -> TokenValue {
+        TokenValue::Src(Rc::clone(code), start as u32, end as u32)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            TokenValue::Src(c,s,e) => &c[*s as usize..*e as usize],
+            TokenValue::Fixed(s) => s,
+            TokenValue::Owned(s) => s,
+        }
+    }
+
+    /// Append some text: the value is then no longer a reference to the source
+    pub fn push_str(&mut self, v: &str) {
+        match self {
+            TokenValue::Owned(s) => s.push_str(v),
+            _ => {
+                let mut s = String::with_capacity(self.len() + v.len());
+                s.push_str(self.as_str());
+                s.push_str(v);
+                *self = TokenValue::Owned(s);
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for TokenValue {
+    type Target = str;
+    fn deref(&self) -> &str {self.as_str()}
+}
+
+impl AsRef<str> for TokenValue {
+    fn as_ref(&self) -> &str {self.as_str()}
+}
+
+impl std::borrow::Borrow<str> for TokenValue {
+    fn borrow(&self) -> &str {self.as_str()}
+}
+
+impl From<&'static str> for TokenValue {
+    fn from(s: &'static str) -> Self {TokenValue::Fixed(s)}
+}
+
+impl From<String> for TokenValue {
+    fn from(s: String) -> Self {TokenValue::Owned(s)}
+}
+
+impl From<TokenValue> for String {
+    fn from(v: TokenValue) -> Self {
+        match v {
+            TokenValue::Owned(s) => s,
+            _ => v.as_str().to_owned()
+        }
+    }
+}
+
+impl PartialEq for TokenValue {
+    fn eq(&self, other: &TokenValue) -> bool {self.as_str() == other.as_str()}
+}
+
+impl PartialEq<str> for TokenValue {
+    fn eq(&self, other: &str) -> bool {self.as_str() == other}
+}
+
+impl PartialEq<&str> for TokenValue {
+    fn eq(&self, other: &&str) -> bool {self.as_str() == *other}
+}
+
+impl PartialEq<String> for TokenValue {
+    fn eq(&self, other: &String) -> bool {self.as_str() == other}
+}
+
+impl fmt::Debug for TokenValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for TokenValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Token {
     pub kind: TokenKind,
-    pub value: String,
+    /// Text of the token, referencing the source code when possible
+    pub value: TokenValue,
     pub pos: Position,
+    /// Position of the last character of the token
+    pub end: Position,
+    /// Macro expansion which produced the token (None for token read from source)
+    pub exp: Option<Rc<MacroExp>>,
 }
 
 impl Token {
-    pub fn new(k : TokenKind, v: String, p : Position) -> Token {
-        Token {kind:k, value: v, pos: p}
+    pub fn new<V: Into<TokenValue>>(k : TokenKind, v: V, p : Position) -> Token {
+        Token {kind:k, value: v.into(), pos: p, end: p, exp: None}
     }
 }
 
+/// Macro expansion context: macro name, call site and definition site.
+/// Expansion inside the body of another macro are linked to it via the parent.
+#[derive(Debug)]
+pub struct MacroExp {
+    pub name       : String,
+    pub call_fname : String,
+    pub call_pos   : Position,
+    pub def_fname  : String,
+    pub def_pos    : Position,
+    pub parent     : Option<Rc<MacroExp>>,
+}
+
 //-----------------------------------------------------------------------------
 // Display traits
 
@@ -563,6 +676,62 @@ pub fn keyword_from_str(w: &str) -> Option<TokenKind> {
     }
 }
 
+//-----------------------------------------------------------------------------
+// Keyword version (`begin_keywords / --std)
+
+/// Version of the language defining the set of reserved keywords
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
+pub enum KwVersion {V1995, V2001Noconfig, V2001, V2005, Sv2005, Sv2009, Sv2012, Sv2017, Sv2023}
+
+impl KwVersion {
+    /// Get the keyword version from the version specifier used in `begin_keywords
+    pub fn from_spec(s: &str) -> Option<KwVersion> {
+        match s {
+            "1364-1995"          => Some(KwVersion::V1995),
+            "1364-2001-noconfig" => Some(KwVersion::V2001Noconfig),
+            "1364-2001"          => Some(KwVersion::V2001),
+            "1364-2005"          => Some(KwVersion::V2005),
+            "1800-2005"          => Some(KwVersion::Sv2005),
+            "1800-2009"          => Some(KwVersion::Sv2009),
+            "1800-2012"          => Some(KwVersion::Sv2012),
+            "1800-2017"          => Some(KwVersion::Sv2017),
+            "1800-2023"          => Some(KwVersion::Sv2023),
+            _ => None
+        }
+    }
+}
+
+// Version introducing a keyword (or base type): any other word is a Verilog-1995 keyword or not a keyword at all.
+// Configuration keywords are excluded from 1364-2001-noconfig.
+pub fn keyword_version(w: &str) -> KwVersion {
+    match w {
+        "automatic" | "endgenerate" | "generate" | "genvar" | "localparam" | "noshowcancelled" |
+        "pulsestyle_ondetect" | "pulsestyle_onevent" | "showcancelled" | "signed" | "unsigned" => KwVersion::V2001Noconfig,
+        "cell" | "config" | "design" | "endconfig" | "incdir" | "include" | "instance" |
+        "liblist" | "library" | "use" => KwVersion::V2001,
+        "uwire" => KwVersion::V2005,
+        "alias" | "always_comb" | "always_ff" | "always_latch" | "assert" | "assume" | "before" |
+        "bind" | "bins" | "binsof" | "bit" | "break" | "byte" | "chandle" | "class" | "clocking" |
+        "const" | "constraint" | "context" | "continue" | "cover" | "covergroup" | "coverpoint" |
+        "cross" | "dist" | "do" | "endclass" | "endclocking" | "endgroup" | "endinterface" |
+        "endpackage" | "endprogram" | "endproperty" | "endsequence" | "enum" | "expect" | "export" |
+        "extends" | "extern" | "final" | "first_match" | "foreach" | "forkjoin" | "iff" |
+        "ignore_bins" | "illegal_bins" | "import" | "inside" | "int" | "interface" | "intersect" |
+        "join_any" | "join_none" | "local" | "logic" | "longint" | "matches" | "modport" | "new" |
+        "null" | "package" | "packed" | "priority" | "program" | "property" | "protected" | "pure" |
+        "rand" | "randc" | "randcase" | "randsequence" | "ref" | "return" | "sequence" | "shortint" |
+        "shortreal" | "solve" | "static" | "string" | "struct" | "super" | "tagged" | "this" |
+        "throughout" | "timeprecision" | "timeunit" | "type" | "typedef" | "union" | "unique" |
+        "var" | "virtual" | "void" | "wait_order" | "wildcard" | "with" | "within" => KwVersion::Sv2005,
+        "accept_on" | "checker" | "endchecker" | "eventually" | "global" | "implies" | "let" |
+        "nexttime" | "reject_on" | "restrict" | "s_always" | "s_eventually" | "s_nexttime" |
+        "s_until" | "s_until_with" | "strong" | "sync_accept_on" | "sync_reject_on" | "unique0" |
+        "until" | "until_with" | "untyped" | "weak" => KwVersion::Sv2009,
+        "implements" | "interconnect" | "nettype" | "soft" => KwVersion::Sv2012,
+        _ => KwVersion::V1995
+    }
+}
+
 //-----------------------------------------------------------------------------
 // List of SV keywords / Base type
 pub fn basetype_from_str(w: &str) -> Option<TokenKind> {
@@ -2,10 +2,13 @@
 // Copyright (c) 2019, clams@mail.com
 
 use std::collections::{VecDeque, HashMap};
+use std::rc::Rc;
 
 use crate::error::*;
 use crate::project::Project;
 use crate::lex::{position::Position, token::*, source::Source};
+use crate::ast::cst::{CstElem,CstKind};
+use crate::ast::astnode::{AstNode,AstNodeKind};
 use crate::reporter::{REPORTER, MsgID};
 
 
@@ -13,39 +16,75 @@ pub struct TokenStream<'a,'b> {
     pub source: &'a mut Source,
     last_char: char,
     pub last_pos : Position,
+    /// End position of the last token consumed
+    pub last_end : Position,
     buffer : VecDeque<Token>,
     rd_ptr : usize,
     pub inc_files : Vec<String>,
     pub project : &'b mut Project,
-    branch : Vec<BranchState>
+    branch : Vec<BranchInfo>,
+    /// Stack of keyword version set by `begin_keywords
+    pub kw_stack : Vec<KwVersion>,
+    /// Concrete syntax tree (lossless mode): all tokens and trivia read from the source
+    /// and byte index of the end of the last element
+    cst : Option<Vec<CstElem>>,
+    cst_idx : usize,
+    /// Syntax errors recovered during parsing
+    pub errors : Vec<SvError>,
+    /// Token read after the end of a macro definition, not yet preprocessed
+    pending : Option<Token>,
 }
 
 /// Enum for the state machine parsing number
 #[derive(PartialEq, Debug,  Clone)]
 enum NumParseState {Start, Base, IntStart, Int, Dec, Exp}
 
+type CondTokenIter = std::iter::Peekable<std::vec::IntoIter<Token>>;
+
 #[derive(PartialEq, Debug)]
 enum NumBase {Binary, Octal, Hexa, Decimal}
 
 #[derive(PartialEq, Debug,  Clone)]
 enum BranchState {Success, Fail, Done}
 
+/// Conditional compilation context: state of the current branch,
+/// flag indicating if the `else was already seen and the directive which opened it
+#[derive(Debug, Clone)]
+struct BranchInfo {
+    state : BranchState,
+    has_else : bool,
+    token : Token,
+}
+
 impl<'a,'b> TokenStream<'a,'b> {
 
     // Create a token stream with for a source code
     pub fn new(src: &'a mut Source, project: &'b mut Project) -> TokenStream<'a,'b> {
+        rpt_add_source!(src.filename, src.code());
+        let cst = if project.keep_trivia {Some(Vec::new())} else {None};
         TokenStream {
             source: src,
             last_char : ' ',
             project : project,
             last_pos : Position::new() ,
+            last_end : Position::new() ,
             buffer   : VecDeque::new() ,
             branch   : Vec::new() ,
             rd_ptr   : 0,
-            inc_files: Vec::new()
+            inc_files: Vec::new(),
+            kw_stack : Vec::new(),
+            cst,
+            cst_idx  : 0,
+            errors   : Vec::new(),
+            pending  : None,
         }
     }
 
+    // Keyword version currently active: last `begin_keywords or project default
+    pub fn kw_version(&self) -> KwVersion {
+        *self.kw_stack.last().unwrap_or(&self.project.kw_version)
+    }
+
     fn updt_last(&mut self, c: char) {
         self.last_char = c;
         self.last_pos = self.source.pos;
@@ -67,27 +106,29 @@ impl<'a,'b> TokenStream<'a,'b> {
 
     /// Get a valid identifier
     fn parse_ident(&mut self, first_char: char) -> Result<Token,SvError> {
-        let mut s = String::new();
-        s.push(first_char);
         let p = self.last_pos;
+        // The identifier is extracted from the source once its end is known
+        let start = p.byte as usize;
+        let mut end = self.source.get_idx();
         let mut is_pathpulse = false;
         let mut is_casting = false;
         let mut second_char = ' ';
         while let Some(c) = self.source.get_char() {
-            if s.len()==1 {
+            let len = end - start;
+            if len==1 {
                 second_char = c;
             }
-            if !c.is_alphanumeric() && c!='_' && !(s.len()==1 && second_char=='`' && first_char=='`'){
+            if !c.is_alphanumeric() && c!='_' && !(len==1 && second_char=='`' && first_char=='`'){
                 match c {
                     '$' => {
-                        s.push(c);
-                        if s=="PATHPULSE" {
+                        end = self.source.get_idx();
+                        if self.source.slice(start,end)=="PATHPULSE" {
                             is_pathpulse = true;
                             break;
                         }
                     },
                     '\'' => {
-                        s.push(c);
+                        end = self.source.get_idx();
                         is_casting = true;
                         break;
                     }
@@ -98,26 +139,28 @@ impl<'a,'b> TokenStream<'a,'b> {
                     }
                 }
             } else {
-                s.push(c);
+                end = self.source.get_idx();
             }
         }
+        let mut s = self.source.value(start,end);
         // Check if the word is a base type of a keyword
         let k = {
             if first_char == '$' {
                 if is_pathpulse || is_casting {
                     return Err(SvError::token(p,s));
                 }
-                TokenKind::SystemTask
+                // $unit is the name of the compilation unit scope
+                if s=="$unit" {TokenKind::Ident} else {TokenKind::SystemTask}
             }
             else if first_char == '`' {
                 if is_pathpulse || s.len()==1 {
                     return Err(SvError::token(p,s));
                 } else if is_casting {
-                    let nc = self.source.peek_char().unwrap_or(&' ');
+                    let nc = self.source.peek_char().unwrap_or(' ');
                     match nc {
                         'b'|'B'|'o'|'O'|'h'|'H'|'d'|'D' => {
                             let t = self.parse_number('\'')?;
-                            s.push_str(&t.value);
+                            s.push_str(t.value.as_str());
                             return Ok(Token::new(t.kind,s,p));
                         }
                         '(' => {},
@@ -126,7 +169,7 @@ impl<'a,'b> TokenStream<'a,'b> {
                 }
                 if is_casting {TokenKind::Casting}
                 else if second_char=='`' && first_char=='`' {
-                    s = s.chars().skip(2).collect();
+                    s = self.source.value(start+2,end);
                     TokenKind::IdentInterpolated
                 }
                 else {
@@ -141,6 +184,8 @@ impl<'a,'b> TokenStream<'a,'b> {
                 }
             }
             else if is_casting {TokenKind::Casting}
+            // Word reserved only in a later version of the language
+            else if keyword_version(&s) > self.kw_version() {TokenKind::Ident}
             else if let Some(k) = basetype_from_str(s.as_ref()) {k}
             else if let Some(k) = keyword_from_str(s.as_ref()) {
                 if is_casting {
@@ -155,60 +200,58 @@ impl<'a,'b> TokenStream<'a,'b> {
 
     /// Escaped Identifier: get all characters until whitespace
     fn parse_esc_ident(&mut self) -> Result<Token,SvError> {
-        let mut s = "\\".to_owned();
         let p = self.last_pos;
+        let mut end = self.source.get_idx();
         while let Some(c) = self.source.get_char() {
             if ! c.is_whitespace() {
-                s.push(c);
+                end = self.source.get_idx();
             } else { break;}
         }
         self.last_pos = self.source.pos;
         self.last_char = ' ';
-        Ok(Token::new(TokenKind::Ident,s,p))
+        Ok(Token::new(TokenKind::Ident,self.source.value(p.byte as usize,end),p))
     }
 
     /// Get all characters until end of string
     fn parse_string(&mut self, is_macro: bool) -> Result<Token,SvError> {
         // TODO: Macro interpolated string to be handled
-        let mut s = "".to_owned();
         let p = self.last_pos;
+        let start = self.source.get_idx();
+        let mut end = start;
         let mut cpp = ' ';
         while let Some(c) = self.source.get_char() {
             let done = c == '"' && if is_macro {self.last_char == '`'} else {self.last_char != '\\' || cpp == '\\'};
             if done {
-                if is_macro {s.pop();}
+                if is_macro {end -= 1;}
                 break;
             }
-            s.push(c);
+            end = self.source.get_idx();
             cpp = self.last_char;
             self.last_char = c;
         }
         self.last_char = ' ';
-        Ok(Token::new(TokenKind::Str,s,p))
+        Ok(Token::new(TokenKind::Str,self.source.value(start,end),p))
     }
 
     /// Get all characters until end of line
     fn parse_comment_line(&mut self) -> Result<Token,SvError> {
-        let mut s = String::from("/");
         let p = self.last_pos;
+        let mut end = self.source.get_idx();
         while let Some(c) = self.source.get_char() {
             if c == '\n'{
                 break;
-            } else {
-                s.push(c);
             }
+            end = self.source.get_idx();
         }
         self.last_pos = self.source.pos;
         self.last_char = ' ' ;
-        Ok(Token::new(TokenKind::Comment,s,p))
+        Ok(Token::new(TokenKind::Comment,self.source.value(p.byte as usize, end),p))
     }
 
     /// Get all characters until */
     fn parse_comment_block(&mut self) -> Result<Token,SvError> {
-        let mut s = String::from("/");
         let p = self.last_pos;
         while let Some(c) = self.source.get_char() {
-            s.push(c);
             if c == '/' && self.last_char == '*' {
                 self.last_char = ' '; // Last char is consume
                 break;
@@ -216,15 +259,13 @@ impl<'a,'b> TokenStream<'a,'b> {
             self.last_char = c;
         }
         self.last_pos = self.source.pos;
-        Ok(Token::new(TokenKind::Comment,s,p))
+        Ok(Token::new(TokenKind::Comment,self.source.value(p.byte as usize, self.source.get_idx()),p))
     }
 
     /// Get all characters until *)
     fn parse_attribute(&mut self) -> Result<Token,SvError> {
-        let mut s = String::from("(");
         let p = self.last_pos;
         while let Some(c) = self.source.get_char() {
-            s.push(c);
             if c == ')' && self.last_char == '*' {
                 self.last_char = ' '; // Last char is consumed
                 break;
@@ -232,6 +273,7 @@ impl<'a,'b> TokenStream<'a,'b> {
             self.last_char = c;
         }
         self.last_pos = self.source.pos;
+        let s = self.source.value(p.byte as usize, self.source.get_idx());
         if s.len() == 3 {
             Ok(Token::new(TokenKind::SensiAll,s,p))
         } else {
@@ -242,14 +284,15 @@ impl<'a,'b> TokenStream<'a,'b> {
 
     /// Parse a number (real or integer)
     fn parse_number(&mut self, first_char: char) -> Result<Token,SvError> {
-        let mut s = String::new();
         let p = self.last_pos;
+        // The number is extracted from the source once its end is known
+        let start = self.source.get_idx() - first_char.len_utf8();
+        let mut end = self.source.get_idx();
         let mut has_xz = false;
         let mut base = NumBase::Decimal;
         let mut fsm = if first_char=='\'' {NumParseState::Base} else  {NumParseState::Start};
         let mut fsm_next;
         let mut done = false;
-        s.push(first_char);
         while let Some(c) = self.source.get_char() {
             // if fsm==NumParseState::IntStart && !c.is_whitespace() {
             //
@@ -266,8 +309,8 @@ impl<'a,'b> TokenStream<'a,'b> {
                 'x'|'X'|'z'|'Z'|'?' if fsm==NumParseState::IntStart && base==NumBase::Decimal => done = true,
                 // x/z allowed for integer number only
                 'x'|'X'|'z'|'Z' => {
-                    if base != NumBase::Binary && base != NumBase::Hexa && fsm!=NumParseState::Int && fsm!=NumParseState::IntStart && fsm!=NumParseState::Start && &s!="'" {
-                        return Err(SvError::token(self.source.pos,s));
+                    if base != NumBase::Binary && base != NumBase::Hexa && fsm!=NumParseState::Int && fsm!=NumParseState::IntStart && fsm!=NumParseState::Start && self.source.slice(start,end)!="'" {
+                        return Err(SvError::token(self.source.pos,self.source.value(start,end)));
                     }
                     has_xz = true;
                 }
@@ -277,25 +320,25 @@ impl<'a,'b> TokenStream<'a,'b> {
                 // Base specifier
                 '\'' => {
                     if fsm != NumParseState::Start || has_xz {
-                        return Err(SvError::token(self.source.pos,s));
+                        return Err(SvError::token(self.source.pos,self.source.value(start,end)));
                     }
                     fsm_next = NumParseState::Base;
                 },
                 // s following a number can be the time unit
                 's' if fsm != NumParseState::Base => {
                     // Check for 1step keyword
-                    if s == "1" {
-                        let nc = self.source.peek_char().unwrap_or(&' ');
-                        if nc == &'t' {
+                    if self.source.slice(start,end) == "1" {
+                        let nc = self.source.peek_char().unwrap_or(' ');
+                        if nc == 't' {
                             self.source.get_char(); // consume t, and check the next two char are e and p
-                            if self.source.get_char().unwrap_or(' ') != 'e' {return Err(SvError::token(self.source.pos,s));}
-                            if self.source.get_char().unwrap_or(' ') != 'p' {return Err(SvError::token(self.source.pos,s));}
-                            let lc = self.source.peek_char().unwrap_or(&'/');
-                            if lc.is_whitespace() || lc==&';' {
+                            if self.source.get_char().unwrap_or(' ') != 'e' {return Err(SvError::token(self.source.pos,self.source.value(start,end)));}
+                            if self.source.get_char().unwrap_or(' ') != 'p' {return Err(SvError::token(self.source.pos,self.source.value(start,end)));}
+                            let lc = self.source.peek_char().unwrap_or('/');
+                            if lc.is_whitespace() || lc==';' {
                                 self.last_pos = self.source.pos;
-                                return Ok(Token::new(TokenKind::Kw1step,"1step".to_owned(),p));
+                                return Ok(Token::new(TokenKind::Kw1step,"1step",p));
                             } else {
-                                return Err(SvError::token(self.source.pos,s));
+                                return Err(SvError::token(self.source.pos,self.source.value(start,end)));
                             }
                         }
                     }
@@ -325,14 +368,14 @@ impl<'a,'b> TokenStream<'a,'b> {
                 // Dot -> real number
                 '.' => {
                     if fsm != NumParseState::Start || has_xz {
-                        return Err(SvError::token(self.source.pos,s));
+                        return Err(SvError::token(self.source.pos,self.source.value(start,end)));
                     }
                     fsm_next = NumParseState::Dec;
                 }
                 // Exponent -> real number
                 'e' | 'E' => {
                     if (fsm != NumParseState::Start && fsm != NumParseState::Dec) || has_xz {
-                        return Err(SvError::token(self.source.pos,s));
+                        return Err(SvError::token(self.source.pos,self.source.value(start,end)));
                     }
                     fsm_next = NumParseState::Exp;
                 }
@@ -357,7 +400,7 @@ impl<'a,'b> TokenStream<'a,'b> {
                 fsm_next = NumParseState::Int;
             }
             fsm = fsm_next.clone();
-            s.push(c);
+            end = self.source.get_idx();
             self.last_char = c;
             if done {
                 self.last_char = ' ';
@@ -369,15 +412,60 @@ impl<'a,'b> TokenStream<'a,'b> {
         // let k = if fsm==NumParseState::Dec || fsm==NumParseState::Exp {TokenKind::Real} else {TokenKind::Integer};
         let k = match fsm {
             NumParseState::Dec | NumParseState::Exp => TokenKind::Real,
-            NumParseState::Base => {s.pop(); TokenKind::Casting}
+            NumParseState::Base => {end -= 1; TokenKind::Casting}
             _ => TokenKind::Integer
         };
         // println!("[TokenStream] Parse_number @ {} = {} | kind={} | FSM={:?}", p, s,k,fsm);
-        Ok(Token::new(k,s,p))
+        Ok(Token::new(k,self.source.value(start,end),p))
     }
 
     /// Return the next token from the source code
     pub fn get_next_token(&mut self) -> Result<Token,SvError> {
+        if let Some(t) = self.pending.take() {
+            return Ok(t);
+        }
+        let mut t = self.lex_token()?;
+        // The token ends on the last character read, unless a look-ahead character was consumed
+        // (i.e. the last character read is still pending in last_char)
+        let lookahead = self.last_pos.byte != t.pos.byte &&
+            self.source.slice(self.last_pos.byte as usize, self.source.get_idx()).starts_with(self.last_char);
+        t.end = if lookahead {self.source.prev_pos} else {self.source.pos};
+        if self.cst.is_some() {
+            let end = if lookahead {self.last_pos.byte as usize} else {self.source.get_idx()};
+            let kind = match t.kind {
+                TokenKind::Comment   => CstKind::Comment,
+                TokenKind::Attribute => CstKind::Attribute,
+                _ => CstKind::Token
+            };
+            self.cst_push(t.pos.byte as usize, end, kind);
+        }
+        Ok(t)
+    }
+
+    // Add an element to the concrete syntax tree, with the text between the previous element
+    fn cst_push(&mut self, start: usize, end: usize, kind: CstKind) {
+        if start > self.cst_idx {
+            let gap = self.source.slice(self.cst_idx, start);
+            let gap_kind = if gap.trim().is_empty() {CstKind::Whitespace} else {CstKind::Skipped};
+            let e = CstElem{kind: gap_kind, text: gap.to_owned(), byte: self.cst_idx as u32};
+            if let Some(cst) = self.cst.as_mut() {cst.push(e);}
+        }
+        if end > start {
+            let e = CstElem{kind, text: self.source.slice(start, end).to_owned(), byte: start as u32};
+            if let Some(cst) = self.cst.as_mut() {cst.push(e);}
+            self.cst_idx = end;
+        }
+    }
+
+    // Complete the concrete syntax tree with the end of the source and return it
+    pub fn cst_finish(&mut self) -> Option<Vec<CstElem>> {
+        self.cst.as_ref()?;
+        let size = self.source.size();
+        self.cst_push(size, size, CstKind::Whitespace);
+        self.cst.take()
+    }
+
+    fn lex_token(&mut self) -> Result<Token,SvError> {
         let c = self.get_first_char().ok_or_else(|| SvError::null(self.last_pos))?;
         let p = self.last_pos; // Save position of this first char since it will
         self.last_char = ' ';
@@ -385,73 +473,73 @@ impl<'a,'b> TokenStream<'a,'b> {
         match c {
             // Operator
             '/' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     '/' => self.parse_comment_line() ,
                     '*' => self.parse_comment_block() ,
                     '=' => {
-                        let t = Token::new(TokenKind::OpCompAss,"/=".to_owned(),p);
+                        let t = Token::new(TokenKind::OpCompAss,"/=",p);
                         self.source.get_char();  // Consume peeked character
                         Ok(t)
                     }
-                    _ => Ok(Token::new(TokenKind::OpDiv,"/".to_owned(),p))
+                    _ => Ok(Token::new(TokenKind::OpDiv,"/",p))
                 }
             }
             '%' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"%=".to_owned(),p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"%=",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpMod,"%".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpMod,"%",p))
                     }
                 }
             }
             '+' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '+' => Ok(Token::new(TokenKind::OpIncrDecr,"++".to_owned(),p)) ,
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"+=".to_owned(),p)) ,
-                    ':' => Ok(Token::new(TokenKind::OpRange   ,"+:".to_owned(),p)) ,
+                    '+' => Ok(Token::new(TokenKind::OpIncrDecr,"++",p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"+=",p)) ,
+                    ':' => Ok(Token::new(TokenKind::OpRange   ,"+:",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpPlus,"+".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpPlus,"+",p))
                     }
                 }
             }
             '-' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '-' => Ok(Token::new(TokenKind::OpIncrDecr,"--".to_owned(),p)) ,
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"-=".to_owned(),p)) ,
-                    ':' => Ok(Token::new(TokenKind::OpRange   ,"-:".to_owned(),p)) ,
-                    '>' => Ok(Token::new(TokenKind::OpImpl    ,"->".to_owned(),p)) ,
+                    '-' => Ok(Token::new(TokenKind::OpIncrDecr,"--",p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"-=",p)) ,
+                    ':' => Ok(Token::new(TokenKind::OpRange   ,"-:",p)) ,
+                    '>' => Ok(Token::new(TokenKind::OpImpl    ,"->",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpMinus,"-".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpMinus,"-",p))
                     }
                 }
             }
             '*' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '*' => Ok(Token::new(TokenKind::OpPow,"**".to_owned(),p)) ,
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"*=".to_owned(),p)) ,
-                    '>' => Ok(Token::new(TokenKind::OpStarLT ,"*>".to_owned(),p)) ,
+                    '*' => Ok(Token::new(TokenKind::OpPow,"**",p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"*=",p)) ,
+                    '>' => Ok(Token::new(TokenKind::OpStarLT ,"*>",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpStar,"*".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpStar,"*",p))
                     }
                 }
             }
             '^' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '~' => Ok(Token::new(TokenKind::OpXnor    ,"^~".to_owned(),p)) ,
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"^=".to_owned(),p)) ,
+                    '~' => Ok(Token::new(TokenKind::OpXnor    ,"^~",p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"^=",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpXor,"^".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpXor,"^",p))
                     }
                 }
             }
@@ -459,120 +547,123 @@ impl<'a,'b> TokenStream<'a,'b> {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
                     '&' => {
-                        let nnc = self.source.peek_char().unwrap_or(&' ');
+                        let nnc = self.source.peek_char().unwrap_or(' ');
                         match nnc {
                             '&' => {
                                 self.source.get_char();  // Consume peeked character
-                                Ok(Token::new(TokenKind::OpTimingAnd,"&&&".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpTimingAnd,"&&&",p))
                             },
-                            _ => Ok(Token::new(TokenKind::OpLogicAnd,"&&".to_owned(),p))
+                            _ => Ok(Token::new(TokenKind::OpLogicAnd,"&&",p))
                         }
                     }
-                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"&=".to_owned(),p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpCompAss ,"&=",p)) ,
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpAnd,"&".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpAnd,"&",p))
                     }
                 }
             }
             '|' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '|' => Ok(Token::new(TokenKind::OpLogicOr,"||".to_owned(),p)) ,
+                    '|' => Ok(Token::new(TokenKind::OpLogicOr,"||",p)) ,
                     '=' => {
-                        let nnc = self.source.peek_char().unwrap_or(&' ');
+                        let nnc = self.source.peek_char().unwrap_or(' ');
                         match nnc {
                             '>' => {
                                 self.source.get_char();  // Consume peeked character
-                                Ok(Token::new(TokenKind::OpSeqRel,"|=>".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpSeqRel,"|=>",p))
                             },
-                            _ => Ok(Token::new(TokenKind::OpCompAss,"|=".to_owned(),p))
+                            _ => Ok(Token::new(TokenKind::OpCompAss,"|=",p))
                         }
                     }
                     '-' => {
-                        let nnc = self.source.peek_char().unwrap_or(&' ');
+                        let nnc = self.source.peek_char().unwrap_or(' ');
                         match nnc {
-                            '>' => Ok(Token::new(TokenKind::OpSeqRel,"|->".to_owned(),p)) ,
+                            '>' => {
+                                self.source.get_char();  // Consume peeked character
+                                Ok(Token::new(TokenKind::OpSeqRel,"|->",p))
+                            },
                             _ => {
                                 self.updt_last(nc);
-                                Ok(Token::new(TokenKind::OpOr,"|".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpOr,"|",p))
                             }
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpOr,"|".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpOr,"|",p))
                     }
                 }
             }
             '<' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '=' => Ok(Token::new(TokenKind::OpLTE,"<=".to_owned(),p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpLTE,"<=",p)) ,
                     '<' => {
                         let nc = self.source.get_char().unwrap_or(' ');
                         match nc {
-                            '=' => Ok(Token::new(TokenKind::OpCompAss,"<<=".to_owned(),p)) ,
+                            '=' => Ok(Token::new(TokenKind::OpCompAss,"<<=",p)) ,
                             '<' => {
                                 let nc = self.source.get_char().unwrap_or(' ');
                                 match nc {
-                                    '=' => Ok(Token::new(TokenKind::OpCompAss,"<<<=".to_owned(),p)) ,
+                                    '=' => Ok(Token::new(TokenKind::OpCompAss,"<<<=",p)) ,
                                     _ => {
                                         self.updt_last(nc);
-                                        Ok(Token::new(TokenKind::OpSShift,"<<<".to_owned(),p))
+                                        Ok(Token::new(TokenKind::OpSShift,"<<<",p))
                                     }
                                 }
                             }
                             _ => {
                                 self.updt_last(nc);
-                                Ok(Token::new(TokenKind::OpSL,"<<".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpSL,"<<",p))
                             }
                         }
                     }
                     // Check for equivalence operator <->
                     '-' => {
-                        let nnc = self.source.peek_char().unwrap_or(&' ');
-                        if nnc == &'>' {
+                        let nnc = self.source.peek_char().unwrap_or(' ');
+                        if nnc == '>' {
                             self.source.get_char().unwrap(); // Consume next char
-                            Ok(Token::new(TokenKind::OpEquiv,"<->".to_owned(),p))
+                            Ok(Token::new(TokenKind::OpEquiv,"<->",p))
                         } else {
                             self.updt_last(nc);
-                            Ok(Token::new(TokenKind::OpLT,"<".to_owned(),p))
+                            Ok(Token::new(TokenKind::OpLT,"<",p))
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpLT,"<".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpLT,"<",p))
                     }
                 }
             }
             '>' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '=' => Ok(Token::new(TokenKind::OpGTE,">=".to_owned(),p)) ,
+                    '=' => Ok(Token::new(TokenKind::OpGTE,">=",p)) ,
                     '>' => {
                         let nc = self.source.get_char().unwrap_or(' ');
                         match nc {
-                            '=' => Ok(Token::new(TokenKind::OpCompAss,">>=".to_owned(),p)) ,
+                            '=' => Ok(Token::new(TokenKind::OpCompAss,">>=",p)) ,
                             '>' => {
                                 let nc = self.source.get_char().unwrap_or(' ');
                                 match nc {
-                                    '=' => Ok(Token::new(TokenKind::OpCompAss,">>>=".to_owned(),p)) ,
+                                    '=' => Ok(Token::new(TokenKind::OpCompAss,">>>=",p)) ,
                                     _ => {
                                         self.updt_last(nc);
-                                        Ok(Token::new(TokenKind::OpSShift,">>>".to_owned(),p))
+                                        Ok(Token::new(TokenKind::OpSShift,">>>",p))
                                     }
                                 }
                             }
                             _ => {
                                 self.updt_last(nc);
-                                Ok(Token::new(TokenKind::OpSR,">>".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpSR,">>",p))
                             }
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpGT,">".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpGT,">",p))
                     }
                 }
             }
@@ -582,148 +673,148 @@ impl<'a,'b> TokenStream<'a,'b> {
                     '=' => {
                         let nc = self.source.get_char().unwrap_or(' ');
                         match nc {
-                            '=' => Ok(Token::new(TokenKind::OpDiff2,"!==".to_owned(),p)) ,
-                            '?' => Ok(Token::new(TokenKind::OpDiffQue,"!=?".to_owned(),p)) ,
+                            '=' => Ok(Token::new(TokenKind::OpDiff2,"!==",p)) ,
+                            '?' => Ok(Token::new(TokenKind::OpDiffQue,"!=?",p)) ,
                             _ => {
                                 self.updt_last(nc);
-                                Ok(Token::new(TokenKind::OpDiff,"!=".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpDiff,"!=",p))
                             }
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpBang,"!".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpBang,"!",p))
                     }
                 }
             }
             '=' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '>' => Ok(Token::new(TokenKind::OpFatArrL,"=>".to_owned(),p)) ,
+                    '>' => Ok(Token::new(TokenKind::OpFatArrL,"=>",p)) ,
                     '=' => {
                         let nc = self.source.get_char().unwrap_or(' ');
                         match nc {
-                            '=' => Ok(Token::new(TokenKind::OpEq3,"===".to_owned(),p)) ,
-                            '?' => Ok(Token::new(TokenKind::OpEq2Que,"==?".to_owned(),p)) ,
+                            '=' => Ok(Token::new(TokenKind::OpEq3,"===",p)) ,
+                            '?' => Ok(Token::new(TokenKind::OpEq2Que,"==?",p)) ,
                             _ => {
                                 self.updt_last(nc);
-                                Ok(Token::new(TokenKind::OpEq2,"==".to_owned(),p))
+                                Ok(Token::new(TokenKind::OpEq2,"==",p))
                             }
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpEq,"=".to_owned(),p))
+                        Ok(Token::new(TokenKind::OpEq,"=",p))
                     }
                 }
             }
             '~' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '&' => Ok(Token::new(TokenKind::OpNand ,"~&".to_owned(),p)),
-                    '|' => Ok(Token::new(TokenKind::OpNor  ,"~|".to_owned(),p)),
-                    '^' => Ok(Token::new(TokenKind::OpXnor ,"~^".to_owned(),p)),
+                    '&' => Ok(Token::new(TokenKind::OpNand ,"~&",p)),
+                    '|' => Ok(Token::new(TokenKind::OpNor  ,"~|",p)),
+                    '^' => Ok(Token::new(TokenKind::OpXnor ,"~^",p)),
                     _   => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::OpTilde,"~".to_owned() ,p))
+                        Ok(Token::new(TokenKind::OpTilde,"~" ,p))
                     }
                 }
             }
             // Parenthesis
             '(' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     '*' => self.parse_attribute() ,
-                    _ => Ok(Token::new(TokenKind::ParenLeft  ,"(".to_owned(),p)),
+                    _ => Ok(Token::new(TokenKind::ParenLeft  ,"(",p)),
                 }
             }
-            ')' => Ok(Token::new(TokenKind::ParenRight ,")".to_owned(),p)),
-            '[' => Ok(Token::new(TokenKind::SquareLeft ,"[".to_owned(),p)),
-            ']' => Ok(Token::new(TokenKind::SquareRight,"]".to_owned(),p)),
-            '{' => Ok(Token::new(TokenKind::CurlyLeft  ,"{".to_owned(),p)),
-            '}' => Ok(Token::new(TokenKind::CurlyRight ,"}".to_owned(),p)),
+            ')' => Ok(Token::new(TokenKind::ParenRight ,")",p)),
+            '[' => Ok(Token::new(TokenKind::SquareLeft ,"[",p)),
+            ']' => Ok(Token::new(TokenKind::SquareRight,"]",p)),
+            '{' => Ok(Token::new(TokenKind::CurlyLeft  ,"{",p)),
+            '}' => Ok(Token::new(TokenKind::CurlyRight ,"}",p)),
             // Special characters
-            ',' => Ok(Token::new(TokenKind::Comma    ,",".to_owned(),p)),
-            '?' => Ok(Token::new(TokenKind::Que      ,"?".to_owned(),p)),
-            ';' => Ok(Token::new(TokenKind::SemiColon,";".to_owned(),p)),
+            ',' => Ok(Token::new(TokenKind::Comma    ,",",p)),
+            '?' => Ok(Token::new(TokenKind::Que      ,"?",p)),
+            ';' => Ok(Token::new(TokenKind::SemiColon,";",p)),
             '@' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '@' => Ok(Token::new(TokenKind::At2 ,"@@".to_owned(),p)),
+                    '@' => Ok(Token::new(TokenKind::At2 ,"@@",p)),
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::At ,"@".to_owned(),p))
+                        Ok(Token::new(TokenKind::At ,"@",p))
                     }
                 }
             }
             '.' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     '*' => {
                         self.source.get_char().unwrap(); // Consume next char
-                        Ok(Token::new(TokenKind::DotStar,".*".to_owned(),p))
+                        Ok(Token::new(TokenKind::DotStar,".*",p))
                     }
-                    _ => Ok(Token::new(TokenKind::Dot      ,".".to_owned(),p)),
+                    _ => Ok(Token::new(TokenKind::Dot      ,".",p)),
                 }
 
             }
             '$' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     'a'..='z' | 'A'..='Z' | '_'  => self.parse_ident(c),
-                    _ => Ok(Token::new(TokenKind::Dollar ,"$".to_owned(),p)),
+                    _ => Ok(Token::new(TokenKind::Dollar ,"$",p)),
                 }
 
             }
             '#' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '#' => Ok(Token::new(TokenKind::Hash2,"##".to_owned(),p)),
+                    '#' => Ok(Token::new(TokenKind::Hash2,"##",p)),
                     '-' | '=' => {
-                        let nnc = self.source.peek_char().unwrap_or(&' ');
-                        if nnc == &'#' {
+                        let nnc = self.source.peek_char().unwrap_or(' ');
+                        if nnc == '#' {
                             self.source.get_char().unwrap(); // Consume next char
-                            Ok(Token::new(TokenKind::OpEquiv, format!("#{}#", nc),p))
+                            Ok(Token::new(TokenKind::OpEquiv, if nc=='-' {"#-#"} else {"#=#"},p))
                         } else {
                             self.updt_last(nc);
-                            Ok(Token::new(TokenKind::Hash2,"##".to_owned(),p))
+                            Ok(Token::new(TokenKind::Hash2,"##",p))
                         }
                     }
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::Hash,"#".to_owned(),p))
+                        Ok(Token::new(TokenKind::Hash,"#",p))
                     }
                 }
             }
             ':' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    ':' => Ok(Token::new(TokenKind::Scope,"::".to_owned(),p)),
-                    '/' => Ok(Token::new(TokenKind::OpDist,":/".to_owned(),p)),
-                    '=' => Ok(Token::new(TokenKind::OpDist,":=".to_owned(),p)),
+                    ':' => Ok(Token::new(TokenKind::Scope,"::",p)),
+                    '/' => Ok(Token::new(TokenKind::OpDist,":/",p)),
+                    '=' => Ok(Token::new(TokenKind::OpDist,":=",p)),
                     _ => {
                         self.updt_last(nc);
-                        Ok(Token::new(TokenKind::Colon,":".to_owned(),p))
+                        Ok(Token::new(TokenKind::Colon,":",p))
                     }
                 }
             }
             //
             '\'' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     '{' => {
                         self.source.get_char().unwrap(); // Consume next char
-                        Ok(Token::new(TokenKind::TickCurly ,"'{".to_owned(),p))
+                        Ok(Token::new(TokenKind::TickCurly ,"'{",p))
                     },
-                    '(' => Ok(Token::new(TokenKind::Casting,"\'".to_owned(),p)),
+                    '(' => Ok(Token::new(TokenKind::Casting,"\'",p)),
                     'h'|'d'|'o'|'b'|'x'|'z'|'H'|'D'|'O'|'B'|'X'|'Z'|'1'|'0' => self.parse_number(c),
-                    _ => Err(SvError::token(p,'\''.to_string()))
+                    _ => Err(SvError::token(p,"'"))
                 }
             }
             '\\' => {
                 let nc = self.source.get_char().unwrap_or(' ');
                 match nc {
-                    '\n' => Ok(Token::new(TokenKind::LineCont ,"".to_owned(),p)) ,
+                    '\n' => Ok(Token::new(TokenKind::LineCont ,"",p)) ,
                     _ => self.parse_esc_ident()
                 }
             }
@@ -731,7 +822,7 @@ impl<'a,'b> TokenStream<'a,'b> {
             '"' => self.parse_string(false),
             //
             '`' => {
-                let nc = self.source.peek_char().unwrap_or(&' ');
+                let nc = self.source.peek_char().unwrap_or(' ');
                 match nc {
                     '"' => {
                         self.source.get_char().unwrap();
@@ -752,7 +843,7 @@ impl<'a,'b> TokenStream<'a,'b> {
     }
 
     pub fn next_token_processed(&mut self, skip_comment: bool, peek: bool) -> Result<(Token,bool),SvError> {
-        let mut branch_ok = true;
+        let mut branch_ok = self.branch.iter().all(|b| b.state==BranchState::Success);
         loop {
             match self.get_next_token() {
                 Ok(t) => {
@@ -761,7 +852,7 @@ impl<'a,'b> TokenStream<'a,'b> {
                             let b = Vec::new(); // Empty body: unused since top caller
                             let a = HashMap::new(); // Empty argument list: unused since top caller
                             let pos = t.pos.clone();
-                            match self.macro_expand(t, pos, true, &mut b.into_iter(), &a) {
+                            match self.macro_expand(t, pos, true, &mut b.into_iter(), &a, &None) {
                                 Some(Err(t)) => {return Err(t);}
                                 Some(Ok(t)) => {return Ok((t,false));}
                                 // None => {return Err(SvError::null(self.last_pos));}
@@ -786,7 +877,7 @@ impl<'a,'b> TokenStream<'a,'b> {
                         }
                         TokenKind::CompDir => {
                             self.branch_updt(&t)?;
-                            branch_ok = self.branch.iter().fold(true,|s,b| s & (*b==BranchState::Success));
+                            branch_ok = self.branch.iter().all(|b| b.state==BranchState::Success);
                             // rpt_t!(MsgID::DbgStatus, &t, &format!("Token {} => {:?} -> ok={}",t, self.branch, branch_ok));
                         }
                         TokenKind::Comment   if skip_comment => {},
@@ -797,7 +888,12 @@ impl<'a,'b> TokenStream<'a,'b> {
                         }
                     }
                 }
-                Err(e) => {return Err(e);}
+                Err(e) => {
+                    if e.kind==SvErrorKind::Null {
+                        self.branch_check_eof();
+                    }
+                    return Err(e);
+                }
             }
         }
     }
@@ -808,7 +904,9 @@ impl<'a,'b> TokenStream<'a,'b> {
         if self.buffer.len()>self.rd_ptr || (!peek && !self.buffer.is_empty()) {
             if !peek {
                 if self.rd_ptr>0 {self.rd_ptr -= 1;}
-                return Ok(self.buffer.pop_front().unwrap());
+                let t = self.buffer.pop_front().unwrap();
+                self.last_end = t.end;
+                return Ok(t);
             }
             else {
                 let t = self.buffer.get(self.rd_ptr).unwrap();
@@ -821,6 +919,8 @@ impl<'a,'b> TokenStream<'a,'b> {
                 if peek && !peeked {
                     self.buffer.push_back(t.clone());
                     self.rd_ptr += 1;
+                } else if !peek {
+                    self.last_end = t.end;
                 }
                 Ok(t)
             }
@@ -838,6 +938,10 @@ impl<'a,'b> TokenStream<'a,'b> {
 
     pub fn flush(&mut self, nb : usize) {
         // println!("Flushing: Buffer size = {:?} , rd_ptr = {}", self.buffer, self.rd_ptr);
+        let last = if nb==0 {self.buffer.len()} else {nb.min(self.buffer.len())};
+        if last > 0 {
+            self.last_end = self.buffer[last-1].end;
+        }
         if nb==0 || nb>=self.buffer.len() {
             self.buffer.clear();
             self.rd_ptr = 0;
@@ -848,18 +952,13 @@ impl<'a,'b> TokenStream<'a,'b> {
     }
 
     pub fn flush_rd(&mut self) {
+        if self.rd_ptr > 0 {
+            self.last_end = self.buffer[self.rd_ptr-1].end;
+        }
         self.buffer.drain(0..self.rd_ptr);
         self.rd_ptr = 0;
     }
 
-    // Flush to keep nb element
-    pub fn flush_keep(&mut self, mut nb : usize) {
-        let l = self.buffer.len();
-        nb = l - nb;
-        self.buffer.drain(0..nb);
-        self.rd_ptr = 0;
-    }
-
     pub fn rewind(&mut self, nb : usize) {
         self.rd_ptr = if nb==0 || self.rd_ptr < nb {0} else {self.rd_ptr - nb};
     }
@@ -890,6 +989,74 @@ impl<'a,'b> TokenStream<'a,'b> {
         Ok(())
     }
 
+    /// Recover from a syntax error: record it with an error node and skip tokens until
+    /// a synchronization point, i.e. after the next ; or before the next end keyword
+    /// (endmodule, endclass, endfunction, end, ...) at the same nesting level.
+    /// Other errors (end of file, include, ...) are returned as is.
+    pub fn resync(&mut self, node: &mut AstNode, e: SvError) -> Result<(),SvError> {
+        if e.kind != SvErrorKind::Syntax && e.kind != SvErrorKind::Token {
+            return Err(e);
+        }
+        let pos_err = e.token.pos;
+        let mut node_e = AstNode::new(AstNodeKind::Error, pos_err);
+        node_e.end = e.token.end;
+        node_e.attr.insert("msg".to_owned(), format!("{}", e));
+        node.child.push(node_e);
+        self.errors.push(e);
+        // Count the (), {}, begin/end and case/endcase
+        let mut cnt_p = 0;
+        let mut cnt_c = 0;
+        let mut cnt_b = 0;
+        self.rewind(0);
+        loop {
+            let t = match self.next_t(true) {
+                Ok(t) => t,
+                // End of file: let the caller handle it
+                Err(_) => {self.rewind(0); return Ok(());}
+            };
+            let nested = cnt_p>0 || cnt_c>0 || cnt_b>0;
+            match t.kind {
+                TokenKind::ParenLeft  => cnt_p += 1,
+                TokenKind::ParenRight => cnt_p -= 1,
+                TokenKind::CurlyLeft  => cnt_c += 1,
+                TokenKind::CurlyRight => cnt_c -= 1,
+                TokenKind::KwBegin | TokenKind::KwCase => cnt_b += 1,
+                TokenKind::KwEnd | TokenKind::KwEndcase if cnt_b>0 => cnt_b -= 1,
+                TokenKind::SemiColon if !nested => {
+                    self.flush_rd();
+                    return Ok(());
+                }
+                // Stop before an end keyword, unless it caused the error
+                TokenKind::KwEnd         |
+                TokenKind::KwEndcase     |
+                TokenKind::KwJoin        if !nested && t.pos != pos_err => {
+                    self.rewind(1);
+                    return Ok(());
+                }
+                // End of a design element/method: ignore unbalanced parenthesis/braces
+                TokenKind::KwEndClass    |
+                TokenKind::KwEndClocking |
+                TokenKind::KwEndFunction |
+                TokenKind::KwEndGenerate |
+                TokenKind::KwEndGroup    |
+                TokenKind::KwEndIntf     |
+                TokenKind::KwEndModule   |
+                TokenKind::KwEndPackage  |
+                TokenKind::KwEndProperty |
+                TokenKind::KwEndTask     if cnt_b<=0 && t.pos != pos_err => {
+                    self.rewind(1);
+                    return Ok(());
+                }
+                TokenKind::Keyword if (t.value=="endspecify" || t.value=="endprogram" || t.value=="endchecker" || t.value=="endtable" || t.value=="endprimitive" || t.value=="endconfig") && cnt_b<=0 && t.pos != pos_err => {
+                    self.rewind(1);
+                    return Ok(());
+                }
+                _ => {}
+            }
+            self.flush_rd();
+        }
+    }
+
     pub fn peek_until(&mut self, tk_end: TokenKind) -> Result<(),SvError> {
         // Count the (), {} and begin/end
         let mut cnt_p = 0;
@@ -983,7 +1150,16 @@ impl<'a,'b> TokenStream<'a,'b> {
                 _ => {}
             }
             if t.pos.line != line_num || (is_list && cnt_p<=0 && cnt_b<=0 && cnt_c<=0  && (t.kind==TokenKind::Comma || t.kind==TokenKind::ParenRight)) {
-                self.buffer.push_back(t);
+                // End of a macro body: the next line goes through the preprocessor once the macro is defined
+                if !is_list {
+                    self.pending = Some(t);
+                }
+                // Conditional directive must go through the preprocessor, not the buffer
+                else if t.kind==TokenKind::CompDir {
+                    self.branch_updt(&t)?;
+                } else {
+                    self.buffer.push_back(t);
+                }
                 break;
             }
             else if t.kind== TokenKind::LineCont {
@@ -998,11 +1174,78 @@ impl<'a,'b> TokenStream<'a,'b> {
         Ok(v)
     }
 
+    // Peek the next token if it is on the given line, return None at the end of the line.
+    // A token on a following line is kept pending without preprocessing, so that a conditional directive
+    // right after a macro definition is evaluated once the macro is defined
+    pub fn peek_on_line(&mut self, line: u32) -> Result<Option<Token>,SvError> {
+        if self.buffer.len()>self.rd_ptr {
+            let t = self.next_t(true)?;
+            if t.pos.line != line {
+                self.rewind(1);
+                return Ok(None);
+            }
+            return Ok(Some(t));
+        }
+        loop {
+            let t = match self.get_next_token() {
+                Ok(t) => t,
+                Err(e) if e.kind==SvErrorKind::Null => return Ok(None),
+                Err(e) => return Err(e)
+            };
+            if t.pos.line != line {
+                self.pending = Some(t);
+                return Ok(None);
+            }
+            match t.kind {
+                TokenKind::Comment | TokenKind::Attribute => {}
+                _ => {
+                    self.buffer.push_back(t.clone());
+                    self.rd_ptr += 1;
+                    return Ok(Some(t));
+                }
+            }
+        }
+    }
+
     //
     pub fn add_inc(&mut self, fname: &str) {
         self.inc_files.push(fname.to_string());
     }
 
+    // Read raw text until the end of the current line (line return consumed),
+    // starting with the character read after the last token
+    pub fn read_line(&mut self) -> String {
+        let mut s = String::new();
+        let c0 = std::mem::replace(&mut self.last_char, ' ');
+        if c0 == '\n' {
+            return s;
+        }
+        if !c0.is_whitespace() {
+            s.push(c0);
+        }
+        while let Some(c) = self.source.get_char() {
+            if c == '\n' {break;}
+            s.push(c);
+        }
+        self.last_pos = self.source.pos;
+        s
+    }
+
+    // Skip all lines of a protected region until the `pragma protect with the end keyword
+    pub fn skip_protected(&mut self, t: &Token, kw_end: &str) -> Result<(),SvError> {
+        while self.source.peek_char().is_some() {
+            let l = self.read_line();
+            let l = l.trim_start();
+            if let Some(l) = l.strip_prefix("`pragma") {
+                let mut w = l.split(|c: char| c.is_whitespace() || c==',').filter(|w| !w.is_empty());
+                if w.next()==Some("protect") && w.any(|w| w==kw_end) {
+                    return Ok(());
+                }
+            }
+        }
+        Err(SvError::new(SvErrorKind::Eof, t.clone(), "".to_owned()))
+    }
+
     // Debug function
     #[allow(dead_code)]
     pub fn display_status(&self, comment : &str) {
@@ -1013,12 +1256,24 @@ impl<'a,'b> TokenStream<'a,'b> {
         rpt_s!(MsgID::DbgStatus, &s);
     }
 
-    pub fn macro_expand(&mut self, t: Token, pos: Position, top: bool, macro_body: &mut std::vec::IntoIter<Token>, args_caller: &HashMap<String,Vec<Token>>) -> Option<Result<Token,SvError>> {
+    pub fn macro_expand(&mut self, t: Token, pos: Position, top: bool, macro_body: &mut std::vec::IntoIter<Token>, args_caller: &HashMap<String,Vec<Token>>, parent: &Option<Rc<MacroExp>>) -> Option<Result<Token,SvError>> {
         match t.value.as_ref() {
-            "`__FILE__" => Some(Ok(Token::new(TokenKind::Str,self.source.get_filename(),pos))),
-            "`__LINE__" => Some(Ok(Token::new(TokenKind::Integer, pos.line.to_string() ,pos))),
+            // Always expand to the top call site, even when used inside a macro body
+            "`__FILE__" | "`__LINE__" => {
+                let mut nt = if t.value=="`__FILE__" {
+                    Token::new(TokenKind::Str,self.source.get_filename(),pos)
+                } else {
+                    Token::new(TokenKind::Integer, pos.line.to_string() ,pos)
+                };
+                if top {
+                    return Some(Ok(nt));
+                }
+                nt.exp = parent.clone();
+                self.buffer.push_back(nt);
+                None
+            }
             _ => {
-                let macro_name = t.value.clone();
+                let macro_name = t.value.to_string();
                 let def = self.project.defines.get(&macro_name);
                 if def.is_none() {
                     rpt_t!(MsgID::ErrMacro, &t, "unknown");
@@ -1031,6 +1286,18 @@ impl<'a,'b> TokenStream<'a,'b> {
                     return None;
                 }
                 let macro_def = def.unwrap().as_ref().unwrap().clone();
+                // Expansion context: for nested macro the call site is inside the parent macro definition
+                let call_fname = match parent {
+                    Some(p) => p.def_fname.clone(),
+                    None => self.source.get_filename()
+                };
+                let exp = Some(Rc::new(MacroExp {
+                    name: macro_name, call_fname, call_pos: t.pos,
+                    def_fname: macro_def.fname.clone(), def_pos: macro_def.pos,
+                    parent: parent.clone()}));
+                if let (None,Some(e)) = (parent,&exp) {
+                    rpt_add_exp!(self.source.filename, e.clone());
+                }
                 let mut body = macro_def.body.clone().into_iter();
                 let mut args = HashMap::new();
                 // Check for macro param
@@ -1074,9 +1341,9 @@ impl<'a,'b> TokenStream<'a,'b> {
                             }
                             _ if cnt_a == -1 => {return Some(Err(SvError::syntax(t,"Macro call parameter")));}
                             // Replace identifier by argument value
-                            TokenKind::Ident if args_caller.contains_key(&t.value) => {
-                                // println!("[macro_expand] {} : macro {} port {} token {} replaced by {:?}", self.source.get_filename(),macro_name,macro_def.ports[cnt_a as usize].0,t.value,args_caller[&t.value]);
-                                for at in args_caller[&t.value].clone() {
+                            TokenKind::Ident if args_caller.contains_key(t.value.as_str()) => {
+                                // println!("[macro_expand] {} : macro {} port {} token {} replaced by {:?}", self.source.get_filename(),macro_name,macro_def.ports[cnt_a as usize].0,t.value,args_caller[t.value.as_str()]);
+                                for at in args_caller[t.value.as_str()].clone() {
                                     v.push(at);
                                 }
                             }
@@ -1104,23 +1371,25 @@ impl<'a,'b> TokenStream<'a,'b> {
                     match bt.kind {
                         TokenKind::MacroCall => {
                             col_next = bt.pos.col + bt.value.len() as u32;
-                            if bt.value=="`__FILE__" || bt.value=="`__LINE__" || self.project.defines.contains_key(&bt.value) {
-                                if let Some(Err(e)) = self.macro_expand(bt, pos, false,&mut body, &args) {
+                            if bt.value=="`__FILE__" || bt.value=="`__LINE__" || self.project.defines.contains_key(bt.value.as_str()) {
+                                if let Some(Err(e)) = self.macro_expand(bt, pos, false,&mut body, &args, &exp) {
                                     return Some(Err(e));
                                 }
                             } else {
                                 // Unknown Macro ? push as is, hopefully  will be concatenated (might need to add check that this is resolved on next)
-                                self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos});
+                                self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos,end:pos,exp:exp.clone()});
                             }
                         }
                         // Mark an interpolation: next token should be append to previous one
                         TokenKind::IdentInterpolated => {
                             append = true;
-                            // if macro_name=="`MACRONAME" {rpt_s!(MsgID::DbgStatus,&format!("Interpolating {} ({}) to {:?}", bt.value, bt.pos.col, args.get(&bt.value)));}
+                            // if macro_name=="`MACRONAME" {rpt_s!(MsgID::DbgStatus,&format!("Interpolating {} ({}) to {:?}", bt.value, bt.pos.col, args.get(bt.value.as_str())));}
                             let val =
-                                if let Some(ea) = args.get(&bt.value) {
+                                if let Some(ea) = args.get(bt.value.as_str()) {
                                     if ea.len() != 1 {
-                                        return Some(Err(SvError::syntax(bt,"macro expansion (More than one item in the expansion)")));
+                                        // Report at the call site: the body token has the position of the macro definition
+                                        let et = Token{pos,end:pos,exp:exp.clone(),..bt};
+                                        return Some(Err(SvError::syntax(et,"macro expansion (More than one item in the expansion)")));
                                     }
                                     ea[0].value.clone()
                                 } else {
@@ -1130,9 +1399,9 @@ impl<'a,'b> TokenStream<'a,'b> {
                                 // if macro_name=="`MACRONAME" {rpt_s!(MsgID::DbgStatus,&format!("Interpolating {} ({}) to {} and append to {} ({})", bt.value, bt.pos.col, val, lt.value, col_next));}
                                 if (lt.kind == TokenKind::Ident || lt.kind == TokenKind::MacroCall) && col_next == bt.pos.col {
                                     lt.value.push_str(&val);
-                                    if lt.kind == TokenKind::MacroCall && (lt.value=="`__FILE__" || lt.value=="`__LINE__" || self.project.defines.contains_key(&lt.value)) {
+                                    if lt.kind == TokenKind::MacroCall && (lt.value=="`__FILE__" || lt.value=="`__LINE__" || self.project.defines.contains_key(lt.value.as_str())) {
                                         append = false;
-                                        if let Some(Err(e)) = self.macro_expand(lt, pos, false,&mut body, &args) {
+                                        if let Some(Err(e)) = self.macro_expand(lt, pos, false,&mut body, &args, &exp) {
                                             return Some(Err(e));
                                         }
                                     } else {
@@ -1148,19 +1417,19 @@ impl<'a,'b> TokenStream<'a,'b> {
                             col_next = bt.pos.col + 2 + bt.value.len() as u32;
                             continue;
                         }
-                        TokenKind::Ident if args.contains_key(&bt.value) => {
-                            // println!("[macro_expand] Replacing {} by {:?}", bt.value, args[&bt.value]);
-                            let mut arg_iter = args[&bt.value].clone().into_iter();
-                            // for at in args[&bt.value].clone() {
+                        TokenKind::Ident if args.contains_key(bt.value.as_str()) => {
+                            // println!("[macro_expand] Replacing {} by {:?}", bt.value, args[bt.value.as_str()]);
+                            let mut arg_iter = args[bt.value.as_str()].clone().into_iter();
+                            // for at in args[bt.value.as_str()].clone() {
                             while let Some(at) = arg_iter.next() {
                                 // if at.kind==TokenKind::Str {s = format!("{} \"{}\"",s, at.value);} else {s = format!("{} {}",s, at.value);}
                                 match at.kind {
                                     TokenKind::MacroCall => {
-                                        if let Some(Err(e)) = self.macro_expand(at, pos, false, &mut arg_iter, &args) {
+                                        if let Some(Err(e)) = self.macro_expand(at, pos, false, &mut arg_iter, &args, &exp) {
                                             return Some(Err(e));
                                         }
                                     }
-                                    _ => self.buffer.push_back(Token{kind:at.kind,value:at.value,pos:pos})
+                                    _ => self.buffer.push_back(Token{kind:at.kind,value:at.value,pos:pos,end:pos,exp:exp.clone()})
                                 }
                             }
                             col_next = bt.pos.col + bt.value.len() as u32;
@@ -1192,37 +1461,30 @@ impl<'a,'b> TokenStream<'a,'b> {
                                         }
                                     }
                                     if no_space || append {
-                                        lt.value.push_str(&bt.value);
+                                        lt.value.push_str(bt.value.as_str());
                                     }
-                                    if lt.kind == TokenKind::MacroCall && (bt.value=="`__FILE__" || bt.value=="`__LINE__" || self.project.defines.contains_key(&lt.value)) {
-                                        if let Some(Err(e)) = self.macro_expand(lt, pos, false,&mut body, &args) {
+                                    if lt.kind == TokenKind::MacroCall && (bt.value=="`__FILE__" || bt.value=="`__LINE__" || self.project.defines.contains_key(lt.value.as_str())) {
+                                        if let Some(Err(e)) = self.macro_expand(lt, pos, false,&mut body, &args, &exp) {
                                             return Some(Err(e));
                                         }
                                     } else {
                                         self.buffer.push_back(lt);
                                         if !no_space && !append {
-                                            self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos});
+                                            self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos,end:pos,exp:exp.clone()});
                                         }
                                     }
                                 }
                                 continue;
                             }
                             if prev_was_inc && bt.kind==TokenKind::Str {
-                                if self.project.ast_inc.contains_key(&bt.value) {
-                                    // println!("[macro_expand] Found include {} : updating defines", bt.value);
-                                    for (k,v) in self.project.ast_inc[&bt.value].defines.clone() {
-                                        self.project.defines.insert(k,v);
-                                    }
-                                } else {
-                                    // println!("[macro_expand] Found include {} : compiling", bt.value);
-                                    if let Err(e) = self.project.compile_inc(bt.value.clone(), t.clone()) {
-                                        return Some(Err(e));
-                                    }
+                                let cur_file = self.source.filename.clone();
+                                if let Err(e) = self.project.compile_inc(&bt.value, false, &cur_file, t.clone()) {
+                                    return Some(Err(e));
                                 }
                             }
                             prev_was_inc = bt.kind == TokenKind::Macro && bt.value=="`include";
                             // if bt.kind==TokenKind::Str {s = format!("{} \"{}\"",s, bt.value);} else {s = format!("{} {}",s, bt.value);}
-                            self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos});
+                            self.buffer.push_back(Token{kind:bt.kind,value:bt.value,pos:pos,end:pos,exp:exp.clone()});
                         }
                     }
                 }
@@ -1235,55 +1497,41 @@ impl<'a,'b> TokenStream<'a,'b> {
 
     pub fn branch_updt(&mut self, t: &Token) -> Result<(),SvError> {
         match t.value.as_ref() {
-            "`ifndef" => {
-                match self.get_next_token() {
-                    Err(e) => Err(e),
-                    Ok(t) if t.kind == TokenKind::Ident => {
-                        self.branch.push(if self.project.defines.contains_key(&t.value) {BranchState::Fail} else {BranchState::Success});
-                        Ok(())
-                    }
-                    Ok(t) => Err(SvError::syntax(t,"Expecting Identifier"))
-                }
-            }
-            "`ifdef"  => {
-                match self.get_next_token() {
-                    Err(e) => Err(e),
-                    Ok(t) if t.kind == TokenKind::Ident => {
-                        self.branch.push(if self.project.defines.contains_key(&t.value) {BranchState::Success} else {BranchState::Fail});
-                        Ok(())
-                    }
-                    Ok(t) => Err(SvError::syntax(t,"Expecting Identifier"))
-                }
+            "`ifndef" | "`ifdef" => {
+                let mut cond = self.branch_cond()?;
+                if t.value=="`ifndef" {cond = !cond;}
+                let state = if cond {BranchState::Success} else {BranchState::Fail};
+                self.branch.push(BranchInfo{state, has_else: false, token: t.clone()});
+                Ok(())
             }
             "`elsif"  => {
-                match self.get_next_token() {
-                    Err(e) => Err(e),
-                    Ok(t) if t.kind == TokenKind::Ident => {
-                        if let Some(s) = self.branch.last_mut() {
-                            *s = if *s!=BranchState::Fail {BranchState::Done}
-                                else if self.project.defines.contains_key(&t.value) {BranchState::Success}
-                                else {BranchState::Fail};
-                            Ok(())
-                        } else {
-                            Err(SvError::syntax(t,"No matching `ifedf"))
-                        }
+                let cond = self.branch_cond()?;
+                match self.branch.last_mut() {
+                    Some(b) if b.has_else => Err(SvError::syntax(t.clone(),"conditional directive: `elsif after `else")),
+                    Some(b) => {
+                        b.state = if b.state!=BranchState::Fail {BranchState::Done}
+                            else if cond {BranchState::Success}
+                            else {BranchState::Fail};
+                        Ok(())
                     }
-                    Ok(t) => Err(SvError::syntax(t,"Expecting Identifier"))
+                    None => Err(SvError::syntax(t.clone(),"conditional directive: no matching `ifdef"))
                 }
             }
             "`else"   => {
-                if let Some(s) = self.branch.last_mut() {
-                    *s = if *s==BranchState::Fail {BranchState::Success} else {BranchState::Fail};
-                    Ok(())
-                } else {
-                    Err(SvError::syntax(t.clone(),"No matching `ifedf"))
+                match self.branch.last_mut() {
+                    Some(b) if b.has_else => Err(SvError::syntax(t.clone(),"conditional directive: multiple `else")),
+                    Some(b) => {
+                        b.has_else = true;
+                        b.state = if b.state==BranchState::Fail {BranchState::Success} else {BranchState::Done};
+                        Ok(())
+                    }
+                    None => Err(SvError::syntax(t.clone(),"conditional directive: no matching `ifdef"))
                 }
             }
             "`endif"  => {
-                if self.branch.len() == 0 {
-                    Err(SvError::syntax(t.clone(),"No matching `ifedf"))
+                if self.branch.pop().is_none() {
+                    Err(SvError::syntax(t.clone(),"conditional directive: no matching `ifdef"))
                 } else {
-                    self.branch.pop();
                     Ok(())
                 }
             }
@@ -1291,4 +1539,113 @@ impl<'a,'b> TokenStream<'a,'b> {
         }
     }
 
+    /// Parse and evaluate the condition of a `ifdef/`ifndef/`elsif:
+    /// either a single macro name or a parenthesized expression (IEEE 1800-2023)
+    fn branch_cond(&mut self) -> Result<bool,SvError> {
+        let t = self.next_cond_token()?;
+        match t.kind {
+            TokenKind::Ident => Ok(self.is_defined(t.value.as_str())),
+            TokenKind::ParenLeft => {
+                // Collect all tokens until the matching parenthesis
+                let mut v = vec![t];
+                let mut cnt = 1;
+                while cnt > 0 {
+                    let nt = self.next_cond_token()?;
+                    match nt.kind {
+                        TokenKind::ParenLeft  => cnt += 1,
+                        TokenKind::ParenRight => cnt -= 1,
+                        _ => {}
+                    }
+                    v.push(nt);
+                }
+                let mut it = v.into_iter().peekable();
+                let r = self.cond_expr(&mut it)?;
+                match it.next() {
+                    Some(nt) => Err(SvError::syntax(nt,"conditional directive expression")),
+                    None => Ok(r)
+                }
+            }
+            _ => Err(SvError::syntax(t,"conditional directive. Expecting identifier or (")),
+        }
+    }
+
+    /// Next token of a conditional directive expression, ignoring comments
+    fn next_cond_token(&mut self) -> Result<Token,SvError> {
+        loop {
+            let t = self.get_next_token()?;
+            match t.kind {
+                TokenKind::Comment | TokenKind::LineCont => {}
+                _ => return Ok(t)
+            }
+        }
+    }
+
+    fn is_defined(&self, name: &str) -> bool {
+        self.project.defines.contains_key(&format!("`{}",name))
+    }
+
+    /// Conditional expression: implication and equivalence have the lowest
+    /// precedence and are right associative
+    fn cond_expr(&self, it: &mut CondTokenIter) -> Result<bool,SvError> {
+        let lhs = self.cond_or(it)?;
+        match it.peek().map(|t| t.kind.clone()) {
+            Some(TokenKind::OpImpl) => {
+                it.next();
+                let rhs = self.cond_expr(it)?;
+                Ok(!lhs || rhs)
+            }
+            Some(TokenKind::OpEquiv) => {
+                it.next();
+                let rhs = self.cond_expr(it)?;
+                Ok(lhs == rhs)
+            }
+            _ => Ok(lhs)
+        }
+    }
+
+    fn cond_or(&self, it: &mut CondTokenIter) -> Result<bool,SvError> {
+        let mut r = self.cond_and(it)?;
+        while it.peek().map(|t| t.kind==TokenKind::OpLogicOr) == Some(true) {
+            it.next();
+            // Evaluate both side to catch syntax error
+            let rhs = self.cond_and(it)?;
+            r = r || rhs;
+        }
+        Ok(r)
+    }
+
+    fn cond_and(&self, it: &mut CondTokenIter) -> Result<bool,SvError> {
+        let mut r = self.cond_primary(it)?;
+        while it.peek().map(|t| t.kind==TokenKind::OpLogicAnd) == Some(true) {
+            it.next();
+            let rhs = self.cond_primary(it)?;
+            r = r && rhs;
+        }
+        Ok(r)
+    }
+
+    fn cond_primary(&self, it: &mut CondTokenIter) -> Result<bool,SvError> {
+        let t = it.next().ok_or_else(|| SvError::eof(self.last_pos))?;
+        match t.kind {
+            TokenKind::Ident  => Ok(self.is_defined(t.value.as_str())),
+            TokenKind::OpBang => Ok(!self.cond_primary(it)?),
+            TokenKind::ParenLeft => {
+                let r = self.cond_expr(it)?;
+                match it.next() {
+                    Some(nt) if nt.kind==TokenKind::ParenRight => Ok(r),
+                    Some(nt) => Err(SvError::syntax(nt,"conditional directive expression. Expecting )")),
+                    None => Err(SvError::eof(self.last_pos))
+                }
+            }
+            _ => Err(SvError::syntax(t,"conditional directive expression. Expecting identifier, ! or (")),
+        }
+    }
+
+    /// Report any conditional directive not closed when reaching the end of file
+    pub fn branch_check_eof(&mut self) {
+        for b in self.branch.drain(..) {
+            rpt_t!(MsgID::ErrSyntax, &b.token, &format!("Unterminated {} : missing `endif at end of file", b.token.value));
+        }
+    }
+
 }
@@ -7,12 +7,15 @@ mod lex;
 mod ast;
 mod comp;
 mod project;
+mod preprocess;
+mod dpiheader;
 
 // #[macro_use]
 #[allow(unused_imports)]
 use std::{
     path::PathBuf,
-    io::{BufWriter, Write},
+    fs::File,
+    io::{self, BufWriter, Write},
     process,
 };
 
@@ -21,6 +24,9 @@ use structopt::StructOpt;
 use structopt::clap::{App, AppSettings};
 
 use project::Project;
+use preprocess::Preprocessor;
+use reporter::{REPORTER, MsgID, Severity};
+use lex::{token::KwVersion, source::path_display};
 
 macro_rules! exit {
     ($str:expr, $($var:expr),+) => {{
@@ -39,12 +45,48 @@ struct Cli {
     /// Source list containing the list of file to compile
     #[structopt(parse(from_os_str), short = "f", long = "filelist")]
     srclist: Option<PathBuf>,
+    /// Library map file: library declarations (library name file_spec, ...;) assigning files to libraries
+    #[structopt(parse(from_os_str), long = "libmap")]
+    libmap: Option<PathBuf>,
+    /// Configuration used to bind the instances (default to the only configuration defined)
+    #[structopt(long = "config")]
+    config: Option<String>,
     /// Include directories
     #[structopt(short = "I", long = "incdir")]
     incdir: Vec<PathBuf>,
+    /// Project-wide define : NAME or NAME=VALUE
+    #[structopt(short = "D", long = "define", number_of_values = 1)]
+    defines: Vec<String>,
     /// Only parse file, no elaboration/type check/...
     #[structopt( long = "parse_only")]
     parse_only: bool,
+    /// Preprocess only: output the code after macro expansion, include and conditional compilation
+    #[structopt(short = "E", long = "preprocess")]
+    preprocess: bool,
+    /// Output file for the preprocessed code (default to stdout)
+    #[structopt(parse(from_os_str), short = "o", long = "output")]
+    output: Option<PathBuf>,
+    /// Sort files in dependency order (package before import, module before instance, ...)
+    #[structopt( long = "auto-order")]
+    auto_order: bool,
+    /// Print the compilation order and exit
+    #[structopt( long = "print-order")]
+    print_order: bool,
+    /// Compilation unit: per-file or single (defines and declarations visible in following files)
+    #[structopt(long = "compilation-unit", possible_values = &["single","per-file"], default_value = "per-file")]
+    compilation_unit: String,
+    /// Default keyword version (can be changed in the code with `begin_keywords)
+    #[structopt(long = "std", possible_values = &["1364-1995","1364-2001","1364-2001-noconfig","1364-2005","1800-2005","1800-2009","1800-2012","1800-2017","1800-2023"], default_value = "1800-2017")]
+    std: String,
+    /// Report implicit net declarations as error instead of warning
+    #[structopt( long = "implicit-net-error")]
+    implicit_net_error: bool,
+    /// Lossless parsing: print the AST of each file with comments attached and exit
+    #[structopt( long = "cst")]
+    cst: bool,
+    /// Write a C header with the prototypes of the DPI-C imported/exported functions and tasks
+    #[structopt(parse(from_os_str), long = "dpi-header")]
+    dpi_header: Option<PathBuf>,
     /// Compile a UVM library
     #[structopt( long = "comp_uvm")]
     comp_uvm: bool,
@@ -62,14 +104,61 @@ fn main() {
     else if let Some(srclist) = args.srclist  {
         proj = Project::from_srcfile(srclist, args.incdir, args.comp_uvm).unwrap_or_else(|e| exit!("{:?} ",e));
     }
+    // Library map only: files are taken from the library declarations
+    else if args.libmap.is_some() {
+        proj = Project::from_list(Vec::new(), args.incdir, args.comp_uvm).unwrap_or_else(|e| exit!("{:?} ",e));
+    }
     // No file or source list provided -> display help message
     else {
         App::new("myprog").setting(AppSettings::ArgRequiredElseHelp);
         return;
     }
 
-    proj.compile_all();
-    if args.parse_only {
+    if let Some(m) = args.libmap {
+        proj.parse_libmap(m, &mut Vec::new()).unwrap_or_else(|e| exit!("{:?} ",e));
+    }
+    proj.config = args.config;
+    proj.single_unit = args.compilation_unit == "single";
+    proj.kw_version = KwVersion::from_spec(&args.std).unwrap_or(KwVersion::Sv2017);
+    if args.implicit_net_error {
+        rpt_set_severity!(MsgID::WarnImplicitNet, Severity::Error);
+    }
+    for d in &args.defines {
+        proj.add_define(d);
+    }
+    if args.preprocess {
+        let mut w : Box<dyn Write> = match args.output {
+            Some(f) => Box::new(BufWriter::new(File::create(f).unwrap_or_else(|e| exit!("{:?} ",e)))),
+            None => Box::new(BufWriter::new(io::stdout())),
+        };
+        Preprocessor::new(&mut w).run(&mut proj);
+        return;
+    }
+    proj.keep_trivia = args.cst;
+    if args.auto_order {
+        proj.compile_ordered();
+    } else {
+        proj.compile_all();
+    }
+    if args.cst {
+        for ast in &proj.ast_list {
+            println!("// {}\n{}", ast.filename.display(), ast.tree);
+            // Sanity check: the source rebuilt from the CST must match the file
+            if let Ok(code) = std::fs::read_to_string(&ast.filename) {
+                if code != ast.to_source() {
+                    rpt_s!(MsgID::WarnRoundTrip, &path_display(&ast.filename));
+                }
+            }
+        }
+        return;
+    }
+    if args.print_order {
+        for f in &proj.filelist {
+            println!("{}", f.display());
+        }
+        return;
+    }
+    if args.parse_only && args.dpi_header.is_none() {
         return;
     }
     // Debug : save AST
@@ -77,6 +166,15 @@ fn main() {
     // let mut w = BufWriter::new(&fw);
     // write!(&mut w, "{:#?}", proj.ast_list).unwrap();
 
+    // Library of all design elements: needed to resolve the user types of DPI functions
+    let mut lib = proj.compile_lib();
+    if let Some(f) = args.dpi_header {
+        let mut w = BufWriter::new(File::create(f).unwrap_or_else(|e| exit!("{:?} ",e)));
+        dpiheader::write_dpi_header(&proj.ast_list, &proj.ast_inc, &lib, &mut w).unwrap_or_else(|e| exit!("{:?} ",e));
+    }
+    if args.parse_only {
+        return;
+    }
     // Analyze ASTs
-    proj.elaborate();
+    proj.elaborate(&mut lib);
 }
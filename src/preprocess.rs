@@ -0,0 +1,219 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+use std::{
+    path::PathBuf,
+    io::Write,
+};
+
+use crate::ast::{parse_macro, astnode::{AstNode,AstNodeKind}};
+use crate::error::{SvError,SvErrorKind};
+use crate::lex::{
+    position::Position,
+    source::{Source,path_display},
+    token::{Token,TokenKind},
+    token_stream::TokenStream};
+use crate::project::Project;
+use crate::reporter::{REPORTER, MsgID};
+
+// Maximum number of empty lines inserted to keep the line alignment before using a `line directive
+const MAX_EMPTY_LINES : u32 = 8;
+
+/// Write the source code as seen by the parser: macros expanded, includes inlined
+/// and conditional compilation resolved, with `line directives to track original positions.
+pub struct Preprocessor<'w> {
+    out : &'w mut dyn Write,
+    /// Line in the original file of the current output line
+    line : u32,
+    /// Column following the last token written (0 at start of line)
+    col : u32,
+    /// Stack of include files being preprocessed
+    inc_stack : Vec<PathBuf>,
+}
+
+impl<'w> Preprocessor<'w> {
+
+    pub fn new(out: &'w mut dyn Write) -> Preprocessor<'w> {
+        Preprocessor {out, line: 1, col: 0, inc_stack: Vec::new()}
+    }
+
+    // Preprocess all files from the project
+    pub fn run(&mut self, proj: &mut Project) {
+        proj.defines = proj.defines_init.clone();
+        for fname in proj.filelist.clone() {
+            // Ignore VHDL files from the source list
+            if let Some(ext) = fname.extension() {
+                if ext == "vhd" || ext == "vhdl" {continue;}
+            }
+            rpt_set_fname!(&fname);
+            // Each file is its own compilation unit: reinit with project-wide define
+            if !proj.single_unit {
+                proj.defines = proj.defines_init.clone();
+            }
+            proj.cur_dir = fname.clone();
+            proj.cur_dir.pop();
+            if let Err(e) = self.file(proj, fname, 0) {
+                rpt_e!(e);
+            }
+        }
+        if let Err(e) = self.out.flush() {
+            rpt_e!(SvError::from(e));
+        }
+    }
+
+    // Preprocess one file: level is the one used in the `line directive (0 for top file, 1 for include)
+    fn file(&mut self, proj: &mut Project, fname: PathBuf, level: u8) -> Result<(),SvError> {
+        let mut src = match Source::from_file(fname.clone()) {
+            Ok(src) => src,
+            Err(_) => {
+                rpt_s!(MsgID::ErrFile, &path_display(fname));
+                return Ok(());
+            }
+        };
+        self.line_directive(1, &fname, level)?;
+        let mut ts = TokenStream::new(&mut src, proj);
+        let mut node = AstNode::new(AstNodeKind::Root, Position::new());
+        // Filename used in the output, changed by `line directive
+        let mut fname_out = fname.clone();
+        loop {
+            let t = match ts.next_t(true) {
+                Ok(t) => t,
+                Err(e) if e.kind==SvErrorKind::Null => break,
+                Err(e) => return Err(e)
+            };
+            match t.kind {
+                // Macro definition are consumed: only their expansion appears in the output
+                TokenKind::Macro if t.value=="`define" => parse_macro(&mut ts, &mut node)?,
+                TokenKind::Macro if t.value=="`include" => {
+                    ts.flush_rd();
+                    if let Err(e) = self.include(&mut ts, &t) {
+                        rpt_e!(e);
+                    }
+                    self.line_directive(t.pos.line+1, &fname_out, 2)?;
+                }
+                // Line directive: kept in the output, the following tokens positions being remapped
+                TokenKind::Macro if t.value=="`line" => {
+                    parse_macro(&mut ts, &mut node)?;
+                    if let Some(n) = node.child.pop() {
+                        fname_out = PathBuf::from(&n.attr["filename"]);
+                        let level = n.attr["level"].parse::<u8>().unwrap_or(0);
+                        self.line_directive(n.attr["line"].parse::<u32>().unwrap_or(1), &fname_out, level)?;
+                    }
+                }
+                // Pragma: kept as is, except protected region which are removed
+                TokenKind::Macro if t.value=="`pragma" => {
+                    parse_macro(&mut ts, &mut node)?;
+                    if let Some(n) = node.child.pop() {
+                        if !n.attr.contains_key("protected") {
+                            self.token(&t, &fname_out)?;
+                            let txt = format!(" {} {}", n.attr["pragma_name"], n.attr["pragma_expr"]);
+                            write!(self.out, "{}", txt.trim_end())?;
+                            self.col += txt.len() as u32;
+                        }
+                    }
+                }
+                _ => {
+                    ts.flush_rd();
+                    self.token(&t, &fname_out)?;
+                }
+            }
+        }
+        if self.col > 0 {
+            writeln!(self.out)?;
+            self.col = 0;
+            self.line += 1;
+        }
+        Ok(())
+    }
+
+    // Inline an include file
+    fn include(&mut self, ts: &mut TokenStream, t_inc: &Token) -> Result<(),SvError> {
+        let mut t = ts.next_t(false)?;
+        let mut angle = false;
+        match t.kind {
+            TokenKind::Str => {}
+            // `include <file> : file name is split in multiple token
+            TokenKind::OpLT => {
+                let mut name = String::new();
+                loop {
+                    let nt = ts.next_t(false)?;
+                    if nt.kind==TokenKind::OpGT {break;}
+                    if nt.pos.line != t.pos.line {
+                        return Err(SvError::syntax(nt, "include directive"));
+                    }
+                    name.push_str(&nt.value);
+                }
+                t = Token::new(TokenKind::Str, name, t.pos);
+                angle = true;
+            }
+            _ => return Err(SvError::syntax(t, "include directive"))
+        }
+        // Include provided internally (e.g. UVM macros): keep the directive and import its defines
+        if ts.project.ast_inc.contains_key(t.value.as_str()) {
+            for (k,v) in ts.project.ast_inc[t.value.as_str()].defines.clone() {
+                ts.project.defines.insert(k,v);
+            }
+            self.token(t_inc, &ts.source.filename)?;
+            return self.token(&t, &ts.source.filename);
+        }
+        let f = match ts.project.find_inc(&t.value, angle, &ts.source.filename) {
+            Some(f) => f,
+            None => return Err(SvError::new(SvErrorKind::Include, t, "".to_string()))
+        };
+        if let Some(i) = self.inc_stack.iter().position(|x| x==&f) {
+            let mut chain : Vec<String> = self.inc_stack[i..].iter().map(path_display).collect();
+            chain.push(path_display(&f));
+            return Err(SvError::new(SvErrorKind::IncCycle, t, chain.join(" -> ")));
+        }
+        // Errors inside the include are reported with the include filename
+        rpt_push_fname!(f);
+        self.inc_stack.push(f.clone());
+        if let Err(e) = self.file(ts.project, f, 1) {
+            rpt_e!(e);
+        }
+        self.inc_stack.pop();
+        rpt_pop_fname!();
+        Ok(())
+    }
+
+    // Write a token, trying to keep the original line and column
+    fn token(&mut self, t: &Token, fname: &PathBuf) -> Result<(),SvError> {
+        let txt = match t.kind {
+            TokenKind::Str => format!("\"{}\"", t.value),
+            TokenKind::IdentInterpolated => format!("``{}", t.value),
+            TokenKind::LineCont => return Ok(()),
+            _ => t.value.to_string()
+        };
+        if t.pos.line > self.line {
+            if t.pos.line - self.line > MAX_EMPTY_LINES {
+                self.line_directive(t.pos.line, fname, 0)?;
+            } else {
+                for _ in self.line..t.pos.line {
+                    writeln!(self.out)?;
+                }
+                self.line = t.pos.line;
+                self.col = 0;
+            }
+        }
+        if self.col == 0 {
+            write!(self.out, "{:1$}", "", t.pos.col.saturating_sub(1) as usize)?;
+        } else if t.pos.col != self.col {
+            write!(self.out, " ")?;
+        }
+        write!(self.out, "{}", txt)?;
+        self.col = t.pos.col + txt.len() as u32;
+        self.line += txt.matches('\n').count() as u32;
+        Ok(())
+    }
+
+    // Write a `line directive: the next line written correspond to the line in the file
+    fn line_directive(&mut self, line: u32, fname: &PathBuf, level: u8) -> Result<(),SvError> {
+        if self.col > 0 {
+            writeln!(self.out)?;
+        }
+        writeln!(self.out, "`line {} \"{}\" {}", line, path_display(fname), level)?;
+        self.line = line;
+        self.col = 0;
+        Ok(())
+    }
+}
@@ -2,19 +2,19 @@
 // Copyright (c) 2019, clams@mail.com
 
 use crate::error::{SvError,SvErrorKind};
-use crate::ast::{Ast,Defines,uvm_macro};
+use crate::ast::{Ast,Defines,MacroDef,uvm_macro};
+use crate::ast::astnode::{AstNode,AstNodeKind};
 
 use std::{
-    path::PathBuf,
-    collections::{HashSet,HashMap},
-    io::{BufReader, BufRead},
-    fs::{File,metadata},
+    path::{Path,PathBuf},
+    collections::{BTreeSet,HashSet,HashMap},
+    fs::{metadata,read_to_string},
 };
 
 use crate::lex::{
     source::{Source,path_display},
-    token::Token,
-    // position::Position,
+    token::{Token,KwVersion},
+    position::Position,
     token_stream::TokenStream};
 
 use crate::comp::comp_lib::CompLib;
@@ -22,13 +22,28 @@ use crate::comp::comp_lib::CompLib;
 use crate::reporter::{REPORTER/*, Severity*/, MsgID};
 
 pub struct Project {
-    pub filelist : HashSet<PathBuf>,
-    pub incdir : HashSet<PathBuf>,
+    pub filelist : Vec<PathBuf>,
+    pub incdir : Vec<PathBuf>,
     pub defines : Defines,
+    pub defines_init : Defines,
     pub ast_list : Vec<Ast>,
     pub ast_inc : HashMap<String,Box<Ast>>,
     pub cur_dir : PathBuf,
     pub comp_uvm : bool,
+    pub libfile : Vec<PathBuf>,
+    pub libdir : Vec<PathBuf>,
+    pub libext : Vec<String>,
+    // Library map: name of each library with its files, in declaration order
+    pub libmap : Vec<(String,Vec<PathBuf>)>,
+    // Configuration used to bind the instances (default to the only one defined)
+    pub config : Option<String>,
+    pub single_unit : bool,
+    // Keep comments and whitespace in the AST (lossless mode)
+    pub keep_trivia : bool,
+    // Default keyword version (changed locally by `begin_keywords)
+    pub kw_version : KwVersion,
+    // Stack of include files being compiled
+    inc_stack : Vec<PathBuf>,
 }
 
 impl Project {
@@ -36,29 +51,33 @@ impl Project {
     // Create a project based on a vector of file/dir
     pub fn from_list(list: Vec<PathBuf>, incs: Vec<PathBuf>, comp_uvm: bool) -> Result<Project,std::io::Error> {
         // Create the include dir list
-        let mut incdir = HashSet::new();
+        let mut incdir = Vec::new();
         for d in incs {
-            incdir.insert(d);
+            if !incdir.contains(&d) {incdir.push(d);}
         }
         // println!("[Project] Include Dir = {:?}", incdir);
         // Create the file list: if the input vec contains a dir, push all file from the fir in the list
-        let mut filelist = HashSet::new();
+        let mut filelist = Vec::new();
         for f in list {
             let md = metadata(&f)?;
             if md.is_dir() {
-                if !incdir.contains(&f) {incdir.insert(f.clone());}
-                let files = std::fs::read_dir(&f)?;
-                files
+                if !incdir.contains(&f) {incdir.push(f.clone());}
+                let mut files : Vec<PathBuf> = std::fs::read_dir(&f)?
                     .filter_map(Result::ok)
-                    .filter(|d| if let Some(e) = d.path().extension() { e == "v" || e == "sv" } else {false})
-                    .for_each(|fd| {filelist.insert(fd.path());});
+                    .map(|d| d.path())
+                    .filter(|p| if let Some(e) = p.extension() { e == "v" || e == "sv" } else {false})
+                    .collect();
+                files.sort();
+                for fd in files {
+                    if !filelist.contains(&fd) {filelist.push(fd);}
+                }
             } else {
                 if let Some(d) = f.parent() {
                     if !incdir.contains(&d.to_path_buf()) {
-                        incdir.insert(d.to_path_buf());
+                        incdir.push(d.to_path_buf());
                     }
                 }
-                filelist.insert(f);
+                if !filelist.contains(&f) {filelist.push(f);}
             }
         }
         // println!("[Project] Filelist = {:?}", filelist);
@@ -71,67 +90,263 @@ impl Project {
             filelist,
             incdir,
             defines: HashMap::new(),
+            defines_init: HashMap::new(),
             cur_dir: PathBuf::new(),
             ast_list: Vec::new(),
             ast_inc, comp_uvm,
+            libfile: Vec::new(),
+            libdir: Vec::new(),
+            libext: Vec::new(),
+            libmap: Vec::new(),
+            config: None,
+            single_unit: false,
+            keep_trivia: false,
+            kw_version: KwVersion::Sv2017,
+            inc_stack: Vec::new(),
         })
     }
 
     // Create a project based on a source list (in .f format)
-    #[allow(unused_mut)]
     pub fn from_srcfile(srclist: PathBuf, incs: Vec<PathBuf>, comp_uvm: bool) -> Result<Project,std::io::Error> {
         // Create the include dir list
-        let mut incdir = HashSet::new();
+        let mut incdir = Vec::new();
         for d in incs {
-            incdir.insert(d);
+            if !incdir.contains(&d) {incdir.push(d);}
+        }
+        let mut ast_inc = HashMap::new();
+        if !comp_uvm {
+            ast_inc.insert("uvm_macros.svh".to_string(),uvm_macro::get_uvm_macro());
         }
+        let mut proj = Project {
+            filelist: Vec::new(),
+            incdir,
+            defines: HashMap::new(),
+            defines_init: HashMap::new(),
+            ast_list: Vec::new(),
+            cur_dir: PathBuf::new(),
+            ast_inc, comp_uvm,
+            libfile: Vec::new(),
+            libdir: Vec::new(),
+            libext: Vec::new(),
+            libmap: Vec::new(),
+            config: None,
+            single_unit: false,
+            keep_trivia: false,
+            kw_version: KwVersion::Sv2017,
+            inc_stack: Vec::new(),
+        };
         // Parse the source list to extract files, incdir, defines, ...
-        let mut filelist = HashSet::new();
-        let f = File::open(srclist.clone())?;
-        let mut src_path = srclist;
-        src_path.pop();
-        let file = BufReader::new(&f);
-        // TODO: use collect and filter to create the vector
-        //       and also handle -f and --inc cases
-        for (_num, line) in file.lines().enumerate() {
-            if let Ok(l) = line {
-                if l.is_empty() || l.starts_with('#') {
-                    continue;
-                }
-                let mut p = src_path.clone();
+        proj.parse_srclist(srclist, true, &mut Vec::new())?;
+        Ok(proj)
+    }
+
+    // Parse a source list: files, nested source list (-f/-F), libraries (-v/-y/+libext+),
+    // include directories (+incdir+) and defines (+define+).
+    // Relative paths are resolved from the directory of the source list (top list and -F)
+    // or from the current directory (-f).
+    fn parse_srclist(&mut self, srclist: PathBuf, rel_to_list: bool, stack: &mut Vec<PathBuf>) -> Result<(),std::io::Error> {
+        let srclist = srclist.canonicalize()?;
+        if stack.contains(&srclist) {
+            rpt_s!(MsgID::ErrInvalid, &format!("Recursive source list {}", path_display(&srclist)));
+            return Ok(());
+        }
+        let content = read_to_string(&srclist)?;
+        let mut src_path = srclist.clone();
+        if rel_to_list {
+            src_path.pop();
+        } else {
+            src_path = PathBuf::new();
+        }
+        stack.push(srclist);
+        let mut words = srclist_words(&content).into_iter().map(|w| expand_env(&w));
+        while let Some(w) = words.next() {
+            match w.as_ref() {
+                // Options with an argument
+                "-f" | "-F" | "-v" | "-y" | "-libmap" => {
+                    let arg = match words.next() {
+                        Some(a) => a,
+                        None => {
+                            rpt_s!(MsgID::ErrInvalid, &format!("Missing argument for option {} in source list", w));
+                            break;
+                        }
+                    };
+                    let p = src_path.join(arg);
+                    match w.as_ref() {
+                        "-f" | "-F" => {
+                            if self.parse_srclist(p.clone(), w=="-F", stack).is_err() {
+                                rpt_s!(MsgID::ErrFile,&path_display(&p));
+                            }
+                        }
+                        "-libmap" => {
+                            if self.parse_libmap(p.clone(), &mut Vec::new()).is_err() {
+                                rpt_s!(MsgID::ErrFile,&path_display(&p));
+                            }
+                        }
+                        "-v" => {
+                            if let Ok(pc) = p.canonicalize() {
+                                if !self.libfile.contains(&pc) {self.libfile.push(pc);}
+                            } else {
+                                rpt_s!(MsgID::ErrFile,&path_display(&p));
+                            }
+                        }
+                        _ => {
+                            if let Ok(pc) = p.canonicalize() {
+                                if !self.libdir.contains(&pc) {self.libdir.push(pc);}
+                            } else {
+                                rpt_s!(MsgID::ErrFile,&path_display(&p));
+                            }
+                        }
+                    }
+                }
                 // Update include directory
-                if l.starts_with("+incdir+") {
-                    let d = l.trim_start_matches("+incdir+").trim();
-                    p.push(d);
-                    // incdir.insert(p);
+                _ if w.starts_with("+incdir+") => {
+                    for d in w.trim_start_matches("+incdir+").split('+').filter(|d| !d.is_empty()) {
+                        let p = src_path.join(d);
+                        if let Ok(pc) = p.canonicalize() {
+                            if !self.incdir.contains(&pc) {self.incdir.push(pc);}
+                        } else {
+                            rpt_s!(MsgID::ErrFile,&path_display(&p));
+                        }
+                    }
+                }
+                // Define(s): +define+NAME[=VALUE][+NAME[=VALUE]]
+                _ if w.starts_with("+define+") => {
+                    for d in w.trim_start_matches("+define+").split('+').filter(|d| !d.is_empty()) {
+                        self.add_define(d);
+                    }
+                }
+                // Library file extension: +libext+.v+.sv
+                _ if w.starts_with("+libext+") => {
+                    for e in w.trim_start_matches("+libext+").split('+').filter(|e| !e.is_empty()) {
+                        if !self.libext.iter().any(|x| x==e) {self.libext.push(e.to_owned());}
+                    }
+                }
+                // Any other option is ignored
+                _ if w.starts_with('-') || w.starts_with('+') => {
+                    rpt_s!(MsgID::DbgSkip, &format!("Ignoring option {} in source list", w));
+                }
+                // Add a file, using absolute path to avoid duplicate
+                _ => {
+                    let p = src_path.join(&w);
                     if let Ok(pc) = p.canonicalize() {
-                        incdir.insert(pc);
+                        if !self.filelist.contains(&pc) {self.filelist.push(pc);}
                     } else {
                         rpt_s!(MsgID::ErrFile,&path_display(&p));
                     }
-                    continue;
                 }
-                // Add a file, using absolute path to avoid duplicate
-                p.push(l);
-                if let Ok(pc) = p.canonicalize() {
-                    filelist.insert(pc);
-                } else {
-                    rpt_s!(MsgID::ErrFile,&path_display(&p));
+            }
+        }
+        stack.pop();
+        Ok(())
+    }
+
+    // Parse a library map: library declarations (library name file_spec, ... [-incdir dir, ...];)
+    // and nested maps (include file_spec;). The files of each library are added to the file list.
+    // Relative paths are resolved from the directory of the library map.
+    pub fn parse_libmap(&mut self, fname: PathBuf, stack: &mut Vec<PathBuf>) -> Result<(),std::io::Error> {
+        let fname = fname.canonicalize()?;
+        if stack.contains(&fname) {
+            rpt_s!(MsgID::ErrInvalid, &format!("Recursive library map {}", path_display(&fname)));
+            return Ok(());
+        }
+        let content = read_to_string(&fname)?;
+        let mut map_path = fname.clone();
+        map_path.pop();
+        stack.push(fname);
+        // Split the words in statements (;) and lists (,)
+        let mut stmts : Vec<Vec<String>> = vec![Vec::new()];
+        for w in srclist_words(&content) {
+            for (i,ws) in w.split(';').enumerate() {
+                if i > 0 {stmts.push(Vec::new());}
+                stmts.last_mut().unwrap().extend(ws.split(',').filter(|x| !x.is_empty()).map(expand_env));
+            }
+        }
+        for stmt in stmts.into_iter().filter(|x| !x.is_empty()) {
+            match stmt[0].as_ref() {
+                "library" if stmt.len() > 1 => {
+                    let mut files = Vec::new();
+                    let mut is_incdir = false;
+                    for w in &stmt[2..] {
+                        if w == "-incdir" {
+                            is_incdir = true;
+                            continue;
+                        }
+                        let p = map_path.join(w);
+                        if is_incdir {
+                            if let Ok(pc) = p.canonicalize() {
+                                if !self.incdir.contains(&pc) {self.incdir.push(pc);}
+                            } else {
+                                rpt_s!(MsgID::ErrFile,&path_display(&p));
+                            }
+                            continue;
+                        }
+                        let fl = glob_files(&p, w.ends_with('/'));
+                        if fl.is_empty() {
+                            rpt_s!(MsgID::ErrFile,&path_display(&p));
+                        }
+                        for f in fl {
+                            if !self.filelist.iter().any(|x| x.canonicalize().ok().as_ref()==Some(&f)) {
+                                self.filelist.push(f.clone());
+                            }
+                            files.push(f);
+                        }
+                    }
+                    match self.libmap.iter_mut().find(|(n,_)| *n==stmt[1]) {
+                        Some((_,v)) => v.extend(files),
+                        None => self.libmap.push((stmt[1].clone(),files))
+                    }
                 }
+                "include" if stmt.len() == 2 => {
+                    let p = map_path.join(&stmt[1]);
+                    if self.parse_libmap(p.clone(), stack).is_err() {
+                        rpt_s!(MsgID::ErrFile,&path_display(&p));
+                    }
+                }
+                _ => rpt_s!(MsgID::ErrInvalid, &format!("Invalid statement in library map: {}", stmt.join(" ")))
             }
         }
-        let mut ast_inc = HashMap::new();
-        if !comp_uvm {
-            ast_inc.insert("uvm_macros.svh".to_string(),uvm_macro::get_uvm_macro());
+        stack.pop();
+        Ok(())
+    }
+
+    // Library of a file: first library of the map containing it, work by default
+    fn lib_of(&self, fname: &Path) -> String {
+        let f = fname.canonicalize().unwrap_or_else(|_| fname.to_path_buf());
+        match self.libmap.iter().find(|(_,v)| v.contains(&f)) {
+            Some((n,_)) => n.clone(),
+            None => "work".to_owned()
         }
-        Ok(Project {
-            filelist,
-            incdir,
-            defines: HashMap::new(), // TODO: handle define from source list
-            ast_list: Vec::new(),
-            cur_dir: PathBuf::new(),
-            ast_inc, comp_uvm,
-        })
+    }
+
+    // Add a project-wide define from a string NAME[=VALUE] (command line or source list)
+    pub fn add_define(&mut self, def: &str) {
+        let (name,value) = match def.find('=') {
+            Some(i) => (def[..i].trim(), Some(&def[i+1..])),
+            None => (def.trim(), None)
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c=='_') {
+            rpt_s!(MsgID::ErrInvalid, &format!("Invalid define {}", def));
+            return;
+        }
+        // Tokenize the value to get the macro body
+        let mut body = Vec::new();
+        if let Some(v) = value {
+            let mut src = Source::from_string(PathBuf::from(format!("+define+{}", name)), v.to_owned());
+            let mut ts = TokenStream::new(&mut src, self);
+            loop {
+                match ts.get_next_token() {
+                    Ok(t) => body.push(t),
+                    Err(e) => {
+                        if e.kind != SvErrorKind::Null {
+                            rpt_s!(MsgID::ErrInvalid, &format!("Invalid value in define {} : {}", def, e));
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        let macro_def = if body.is_empty() {None} else {Some(MacroDef{ports: Vec::new(), body, fname: "command line".to_owned(), pos: Position::at(0, 0)})};
+        self.defines_init.insert(format!("`{}",name), macro_def);
     }
 
     // Parse one file
@@ -146,82 +361,457 @@ impl Project {
 
     // Compile all file from the project
     pub fn compile_all(&mut self) {
-        // Parse
+        // Parse, in source list order
+        self.defines = self.defines_init.clone();
         for fname in self.filelist.clone() {
-            // Ignore VHDL files from the source list
-            if let Some(ext) = fname.extension() {
-                if ext == "vhd" || ext == "vhdl" {continue;}
-            }
-            //
-            if let Ok(mut src) = Source::from_file(fname.clone()) {
-                rpt_set_fname!(&fname);
-                // rpt_s!(MsgID::InfoStatus, "Parsing");
-                self.defines.clear(); // TODO: reinit with project-wide define
-                self.cur_dir = fname.clone();
-                self.cur_dir.pop();
-                let mut ts = TokenStream::new(&mut src, self);
-                let mut ast = Ast::new(fname);
-                match ast.build(&mut ts) {
-                    Err(e) => rpt_e!(e),
-                    _ => {
-                        // rpt_info!("Compilation successfull");
-                        self.ast_list.push(ast);
-                    }
-                }
-            } else {
-                rpt_s!(MsgID::ErrFile, &path_display(fname));
+            if let Some(ast) = self.compile_file(fname) {
+                self.ast_list.push(ast);
             }
         }
         // println!("[Info] Parsing Done");
         // Compile/link
     }
 
+    // Compile all files in dependency order (see auto_order).
+    // In a single compilation unit the order changes the defines and $unit declarations
+    // seen by each file: the files are first parsed silently to extract the dependencies,
+    // then parsed again in the new order.
+    pub fn compile_ordered(&mut self) {
+        if !self.single_unit {
+            self.compile_all();
+            self.auto_order();
+            return;
+        }
+        rpt_set_quiet!(true);
+        self.compile_all();
+        rpt_set_quiet!(false);
+        self.auto_order();
+        self.ast_list.clear();
+        // Keep the builtin UVM macros: they are not read from a file
+        self.ast_inc.retain(|k,_| k == "uvm_macros.svh");
+        self.compile_all();
+    }
+
+    // Compile one file of the project
+    pub fn compile_file(&mut self, fname: PathBuf) -> Option<Ast> {
+        // Ignore VHDL files from the source list
+        if let Some(ext) = fname.extension() {
+            if ext == "vhd" || ext == "vhdl" {return None;}
+        }
+        //
+        match Source::from_file(fname.clone()) {
+            Ok(src) => self.compile_source(src),
+            Err(_) => {
+                rpt_s!(MsgID::ErrFile, &path_display(fname));
+                None
+            }
+        }
+    }
+
+    // Compile a source code already loaded (file or in-memory buffer, e.g. from an editor)
+    pub fn compile_source(&mut self, mut src: Source) -> Option<Ast> {
+        let fname = src.filename.clone();
+        rpt_set_fname!(&fname);
+        // rpt_s!(MsgID::InfoStatus, "Parsing");
+        // Each file is its own compilation unit: reinit with project-wide define
+        if !self.single_unit {
+            self.defines = self.defines_init.clone();
+        }
+        self.cur_dir = fname.clone();
+        self.cur_dir.pop();
+        let lib = self.lib_of(&fname);
+        let mut ts = TokenStream::new(&mut src, self);
+        let mut ast = Ast::new(fname);
+        ast.lib = lib;
+        match ast.build(&mut ts) {
+            Err(e) => rpt_e!(e),
+            _ => {
+                // rpt_info!("Compilation successfull");
+                // Report syntax errors: the partial AST is still usable
+                for e in ast.errors.iter() {
+                    rpt_e!(e.clone());
+                }
+                return Some(ast);
+            }
+        }
+        None
+    }
+
+    // Sort the files in dependency order: a file defining a package/module/class
+    // is placed before the files importing, instantiating or extending it.
+    // Files involved in a dependency cycle keep their relative order.
+    fn auto_order(&mut self) {
+        // Extract definitions and references of each file
+        let mut defs : HashMap<String,usize> = HashMap::new();
+        let mut uses = Vec::new();
+        for (i,ast) in self.ast_list.iter().enumerate() {
+            let mut d = Vec::new();
+            let mut u = Vec::new();
+            collect_deps(&ast.tree, &self.ast_inc, true, &mut d, &mut u, &mut HashSet::new());
+            for name in d {
+                defs.entry(name).or_insert(i);
+            }
+            uses.push(u);
+        }
+        // Build the graph: edge from the file defining a name to the files using it
+        let nb = self.ast_list.len();
+        let mut succ : Vec<Vec<usize>> = vec![Vec::new(); nb];
+        let mut nb_pred = vec![0; nb];
+        for (i,u) in uses.iter().enumerate() {
+            let mut pred : Vec<usize> = u.iter().filter_map(|n| defs.get(n)).cloned().filter(|&j| j!=i).collect();
+            pred.sort_unstable();
+            pred.dedup();
+            for j in pred {
+                succ[j].push(i);
+                nb_pred[i] += 1;
+            }
+        }
+        // Topological sort, taking the first file of the list when several are ready
+        let mut ready : BTreeSet<usize> = (0..nb).filter(|&i| nb_pred[i]==0).collect();
+        let mut order = Vec::with_capacity(nb);
+        while let Some(&i) = ready.iter().next() {
+            ready.remove(&i);
+            order.push(i);
+            for &j in &succ[i] {
+                nb_pred[j] -= 1;
+                if nb_pred[j]==0 {ready.insert(j);}
+            }
+        }
+        if order.len() < nb {
+            let cycle : Vec<String> = (0..nb).filter(|&i| nb_pred[i]>0).map(|i| path_display(&self.ast_list[i].filename)).collect();
+            rpt_s!(MsgID::ErrInvalid, &format!("Circular dependency between files {}", cycle.join(", ")));
+            order.extend((0..nb).filter(|&i| nb_pred[i]>0));
+        }
+        // Reorder the AST and the file list (files without AST stay at the end)
+        let mut ast_list : Vec<Option<Ast>> = self.ast_list.drain(..).map(Some).collect();
+        self.ast_list = order.into_iter().filter_map(|i| ast_list[i].take()).collect();
+        let mut filelist : Vec<PathBuf> = self.ast_list.iter().map(|a| a.filename.clone()).collect();
+        for f in &self.filelist {
+            if !filelist.contains(f) {filelist.push(f.clone());}
+        }
+        self.filelist = filelist;
+    }
+
     // Compile all file from the project
-    pub fn elaborate(&mut self) {
-        let _lib = CompLib::new("my_lib".to_owned(),&self.ast_list, &self.ast_inc);
+    // Create the library with all design elements, including the ones found in library files/directories
+    pub fn compile_lib(&mut self) -> CompLib {
+        let mut lib = CompLib::new("my_lib".to_owned(),&self.ast_list, &self.ast_inc, self.single_unit);
+        lib.libs = self.libmap.iter().map(|(n,_)| n.clone()).collect();
+        if !lib.libs.iter().any(|x| x=="work") {
+            lib.libs.push("work".to_owned());
+        }
+        lib.config = self.config.clone();
+        self.resolve_lib(&mut lib);
+        lib
     }
 
-    //
-    pub fn compile_inc(&mut self, inc_name: String, token: Token) -> Result<(),SvError> {
+    pub fn elaborate(&self, lib: &mut CompLib) {
+        lib.check_all(&self.ast_list, &self.ast_inc);
+    }
+
+    // Search the libraries (-v/-y) for modules not defined in the project.
+    // Library files are only parsed when needed and only the ones defining a missing module are kept.
+    fn resolve_lib(&mut self, lib: &mut CompLib) {
+        let mut searched : HashSet<String> = HashSet::new();
+        // Library files are parsed once and searched on every pass:
+        // a module can be needed by a module found in a library directory
+        let mut libfile_ast : Option<Vec<Ast>> = None;
+        loop {
+            let missing : Vec<String> = lib.unresolved_modules(&self.ast_list).into_iter().filter(|m| !searched.contains(m)).collect();
+            if missing.is_empty() {break;}
+            let mut found = false;
+            // Library directories: one file per module, named after the module
+            for m in &missing {
+                searched.insert(m.clone());
+                if let Some(f) = self.find_in_libdir(m) {
+                    if let Some(ast) = self.compile_file(f) {
+                        lib.add_lib_ast(&ast, &self.ast_inc);
+                        self.ast_list.push(ast);
+                        found = true;
+                    }
+                }
+            }
+            // Library files: keep only the ones defining a missing module
+            let asts = match libfile_ast.take() {
+                Some(v) => v,
+                None => self.libfile.clone().into_iter().filter_map(|f| self.compile_file(f)).collect()
+            };
+            let (used,unused) : (Vec<Ast>,Vec<Ast>) = asts.into_iter().partition(
+                |ast| ast.tree.child.iter().any(|n| (n.kind==AstNodeKind::Module || n.kind==AstNodeKind::Program || n.kind==AstNodeKind::Checker) && missing.contains(&n.attr["name"])));
+            for ast in used {
+                lib.add_lib_ast(&ast, &self.ast_inc);
+                self.ast_list.push(ast);
+                found = true;
+            }
+            libfile_ast = Some(unused);
+            if !found {break;}
+        }
+    }
+
+    // Find a module in the library directories, using the library extension (+libext+)
+    fn find_in_libdir(&self, name: &str) -> Option<PathBuf> {
+        for d in &self.libdir {
+            if self.libext.is_empty() {
+                let f = d.join(name);
+                if f.is_file() {return Some(f);}
+            }
+            for e in &self.libext {
+                let f = d.join(format!("{}{}",name,e));
+                if f.is_file() {return Some(f);}
+            }
+        }
+        None
+    }
+
+    // Find an include file following the LRM search order:
+    // `include "file" : directory of the current file, then directory of the compiled file, then include directories
+    // `include <file> : include directories only
+    pub fn find_inc(&self, inc_name: &str, angle: bool, cur_file: &Path) -> Option<PathBuf> {
         let mut inc_path = PathBuf::new();
-        for s in inc_name.to_string().split('/') {
+        for s in inc_name.split('/') {
             inc_path.push(s);
         }
-        let mut f : Option<PathBuf> = None;
-        // Check if include is in local directory
-        // TODO: Check should be done only if include is using "quotes" and not <angular_brackets>
-        let mut f_raw = self.cur_dir.clone();
-        f_raw.push(inc_path.clone());
-        if let Ok(f_abs) = f_raw.canonicalize() {
-            f = Some(f_abs.clone());
-            // println!("Found {:?} in {:?}", inc_name, self.cur_dir);
+        let mut dirs = Vec::new();
+        if !angle {
+            if let Some(d) = cur_file.parent() {dirs.push(d);}
+            dirs.push(self.cur_dir.as_path());
         }
-        // If not found yet, check if inside include directory list
-        if f.is_none() {
-            for d in &self.incdir {
-                f_raw = d.clone();
-                f_raw.push(inc_path.clone());
-                if let Ok(f_abs) = f_raw.canonicalize() {
-                    f = Some(f_abs.clone());
-                    break;
+        dirs.extend(self.incdir.iter().map(|d| d.as_path()));
+        for d in dirs {
+            if let Ok(f_abs) = d.join(&inc_path).canonicalize() {
+                // println!("Found {:?} in {:?}", inc_name, d);
+                return Some(f_abs);
+            }
+        }
+        None
+    }
+
+    // Compile an include file (if not already done) and update the defines.
+    // Return the key of the include in ast_inc, i.e. the absolute path of the file.
+    pub fn compile_inc(&mut self, inc_name: &str, angle: bool, cur_file: &Path, token: Token) -> Result<String,SvError> {
+        // Internal include (e.g. UVM macros)
+        let key =
+            if self.ast_inc.contains_key(inc_name) {
+                inc_name.to_owned()
+            } else {
+                let f_abs = self.find_inc(inc_name, angle, cur_file).ok_or_else(|| SvError::new(SvErrorKind::Include, token.clone(), "".to_string()))?;
+                let key = path_display(&f_abs);
+                // Recursive include
+                if let Some(i) = self.inc_stack.iter().position(|f| f==&f_abs) {
+                    let mut chain : Vec<String> = self.inc_stack[i..].iter().map(path_display).collect();
+                    chain.push(key);
+                    return Err(SvError::new(SvErrorKind::IncCycle, token, chain.join(" -> ")));
+                }
+                // Parse file if not in cache
+                if !self.ast_inc.contains_key(&key) {
+                    // println!("Compiling include file {:?}", f_abs);
+                    rpt_push_fname!(f_abs);
+                    self.inc_stack.push(f_abs.clone());
+                    // In case of error keep an empty AST to avoid parsing it again
+                    let ast = match self.parse_file(f_abs.clone()) {
+                        Ok(ast) => {
+                            // Report syntax errors: the partial AST is still usable
+                            for e in ast.errors.iter() {
+                                rpt_e!(e.clone());
+                            }
+                            ast
+                        }
+                        Err(e) => {rpt_e!(e); Ast::new(f_abs)}
+                    };
+                    self.inc_stack.pop();
+                    rpt_pop_fname!();
+                    self.ast_inc.insert(key.clone(),Box::new(ast));
                 }
+                key
+            };
+        // Add defines from the include to the current context
+        for (k,v) in self.ast_inc[&key].defines.clone() {
+            self.defines.insert(k,v);
+        }
+        Ok(key)
+    }
+
+}
+
+// Collect the names defined at top level (module/interface/package/class)
+// and the names referenced (import, scope, extends, instance), following includes.
+fn collect_deps(node: &AstNode, ast_inc: &HashMap<String,Box<Ast>>, top: bool, defs: &mut Vec<String>, uses: &mut Vec<String>, inc_done: &mut HashSet<String>) {
+    for nc in &node.child {
+        match nc.kind {
+            AstNodeKind::Module    |
+            AstNodeKind::Program   |
+            AstNodeKind::Checker   |
+            AstNodeKind::Interface |
+            AstNodeKind::Package   |
+            AstNodeKind::Class     if top => {
+                if let Some(n) = nc.attr.get("name") {defs.push(n.clone());}
+            }
+            AstNodeKind::Identifier => {
+                if let Some(n) = nc.attr.get("pkg_name") {uses.push(n.clone());}
             }
+            AstNodeKind::Scope => {
+                if let Some(n) = nc.attr.get("name") {uses.push(n.clone());}
+            }
+            AstNodeKind::Extends    |
+            AstNodeKind::Implements |
+            AstNodeKind::Instances  => {
+                if let Some(n) = nc.attr.get("type") {uses.push(n.clone());}
+            }
+            AstNodeKind::Directive => {
+                if let Some(i) = nc.attr.get("include") {
+                    if let Some(a) = ast_inc.get(i) {
+                        if inc_done.insert(i.clone()) {
+                            collect_deps(&a.tree, ast_inc, top, defs, uses, inc_done);
+                        }
+                    }
+                }
+            }
+            _ => {}
         }
-        // Parse file if found
-        match f {
-            Some(f_abs) => {
-                // println!("Compiling include file {:?}", f_abs);
-                rpt_push_fname!(f_abs);
-                match self.parse_file(f_abs.clone()) {
-                    Ok(ast) => {self.ast_inc.insert(inc_name,Box::new(ast));}
-                    Err(e) => rpt_e!(e)
+        collect_deps(nc, ast_inc, false, defs, uses, inc_done);
+    }
+}
+
+// Split a source list into words, removing comments (// , # and /* */).
+// Double quotes allow spaces inside a word (e.g. +define+MSG="a b")
+fn srclist_words(content: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut w = String::new();
+    let mut chars = content.chars().peekable();
+    let mut in_quote = false;
+    while let Some(c) = chars.next() {
+        if in_quote {
+            w.push(c);
+            if c == '"' {in_quote = false;}
+            continue;
+        }
+        match c {
+            '"' => {
+                in_quote = true;
+                w.push(c);
+            }
+            // Comments start a word: a path can contain /* (e.g. rtl/*.sv in a library map)
+            '/' if w.is_empty() && chars.peek() == Some(&'/') => {
+                for nc in &mut chars { if nc == '\n' {break;} }
+            }
+            '/' if w.is_empty() && chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for nc in &mut chars {
+                    if prev == '*' && nc == '/' {break;}
+                    prev = nc;
+                }
+            }
+            '#' if w.is_empty() => {
+                for nc in &mut chars { if nc == '\n' {break;} }
+            }
+            _ if c.is_whitespace() => {
+                if !w.is_empty() {
+                    words.push(w);
+                    w = String::new();
+                }
+            }
+            _ => w.push(c)
+        }
+    }
+    if !w.is_empty() {
+        words.push(w);
+    }
+    words
+}
+
+// Expand a library map file specification (wildcards *, ?, ... and trailing / for a whole directory)
+fn glob_files(spec: &Path, is_dir: bool) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::new()];
+    let mut comps : Vec<String> = spec.components().map(|c| c.as_os_str().to_string_lossy().into_owned()).collect();
+    if is_dir {comps.push("*".to_owned());}
+    for (i,c) in comps.iter().enumerate() {
+        let mut next = Vec::new();
+        for d in &paths {
+            if c == "..." {
+                let mut stack = vec![d.clone()];
+                while let Some(sd) = stack.pop() {
+                    if let Ok(rd) = std::fs::read_dir(&sd) {
+                        stack.extend(rd.filter_map(Result::ok).map(|e| e.path()).filter(|p| p.is_dir()));
+                    }
+                    next.push(sd);
                 }
-                rpt_pop_fname!();
-                Ok(())
+            } else if c.contains('*') || c.contains('?') {
+                if let Ok(rd) = std::fs::read_dir(d) {
+                    next.extend(rd.filter_map(Result::ok).filter(|e| wildcard_match(c, &e.file_name().to_string_lossy())).map(|e| e.path()));
+                }
+            } else if i==0 {
+                next.push(PathBuf::from(c));
+            } else {
+                next.push(d.join(c));
             }
-            None => Err(SvError::new(SvErrorKind::Include, token, "".to_string()))
-            // None => Err(SvError::new(SvErrorKind::Include, token, format!("line {} -- Unable to find include file {:?}", pos, inc_name)))
         }
+        paths = next;
     }
+    let mut files : Vec<PathBuf> = paths.into_iter().filter(|f| f.is_file()).filter_map(|f| f.canonicalize().ok()).collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+// Match a name against a pattern where * matches any sequence of characters and ? any character
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let p : Vec<char> = pattern.chars().collect();
+    let n : Vec<char> = name.chars().collect();
+    let (mut i, mut j) = (0,0);
+    let mut star : Option<(usize,usize)> = None;
+    while j < n.len() {
+        if i < p.len() && (p[i]=='?' || p[i]==n[j]) {
+            i += 1;
+            j += 1;
+        } else if i < p.len() && p[i]=='*' {
+            star = Some((i,j));
+            i += 1;
+        } else if let Some((si,sj)) = star {
+            i = si + 1;
+            j = sj + 1;
+            star = Some((si,sj+1));
+        } else {
+            return false;
+        }
+    }
+    p[i..].iter().all(|&c| c=='*')
+}
 
-}
\ No newline at end of file
+// Expand environment variables ($VAR or ${VAR}) in a source list word
+fn expand_env(s: &str) -> String {
+    let mut r = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            r.push(c);
+            continue;
+        }
+        let mut name = String::new();
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            for nc in &mut chars {
+                if nc == '}' {break;}
+                name.push(nc);
+            }
+        } else {
+            while let Some(&nc) = chars.peek() {
+                if !nc.is_alphanumeric() && nc != '_' {break;}
+                name.push(nc);
+                chars.next();
+            }
+        }
+        if name.is_empty() {
+            r.push(c);
+            continue;
+        }
+        match std::env::var(&name) {
+            Ok(v) => r.push_str(&v),
+            Err(_) => {
+                rpt_s!(MsgID::ErrInvalid, &format!("Undefined environment variable {} in source list", name));
+                r.push_str(&format!("${}",name));
+            }
+        }
+    }
+    r
+}
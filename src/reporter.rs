@@ -3,14 +3,16 @@
 #![macro_use]
 #![allow(unused_macros)]
 
-use std::path::PathBuf;
+use std::path::{Path,PathBuf};
+use std::fs;
 use std::collections::{HashMap,HashSet};
 use crate::error::SvError;
 
 use crate::ast::astnode::{AstNode, AstNodeKind};
-use crate::lex::{token::Token, source::path_display};
+use crate::lex::{token::{Token,MacroExp}, position::Position, source::path_display};
 // use crate::lex::{token::Token, position::Position};
 use std::cell::RefCell;
+use std::rc::Rc;
 
 thread_local!(pub static REPORTER: RefCell<Reporter> = RefCell::new(Reporter::new(None, Severity::Warning)));
 macro_rules! rpt_set_fname {
@@ -21,10 +23,30 @@ macro_rules! rpt_push_fname {
     ($fn:expr) => {{ REPORTER.with(|log| {log.borrow_mut().push_filename(&$fn)}) }};
 }
 
+macro_rules! rpt_line_fname {
+    ($fn:expr) => {{ REPORTER.with(|log| {log.borrow_mut().set_line_filename(&$fn)}) }};
+}
+
 macro_rules! rpt_pop_fname {
     () => {{ REPORTER.with(|log| {log.borrow_mut().pop_filename()}) }};
 }
 
+macro_rules! rpt_add_source {
+    ($fn:expr, $code:expr) => {{ REPORTER.with(|log| {log.borrow_mut().add_source(&$fn, $code)}) }};
+}
+
+macro_rules! rpt_add_exp {
+    ($fn:expr, $exp:expr) => {{ REPORTER.with(|log| {log.borrow_mut().add_exp(&$fn, $exp)}) }};
+}
+
+macro_rules! rpt_set_quiet {
+    ($q:expr) => {{ REPORTER.with(|log| {log.borrow_mut().quiet = $q}) }};
+}
+
+macro_rules! rpt_set_severity {
+    ($id:expr, $sev:expr) => {{ REPORTER.with(|log| {log.borrow_mut().set_severity($id, $sev)}) }};
+}
+
 macro_rules! rpt {
     ($id:expr, $node:expr, $txt:expr) => {{ REPORTER.with(|log| {log.borrow_mut().msg($id, $node, $txt)}) }};
 }
@@ -43,7 +65,7 @@ macro_rules! rpt_info {
 
 
 macro_rules! rpt_e {
-    ($err:expr) => {{ REPORTER.with(|log| {log.borrow().msg_e($err)}) }};
+    ($err:expr) => {{ REPORTER.with(|log| {log.borrow_mut().msg_e($err)}) }};
 }
 
 
@@ -60,6 +82,8 @@ pub enum MsgID {
     ErrArgExtra       , // Too many argument in instance/method
     ErrInvalid        , // Invalid token
     WarnUnused        , // Unused token
+    WarnImplicitNet   , // Implicit net declaration
+    WarnRoundTrip     , // Source rebuilt from the CST differs from the file
     InfoStatus        , // Compile/Link status
     DbgSkip           , // Skipping analysis of some AstNode
     DbgStatus         , // General Debug status
@@ -82,6 +106,14 @@ pub struct Reporter {
     pub filename: Vec<PathBuf>,
     /// List of previous message in current file to avoid spamming same issue multiple
     prev_msg : HashMap<MsgID,HashSet<String>>,
+    /// Physical path of the file being parsed/compiled (not changed by `line directive)
+    src_name : Vec<PathBuf>,
+    /// Source code of each file, as seen by the tokenizer
+    sources : HashMap<PathBuf,Rc<str>>,
+    /// Macro expansion of each macro call, by file and byte offset of the call
+    exps : HashMap<(PathBuf,u32),Rc<MacroExp>>,
+    /// Discard all messages (e.g. first parse used only to sort the files)
+    pub quiet : bool,
 }
 
 #[allow(dead_code)]
@@ -98,30 +130,57 @@ impl Reporter {
         id_level.insert(MsgID::ErrInvalid   , Severity::Error);
         id_level.insert(MsgID::ErrArgMiss   , Severity::Error);
         id_level.insert(MsgID::WarnUnused   , Severity::Warning);
+        id_level.insert(MsgID::WarnImplicitNet, Severity::Warning);
+        id_level.insert(MsgID::WarnRoundTrip, Severity::Warning);
         id_level.insert(MsgID::InfoStatus   , Severity::Info);
         id_level.insert(MsgID::DbgSkip      , Severity::Debug);
         id_level.insert(MsgID::DbgStatus    , Severity::Debug);
         Reporter {
             logfile, stdout_level: level, id_level,
             filename: Vec::new(),
-            prev_msg: HashMap::new()}
+            prev_msg: HashMap::new(),
+            src_name: Vec::new(),
+            sources: HashMap::new(),
+            exps: HashMap::new(),
+            quiet: false}
     }
 
     // Set the filename begin analyzed
     pub fn set_filename(&mut self, name: &PathBuf) {
         self.filename.clear();
         self.filename.push(name.clone());
+        self.src_name.clear();
+        self.src_name.push(name.clone());
         self.prev_msg.clear();
     }
 
     // Push a new filename (included file)
     pub fn push_filename(&mut self, name: &PathBuf) {
         self.filename.push(name.clone());
+        self.src_name.push(name.clone());
     }
 
     // Push a new filename (included file)
     pub fn pop_filename(&mut self) {
         self.filename.pop();
+        self.src_name.pop();
+    }
+
+    // Register the code of a file, used to display the source line in messages
+    pub fn add_source(&mut self, name: &Path, code: Rc<str>) {
+        self.sources.insert(name.to_path_buf(), code);
+    }
+
+    // Register a macro expansion: all tokens of the expansion have the position of the call
+    pub fn add_exp(&mut self, name: &Path, exp: Rc<MacroExp>) {
+        self.exps.insert((name.to_path_buf(), exp.call_pos.byte), exp);
+    }
+
+    // Change the current filename (`line directive)
+    pub fn set_line_filename(&mut self, name: &Path) {
+        if let Some(f) = self.filename.last_mut() {
+            *f = name.to_path_buf();
+        }
     }
 
     // Set the filename begin analyzed
@@ -129,6 +188,11 @@ impl Reporter {
         self.logfile = Some(name);
     }
 
+    // Change the severity of a message
+    pub fn set_severity(&mut self, id: MsgID, sev: Severity) {
+        self.id_level.insert(id, sev);
+    }
+
     pub fn get_severity_str(&self, id: &MsgID) -> String {
         match self.id_level.get(id) {
             Some(Severity::Debug)   => "[DEBUG]  ".to_string(),
@@ -143,6 +207,7 @@ impl Reporter {
     }
 
     pub fn msg(&mut self, id: MsgID, node: &AstNode, cntxt: &str) {
+        if self.quiet {return;}
         // Avoid repeting some message : check if already seen
         match id {
             MsgID::ErrNotFound => {
@@ -165,7 +230,13 @@ impl Reporter {
                 MsgID::ErrMacro      => format!("Macro {} {}.", node.kind, cntxt),
                 MsgID::ErrSyntax     => format!("Unexpected {} in {}.", node.kind, cntxt),
                 MsgID::ErrNotFound   => format!("Undefined {}!", cntxt),
-                MsgID::ErrArgMiss    => format!("Missing port in instance of {} : {}", node.attr["type"], cntxt),
+                MsgID::ErrArgMiss    => {
+                    match node.kind {
+                        AstNodeKind::MacroCall |
+                        AstNodeKind::MethodCall => format!("Missing argument in call to {} : {}", node.attr["name"], cntxt),
+                        _ => format!("Missing port in instance of {} : {}", node.attr["type"], cntxt),
+                    }
+                }
                 MsgID::ErrImplicit   => format!("Implicit connection to port {} of {} not found.", cntxt, node.attr["type"]),
                 MsgID::ErrArgExtra   => {
                     match node.kind {
@@ -176,14 +247,22 @@ impl Reporter {
                     }
                 }
                 MsgID::WarnUnused    => format!("Unused {}", "".to_string()),
+                MsgID::WarnImplicitNet => format!("Implicit declaration of net {}.", cntxt),
                 MsgID::DbgSkip       => format!("Skipping {} : {}", cntxt, node),
                 _ => cntxt.to_string(),
             };
         // print the message to a file and/or stdout (TODO)
         println!("{} {}:{} | {}", str_sev, str_fn ,node.pos, str_body);
+        self.msg_span(&node.pos, &node.end);
+        // Node produced by a macro: its position is the one of the macro call
+        let exp = self.src_name.last()
+            .and_then(|f| self.exps.get(&(f.clone(), node.pos.byte)))
+            .filter(|e| e.call_pos == node.pos);
+        self.msg_exp(exp);
     }
 
-    pub fn msg_t(&self, id: MsgID, token: &Token, cntxt: &str) {
+    pub fn msg_t(&mut self, id: MsgID, token: &Token, cntxt: &str) {
+        if self.quiet {return;}
         let str_sev = self.get_severity_str(&id);
         let str_fn = path_display(self.get_filename());
         let str_body =
@@ -197,21 +276,80 @@ impl Reporter {
             };
         // print the message to a file and/or stdout (TODO)
         println!("{} {}:{} | {}", str_sev, str_fn , token.pos, str_body);
+        self.msg_span(&token.pos, &token.end);
+        self.msg_exp(token.exp.as_ref());
     }
 
     // Message from error
-    pub fn msg_e(&self, error : SvError) {
+    pub fn msg_e(&mut self, error : SvError) {
+        if self.quiet {return;}
         let str_sev  = self.get_severity_str(&MsgID::ErrSyntax);
         let str_fn   = path_display(self.get_filename());
         println!("{} {}{}", str_sev, str_fn ,error);
+        self.msg_span(&error.token.pos, &error.token.end);
+        self.msg_exp(error.token.exp.as_ref());
+    }
+
+    // Display the source line and underline the range between start and end
+    fn msg_span(&mut self, start: &Position, end: &Position) {
+        if start.col == 0 || start.line == 0 {
+            return;
+        }
+        let fname = match self.src_name.last() {
+            Some(f) => f.clone(),
+            None => return
+        };
+        if !self.sources.contains_key(&fname) {
+            let code = fs::read_to_string(&fname).unwrap_or_default();
+            self.sources.insert(fname.clone(), code.into());
+        }
+        let code = &self.sources[&fname];
+        // Use the byte offset when available: the line number can be remapped by a `line directive
+        let byte = start.byte as usize;
+        let line = if (byte > 0 || start.line == 1) && byte < code.len() && code.is_char_boundary(byte) {
+            let s = code[..byte].rfind('\n').map(|i| i+1).unwrap_or(0);
+            let e = code[byte..].find('\n').map(|i| i+byte).unwrap_or_else(|| code.len());
+            code[s..e].trim_end_matches('\r')
+        } else {
+            match code.lines().nth(start.line as usize - 1) {
+                Some(l) => l,
+                None => return
+            }
+        };
+        // Only underline the first character for span on multiple lines
+        let len = if end.line==start.line && end.col>=start.col {end.col - start.col + 1} else {1};
+        // Keep tabulations so that the underline stays aligned
+        let indent : String = line.chars().take(start.col as usize - 1).map(|c| if c=='\t' {'\t'} else {' '}).collect();
+        println!("{:9} | {}", "", line);
+        println!("{:9} | {}{}", "", indent, "^".repeat(len as usize));
+    }
+
+    // Notes for each macro expansion which produced the token, from innermost to outermost
+    fn msg_exp(&self, exp: Option<&Rc<MacroExp>>) {
+        let mut exp = exp;
+        while let Some(e) = exp {
+            // Builtin and command line macros have no source: no line in their location
+            let call = match e.parent.as_ref() {
+                Some(p) if p.def_pos.line == 0 => format!("from {}", p.name),
+                _ => format!("at {}:{}", e.call_fname, e.call_pos)
+            };
+            if e.def_pos.line == 0 {
+                println!("{:9}   in expansion of {} {} ({} macro)", "", e.name, call, e.def_fname);
+            } else {
+                println!("{:9}   in expansion of {} {} (defined at {}:{})", "", e.name, call, e.def_fname, e.def_pos);
+            }
+            exp = e.parent.as_ref();
+        }
     }
 
     // Basic message (no mode/token)
     pub fn msg_s(&self, id: MsgID, cntxt: &str) {
+        if self.quiet {return;}
         let str_sev = self.get_severity_str(&id);
         let str_body =
             match id {
                 MsgID::ErrFile     => format!("File {} not found.", cntxt),
+                MsgID::WarnRoundTrip => format!("Lossless round-trip failed for {}.", cntxt),
                 MsgID::InfoStatus  => format!("{} | {}", path_display(self.get_filename()), cntxt),
                 MsgID::DbgStatus   => format!("{} | {}", path_display(self.get_filename()), cntxt),
                 _ => cntxt.to_string(),
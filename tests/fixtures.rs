@@ -0,0 +1,152 @@
+// This file is part of sv_check and subject to the terms of MIT Licence
+// Copyright (c) 2019, clams@mail.com
+
+// Regression tests: run sv_check on the files of tests/fixtures and compare the output
+// with the expected diagnostics (<name>.expected). Set SV_CHECK_BLESS=1 to update them.
+
+use std::{env,fs,path::PathBuf,process::Command};
+
+fn check(name: &str, args: &[&str]) -> String {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures");
+    let out = Command::new(env!("CARGO_BIN_EXE_sv_check"))
+        .current_dir(&dir)
+        .args(args)
+        .output()
+        .expect("unable to run sv_check");
+    // Paths of included files are absolute: make them relative to the fixtures directory
+    let txt = String::from_utf8_lossy(&out.stdout).replace(&format!("{}/",dir.display()), "");
+    let fname = dir.join(format!("{}.expected",name));
+    if env::var("SV_CHECK_BLESS").is_ok() {
+        fs::write(&fname, &txt).unwrap();
+        return txt;
+    }
+    let expected = fs::read_to_string(&fname).unwrap_or_else(|_| panic!("missing {}", fname.display()));
+    assert_eq!(txt, expected, "output of {} does not match {}", name, fname.display());
+    txt
+}
+
+#[test]
+fn define_then_ifdef() {check("define_ifdef", &["define_ifdef.sv"]);}
+
+#[test]
+fn define_body_then_ifdef() {check("define_body_ifdef", &["define_body_ifdef.sv"]);}
+
+#[test]
+fn include_syntax_error() {check("include_error", &["include_error.sv"]);}
+
+#[test]
+fn srclist_lib_and_nested() {check("srclist", &["-f","srclist/list.f"]);}
+
+#[test]
+fn dpi_header_user_types() {check("dpi_types", &["--parse_only","--dpi-header","/dev/stdout","dpi_types.sv"]);}
+
+#[test]
+fn dpi_import_types() {check("dpi_import", &["dpi_types.sv"]);}
+
+#[test]
+fn cycle_delay_default_clocking() {check("cycle_delay", &["cycle_delay.sv"]);}
+
+#[test]
+fn checker_event_actuals() {check("checker_event", &["checker_event.sv"]);}
+
+#[test]
+fn unit_imports_single() {check("unit_single", &["--compilation-unit","single","unit/pkg.sv","unit/a.sv","unit/b.sv"]);}
+
+#[test]
+fn unit_imports_per_file() {check("unit_per_file", &["--compilation-unit","per-file","unit/pkg.sv","unit/a.sv","unit/b.sv"]);}
+
+#[test]
+fn unit_default_per_file() {check("unit_per_file", &["unit/pkg.sv","unit/a.sv","unit/b.sv"]);}
+
+#[test]
+fn auto_order_before_parsing() {check("order", &["--auto-order","--compilation-unit","single","order/top.sv","order/sub.sv","order/pkg.sv"]);}
+
+#[test]
+fn auto_order_print() {check("order_print", &["--auto-order","--compilation-unit","single","--print-order","order/top.sv","order/sub.sv","order/pkg.sv"]);}
+
+#[test]
+fn auto_order_uvm_macros() {check("order_uvm", &["--auto-order","--compilation-unit","single","order_uvm/top.sv","order_uvm/pkg.sv"]);}
+
+#[test]
+fn line_directive_span() {check("line_span", &["line_span.sv"]);}
+
+#[test]
+fn macro_builtin_expansion() {check("macro_builtin", &["-D","CMD=1 + ]","macro_builtin.sv"]);}
+
+#[test]
+fn macro_elaboration_error() {check("macro_elab", &["macro_elab.sv"]);}
+
+#[test]
+fn utf8_index_scan() {check("utf8_scan", &["utf8_scan.sv"]);}
+
+#[test]
+fn token_value_ranges() {check("token_value", &["token_value.sv"]);}
+
+#[test]
+fn token_value_ranges_pp() {check("token_value_pp", &["-E","token_value.sv"]);}
+
+#[test]
+fn covergroup_checks() {check("covergroup", &["covergroup.sv"]);}
+
+#[test]
+fn constraint_out_of_block() {check("constraint_oob", &["constraint_oob.sv"]);}
+
+#[test]
+fn define_cli_and_srclist() {check("define_cli", &["-D","FROM_CLI","-D","DEPTH=3","-f","define/list.f"]);}
+
+#[test]
+fn define_srclist_only() {check("define_list", &["-f","define/list.f"]);}
+
+#[test]
+fn include_search_and_cycle() {check("incl", &["incl/top.sv","-I","incl/sys"]);}
+
+#[test]
+fn default_nettype_implicit() {check("nettype", &["nettype.sv"]);}
+
+#[test]
+fn default_nettype_implicit_error() {check("nettype_error", &["--implicit-net-error","nettype.sv"]);}
+
+#[test]
+fn pragma_and_line_macro() {check("pragma_line", &["pragma_line.sv"]);}
+
+#[test]
+fn pragma_and_line_macro_preprocess() {check("pragma_line_pp", &["-E","pragma_line.sv"]);}
+
+#[test]
+fn begin_end_keywords() {check("keywords", &["keywords.sv"]);}
+
+#[test]
+fn keywords_default_std() {check("keywords_std", &["keywords_std.sv"]);}
+
+#[test]
+fn keywords_verilog_std() {check("keywords_std_2005", &["--std","1364-2005","keywords_std.sv"]);}
+
+#[test]
+fn cst_comments() {
+    let txt = check("cst", &["--cst","cst.sv"]);
+    assert!(!txt.contains("round-trip failed"), "source rebuilt from the CST differs from cst.sv");
+}
+
+#[test]
+fn sva_sequences_properties() {check("sva", &["sva.sv"]);}
+
+#[test]
+fn specify_paths_timing_checks() {check("specify", &["specify.sv"]);}
+
+#[test]
+fn specify_delayed_implicit_net_error() {check("specify", &["--implicit-net-error","specify.sv"]);}
+
+#[test]
+fn udp_tables_and_gates() {check("udp", &["udp.sv"]);}
+
+#[test]
+fn libmap_config_default_liblist() {check("libmap_cfg_a", &["--libmap","libmap/lib.map","--config","cfg_a"]);}
+
+#[test]
+fn libmap_config_instance_rule() {check("libmap_cfg_b", &["--libmap","libmap/lib.map","--config","cfg_b"]);}
+
+#[test]
+fn libmap_config_cell_rule() {check("libmap_cfg_c", &["--libmap","libmap/lib.map","--config","cfg_c"]);}
+
+#[test]
+fn modport_expressions_prototypes() {check("modport", &["modport.sv"]);}
@@ -0,0 +1,11 @@
+checker chk(logic a, event e, sequence s = a);
+  assert property (@(e) s |-> a);
+endchecker
+
+module m(input logic c, input logic en);
+  logic x, y;
+  chk u_pos(x, posedge c);
+  chk u_named(.a(x), .e(negedge c iff en));
+  chk u_seq(x, edge c, x ##1 y);
+  chk u_undef(x, posedge clk_missing);
+endmodule
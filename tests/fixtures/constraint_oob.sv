@@ -0,0 +1,35 @@
+// Out-of-block constraints and methods are checked with the members of their class
+class base_c;
+  rand int b;
+  randc bit [3:0] rc;
+endclass
+
+class K extends base_c;
+  rand int a;
+  int n;
+  extern constraint c_ext;
+  constraint c_proto;
+  static constraint c_static { a > 0; }
+  extern function int f(int x);
+  extern task t();
+endclass
+
+constraint K::c_ext { a < 10; solve b before a; missing_c > 1; }
+constraint K::c_proto { a inside {[0:3]}; solve n before a; solve rc before a; }
+constraint Unknown::c { a > 0; }
+
+function int K::f(int x);
+  f = a + x + missing_f;
+endfunction
+
+task K::t();
+  n = b;
+endtask
+
+package p;
+  class P;
+    rand int v;
+    extern constraint c;
+  endclass
+  constraint P::c { v != 0; w == 1; }
+endpackage
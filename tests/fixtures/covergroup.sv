@@ -0,0 +1,11 @@
+// Covergroup checks: cross items, bins selection and options
+module covergroup_chk;
+  logic [3:0] a, b;
+  logic clk;
+  covergroup cg @(posedge clk);
+    cp_a : coverpoint a { bins lo = {[0:7]}; bins hi = {[8:15]}; }
+    cx : cross cp_a, bb { bins x = binsof(cp_a.mid); }
+    cross cp_a, b, cc;
+    option.bogus = 1;
+  endgroup
+endmodule
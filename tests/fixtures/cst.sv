@@ -0,0 +1,5 @@
+// Leading comment of the module
+module cst_trivia (input logic a, output logic b); // trailing comment of the header
+  /* block comment before the assign */
+  (* keep *) assign b = a; // trailing comment of the assign
+endmodule
@@ -0,0 +1,28 @@
+module no_clk(input clk);
+  logic x;
+  initial begin
+    ##1 x = 0;
+    if (x) ##2 x = 0;
+    ##3;
+    x <= ##1 1;
+  end
+endmodule
+
+module with_clk(input clk);
+  logic x;
+  default clocking cb @(posedge clk);
+    output x;
+  endclocking
+  initial begin
+    ##1 cb.x <= 0;
+    if (x) ##2 cb.x <= 1;
+    cb.x <= ##1 1;
+  end
+endmodule
+
+module bad_item(input clk);
+  logic q;
+  clocking cb2 @(posedge clk);
+    input .hq(q);
+  endclocking
+endmodule
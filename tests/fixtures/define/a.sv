@@ -0,0 +1,11 @@
+module a (input logic [`WIDTH-1:0] d);
+`ifdef USE_FIFO
+  logic [`WIDTH-1:0] fifo_q;
+`else
+  logic [`WIDTH-1:0] reg_q;
+`endif
+  assign fifo_q = d;
+`ifndef FROM_CLI
+  assign missing_cli = d;
+`endif
+endmodule
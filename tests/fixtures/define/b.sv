@@ -0,0 +1,6 @@
+module b;
+`ifdef FROM_CLI
+  logic [`DEPTH:0] q;
+  assign q = undeclared_b;
+`endif
+endmodule
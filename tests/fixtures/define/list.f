@@ -0,0 +1,4 @@
+// Defines from the source list seed every file
++define+WIDTH=8+USE_FIFO
+a.sv
+b.sv
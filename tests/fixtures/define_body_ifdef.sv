@@ -0,0 +1,25 @@
+// Macro with a body immediately followed by a conditional directive
+`define A 1
+`define B (2)
+`ifdef (A && B)
+module good1; endmodule
+`else
+module bad1; endmodule
+`endif
+
+`define C 2 // comment
+`ifndef C
+module bad2; endmodule
+`endif
+
+`define F(a) a+1
+`ifdef F
+module good2; endmodule
+`endif
+
+module top;
+  good1 u1();
+  good2 u2();
+  bad1  u3();
+  bad2  u4();
+endmodule
@@ -0,0 +1,28 @@
+// Define immediately followed by a conditional directive
+`define X
+`ifdef X
+module good1; endmodule
+`else
+module bad1; endmodule
+`endif
+
+`define A
+`define B
+`ifdef (A && B)
+module good2; endmodule
+`else
+module bad2; endmodule
+`endif
+
+`define C
+`ifndef (C || X)
+module bad3; endmodule
+`endif
+
+// Only bad1 and bad3 are undefined
+module top;
+  good1 u1();
+  good2 u2();
+  bad1  u3();
+  bad3  u4();
+endmodule
@@ -0,0 +1,25 @@
+package pkg;
+  typedef struct packed {bit [3:0] a; bit b;} bs_t;
+  typedef struct packed {logic [3:0] a; bit b;} ls_t;
+  typedef enum byte unsigned {IDLE, BUSY} state_t;
+  typedef enum logic [1:0] {R, G, B} color_t;
+  typedef logic [7:0] data_t;
+  typedef struct {int id; data_t d; bit flag; real r[2];} pkt_t;
+  typedef struct {pkt_t p; chandle h;} msg_t;
+  class cls_c; endclass
+  // Class types have no C equivalent
+  import "DPI-C" function void f_pkg_class(input cls_c c);
+endpackage
+
+import pkg::*;
+
+module m;
+  typedef enum {A, B2} local_e;
+  import "DPI-C" function void f_packed(input bs_t x, output ls_t y, inout bs_t z);
+  import "DPI-C" function state_t f_enum(input color_t c, output local_e e);
+  import "DPI-C" function void f_struct(input pkt_t p, output msg_t m);
+  import "DPI-C" function void f_scoped(input pkg::data_t d, input int unsigned u);
+  import "DPI-C" function pkt_t f_ret_struct();
+  import "DPI-C" function void f_undef(input undef_t u);
+  import "DPI-C" function void f_class(input pkg::cls_c c);
+endmodule
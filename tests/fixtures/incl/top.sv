@@ -0,0 +1,14 @@
+// "file": directory of the including file first, then the include directories
+`include "defs.svh"
+// <file>: include directories only
+`include <defs.svh>
+// Same include name in another directory: not taken from the cache
+`include "sub/user.svh"
+`include "cyc_a.svh"
+
+module incl_top;
+  logic [`LOCAL_W-1:0] a;
+  logic [`SYS_W-1:0] b;
+  logic [`SUB_W-1:0] c;
+  assign a = missing_x;
+endmodule
@@ -0,0 +1,5 @@
+// Syntax errors inside an include file are reported
+`include "inc/bad.svh"
+module top;
+  int x = P;
+endmodule
@@ -0,0 +1,25 @@
+// Legacy code using SystemVerilog keywords as identifiers
+`begin_keywords "1364-2001"
+module kw_legacy (input wire logic, output wire bit);
+  wire checker;
+  assign checker = logic;
+  assign bit = checker & undeclared_v;
+endmodule
+`end_keywords
+
+// Back to the default keywords
+module kw_sv;
+  logic a;
+  bit b;
+  assign a = b;
+endmodule
+
+`begin_keywords "1800-2005"
+module kw_2005;
+  wire checker;
+`begin_keywords "1364-1995"
+  wire generate_int;
+`end_keywords
+  logic global;
+endmodule
+`end_keywords
@@ -0,0 +1,5 @@
+// Keywords of the standard selected with --std
+module kw_std (input wire a);
+  wire logic;
+  assign logic = a;
+endmodule
@@ -0,0 +1,4 @@
+// Version A: two ports
+module leaf (input logic i, output logic o);
+  assign o = i;
+endmodule
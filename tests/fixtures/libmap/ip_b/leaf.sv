@@ -0,0 +1,4 @@
+// Version B: three ports
+module leaf (input logic i, input logic en, output logic o);
+  assign o = i & en;
+endmodule
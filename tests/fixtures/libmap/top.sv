@@ -0,0 +1,22 @@
+module top;
+  logic a, en, y0, y1;
+  leaf u0 (.i(a), .o(y0));
+  leaf u1 (.i(a), .en(en), .o(y1));
+endmodule
+
+config cfg_a;
+  design work.top;
+  default liblist lib_a lib_b;
+endconfig
+
+config cfg_b;
+  design work.top;
+  default liblist lib_b;
+  instance top.u0 liblist lib_a;
+endconfig
+
+config cfg_c;
+  design work.top;
+  default liblist lib_a;
+  cell leaf liblist lib_b;
+endconfig
@@ -0,0 +1,13 @@
+// Diagnostics after a `line directive: the source line shown under the message
+// is taken from the parsed code, not from the remapped file
+`line 40 "gen/orig.sv" 0
+module line_span;
+  logic a;
+  assign a = missing_sig;
+  always_comb begin
+    a = other_missing;
+  end
+endmodule
+
+// Line after the end of a module: still remapped
+module line_span_top; line_span_missing u(); endmodule
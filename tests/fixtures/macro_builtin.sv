@@ -0,0 +1,15 @@
+// Expansion notes of builtin UVM macros and command line defines: no definition position
+import uvm_pkg::*;
+`include "uvm_macros.svh"
+
+class c extends uvm_component;
+  `uvm_component_utils(c])
+endclass
+
+module macro_builtin;
+  logic x;
+  initial begin
+    `uvm_info("ID", "msg", UVM_LOW UVM_LOW)
+    x = `CMD;
+  end
+endmodule
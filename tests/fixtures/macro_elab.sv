@@ -0,0 +1,11 @@
+// Elaboration errors inside a macro expansion point back to the macro call
+`include "uvm_macros.svh"
+`define CALL(x) do_it(x)
+`define WRAP(x) undef_var = x
+module macro_elab;
+  initial begin
+    `CALL(1);
+    `WRAP(2);
+    `uvm_info("ID", "msg", 0)
+  end
+endmodule
@@ -0,0 +1,41 @@
+// Modport port expressions and method prototypes
+interface bus_if (input logic clk);
+  logic [7:0] data;
+  logic [3:0] addr;
+  logic       valid;
+  modport mst (
+    output valid,
+    output .wdata(data[7:0]), .waddr(addr),
+    import task write(input logic [7:0] d, input logic [3:0] a),
+    import function int status()
+  );
+  modport slv (
+    input valid, .rdata(data),
+    export task write(input logic [7:0] d, input logic [3:0] a),
+    input .bad(missing_sig)
+  );
+  task write(input logic [7:0] d, input logic [3:0] a);
+    data = d;
+    addr = a;
+  endtask
+  function int status();
+    return valid;
+  endfunction
+endinterface
+
+module mp_user (bus_if.mst b);
+  initial begin
+    b.write(8'h0, 4'h1);
+    b.wdata = 8'hFF;
+    b.undeclared = 1'b0;
+    b.write(8'h0);
+  end
+endmodule
+
+module mp_reader (bus_if.slv b);
+  logic [7:0] q;
+  always_comb begin
+    q = b.rdata;
+    b.valid = 1'b0;
+  end
+endmodule
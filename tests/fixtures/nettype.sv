@@ -0,0 +1,22 @@
+// Implicit nets: declared by a port connection or a continuous assignment
+module nettype_leaf (input logic i, output logic o);
+  assign o = i;
+endmodule
+
+module nettype_wire;
+  nettype_leaf u0 (.i(in_w), .o(out_w));
+  assign sum_w = out_w;
+endmodule
+
+`default_nettype tri
+module nettype_tri;
+  assign y_tri = 1'b0;
+endmodule
+
+`default_nettype none
+module nettype_none;
+  logic a;
+  nettype_leaf u1 (.i(a), .o(out_n));
+  assign sum_n = a;
+endmodule
+`default_nettype wire
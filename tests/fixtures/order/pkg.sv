@@ -0,0 +1,5 @@
+`define DATA_W 8
+
+package p;
+  localparam int W = `DATA_W;
+endpackage
@@ -0,0 +1,2 @@
+module sub(input logic [7:0] d);
+endmodule
@@ -0,0 +1,6 @@
+// Uses package p and the define of pkg.sv: only valid once pkg.sv is compiled first
+module top;
+  import p::*;
+  logic [`DATA_W-1:0] d;
+  sub u_sub(.d(d));
+endmodule
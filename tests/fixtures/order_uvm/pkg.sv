@@ -0,0 +1,12 @@
+`include "uvm_macros.svh"
+// Minimal stand-in for the reporting functions called by `uvm_info
+package p;
+  typedef enum {UVM_INFO, UVM_WARNING, UVM_ERROR, UVM_FATAL} uvm_severity;
+  typedef enum {UVM_NONE=0, UVM_LOW=100} uvm_verbosity;
+  function bit uvm_report_enabled(int verbosity, uvm_severity severity, string id);
+    return 1;
+  endfunction
+  function void uvm_report_info(string id, string message, int verbosity, string filename, int line,
+                                string context_name = "", bit report_enabled_checked = 0);
+  endfunction
+endpackage
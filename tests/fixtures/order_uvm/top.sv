@@ -0,0 +1,6 @@
+// The builtin UVM macros must still be found after the files are reordered
+`include "uvm_macros.svh"
+module top;
+  import p::*;
+  initial `uvm_info("TOP", "hello", UVM_LOW)
+endmodule
@@ -0,0 +1,18 @@
+// `__LINE__ is the line of the macro call, `pragma protect regions are skipped
+`define HERE `__LINE__
+`define CHECK_AT(x) x = `__LINE__;
+
+module pragma_line;
+  int l;
+  initial begin
+    l = `HERE;
+    `CHECK_AT(l)
+  end
+`pragma protect begin_protected
+`pragma protect encoding = (enctype = "base64")
+`pragma protect data_block
+aGVsbG8gd29ybGQ= !!garbage that is not SystemVerilog ;;
+`pragma protect end_protected
+`pragma once
+  assign l2 = l;
+endmodule
@@ -0,0 +1,23 @@
+// Specify blocks: path ports, conditions and timing checks
+module spec_cell (input a, b, clk, d, en, output q, y);
+  reg notifier;
+  specify
+    specparam t_rise = 1:2:3, t_fall = 2;
+    (a => y) = (t_rise, t_fall);
+    (b *> y, q) = 1.5;
+    if (en) (posedge clk => (q +: d)) = 2;
+    ifnone (a => y) = 3;
+    (y => a) = 1;
+    (a => missing_out) = 1;
+    if (bad_cond) (b => y) = 1;
+    showcancelled y;
+    $setup(d, posedge clk, 1, notifier);
+    $hold(posedge clk, d, 1);
+    $setuphold(posedge clk, d, 1, 1, notifier, , , d_clk, d_d);
+    $recrem(posedge en, posedge clk, 1, 1, notifier, , , r_en, r_clk);
+    $width(posedge clk, 5);
+    $period(posedge clk, 10, undeclared_notifier);
+  endspecify
+  // Delayed signals of $setuphold/$recrem are implicitly declared in the module
+  assign q = d_d & d_clk & r_en & r_clk;
+endmodule
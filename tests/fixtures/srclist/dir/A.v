@@ -0,0 +1,3 @@
+module A;
+  B u_b();
+endmodule
@@ -0,0 +1,2 @@
+module B; endmodule
+module unused; endmodule
@@ -0,0 +1,7 @@
+// Library file needed only by a module of a library directory
+-v lib.v
+-y dir +libext+.v
+top.sv
+// Paths of -F lists are relative to the list, paths of -f lists to the current directory
+-F sub/rel.f
+-f sub/cwd.f
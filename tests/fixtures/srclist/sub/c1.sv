@@ -0,0 +1 @@
+module C1; endmodule
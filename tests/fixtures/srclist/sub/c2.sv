@@ -0,0 +1 @@
+module C2; endmodule
@@ -0,0 +1 @@
+srclist/sub/c2.sv
@@ -0,0 +1 @@
+c1.sv
@@ -0,0 +1,6 @@
+module top;
+  A  u_a();
+  C1 u_c1();
+  C2 u_c2();
+  D  u_d(); // Undefined
+endmodule
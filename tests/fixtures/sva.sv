@@ -0,0 +1,26 @@
+// Sequences and properties: operators, formal arguments and local variables
+module sva_chk (input logic clk, rst, req, ack, gnt);
+  sequence s_req_ack(a, b, int n = 2);
+    a ##[1:n] b;
+  endsequence
+
+  sequence s_ops;
+    req [*2] ##1 ack [->1] ##0 gnt [=1:3];
+  endsequence
+
+  property p_handshake;
+    int cnt;
+    @(posedge clk) disable iff (rst)
+      (req, cnt = 0) |-> s_req_ack(req, ack) ##1 (ack throughout gnt [*1:$]) and first_match(req ##[0:$] gnt);
+  endproperty
+
+  property p_bad;
+    @(posedge clk) req |=> s_eventually (ack within s_unknown) or missing_sig;
+  endproperty
+
+  a_hs  : assert property (p_handshake) else $error("handshake");
+  c_ops : cover property (@(posedge clk) s_ops);
+  c_arg : cover property (@(posedge clk) s_req_ack(req));
+  a_bad : assert property (p_bad);
+  assume property (@(posedge clk) req intersect ack);
+endmodule
@@ -0,0 +1,14 @@
+// Token values are ranges of the source buffer: identifiers, numbers, strings and comments
+`define NAME(p) p``_sig
+`define STR `"macro string`"
+module token_value;
+  logic \bus[0] , `NAME(data);
+  logic [7:0] v = 8'b 0101_1010, w = 'hF, z = 'z;
+  real r = 1.5e-3;
+  string s = "a \"quoted\" ✓ string", t = `STR;
+  default clocking cb @(posedge data_sig); endclocking
+  initial begin
+    #10ns v = w;
+    ##1 \bus[0]  = \bux ;
+  end
+endmodule
@@ -0,0 +1,33 @@
+// User-defined primitives: table rows and instances
+primitive udp_mux (output y, input s, a, b);
+  table
+  // s a b : y
+     0 1 ? : 1;
+     0 0 ? : 0;
+     1 ? 1 : 1;
+     1 ? 0 : 0;
+     x 0 0 : 0;
+     0 1 1 : 0;
+     1 1   : 1;
+     0 (01) 1 : 1;
+  endtable
+endprimitive
+
+primitive udp_dff (output reg q, input d, clk);
+  initial q = 1'b0;
+  table
+  // d clk : q : q+
+     0 (01) : ? : 0;
+     1 (01) : ? : 1;
+     ? (10) : ? : -;
+     (??) ? : ? : -;
+     1 r    : 2 : 1;
+  endtable
+endprimitive
+
+module udp_top (input logic s, a, b, clk, output logic y, q);
+  udp_mux m0 (y, s, a, b);
+  udp_mux m1 (y, s, a);
+  udp_dff f0 (q, a, clk);
+  and #(1:2:3) g0 (y, a, b), g1 (y);
+endmodule
@@ -0,0 +1,6 @@
+module a;
+  import p::*;
+  logic [PW-1:0] n;
+  logic [QW-1:0] b;
+  initial n = PW + QW;
+endmodule
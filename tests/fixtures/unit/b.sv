@@ -0,0 +1,5 @@
+// Import inside module a must not be visible here
+module b;
+  logic [7:0] n;
+  initial n = PW + QW;
+endmodule
@@ -0,0 +1,10 @@
+package p;
+  localparam int PW = 4;
+endpackage
+
+package q;
+  localparam int QW = 8;
+endpackage
+
+// $unit import: visible in the following files
+import q::*;
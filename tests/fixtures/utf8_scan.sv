@@ -0,0 +1,9 @@
+// Index-based scanning over multi-byte characters: façade, 温度, émetteur
+module utf8_scan;
+  /* bloc « commenté » */ logic a;
+  string s = "déjà vu ✓";
+  initial begin
+    a = 1'b0; // généré
+    /* « ✓ » */ a = missing;
+  end
+endmodule